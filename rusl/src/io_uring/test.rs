@@ -10,12 +10,15 @@ use crate::io_uring::{
 use crate::platform::{
     AddressFamily, Fd, IoSlice, IoSliceMut, IoUring, IoUringCompletionQueueEntry,
     IoUringEnterFlags, IoUringParamFlags, IoUringParams, IoUringSQEFlags,
-    IoUringSubmissionQueueEntry, Mode, OpenFlags, PollAddMultiFlags, PollEvents, RenameFlags,
-    SocketFlags, SocketOptions, SocketType, StatxFlags, StatxMask, TimeSpec, STDERR, STDIN, STDOUT,
+    IoUringSubmissionQueueEntry, LinkFlags, Mode, OpenFlags, PollAddMultiFlags, PollEvents,
+    RenameFlags, SocketFlags, SocketOptions, SocketType, StatxFlags, StatxMask, TimeSpec, STDERR,
+    STDIN, STDOUT,
 };
 use crate::string::unix_str::UnixStr;
 use crate::time::clock_get_monotonic_time;
-use crate::unistd::{close, open, open_mode, read, stat, unlink_flags, UnlinkFlags};
+use crate::unistd::{
+    close, open, open_mode, read, readlinkat, stat, unlink, unlink_flags, UnlinkFlags,
+};
 
 #[test]
 fn uring_setup() {
@@ -310,6 +313,58 @@ fn uring_single_rename_at() {
     }
 }
 
+#[test]
+fn uring_single_symlink_at() {
+    let Some(mut uring) = setup_ignore_enosys(8, IoUringParamFlags::empty()) else {
+        return;
+    };
+    let link_path = unsafe { UnixStr::from_str_unchecked("test-files/io_uring/symlink\0") };
+    let _ = unlink(link_path);
+    let user_data = 368;
+    let entry = unsafe {
+        IoUringSubmissionQueueEntry::new_symlinkat(
+            UnixStr::from_str_checked("../can_open.txt\0"),
+            None,
+            link_path,
+            user_data,
+            IoUringSQEFlags::empty(),
+        )
+    };
+    write_await_single_entry(&mut uring, entry, user_data);
+    let mut buf = [0u8; 32];
+    let len = readlinkat(None, link_path, &mut buf).unwrap();
+    assert_eq!(b"../can_open.txt", &buf[..len]);
+    unlink(link_path).unwrap();
+}
+
+#[test]
+fn uring_single_link_at() {
+    let Some(mut uring) = setup_ignore_enosys(8, IoUringParamFlags::empty()) else {
+        return;
+    };
+    let old_path = UnixStr::from_str_checked("test-files/can_open.txt\0");
+    let new_path = unsafe { UnixStr::from_str_unchecked("test-files/io_uring/hardlink\0") };
+    let _ = unlink(new_path);
+    let user_data = 369;
+    let entry = unsafe {
+        IoUringSubmissionQueueEntry::new_linkat(
+            None,
+            old_path,
+            None,
+            new_path,
+            LinkFlags::empty(),
+            user_data,
+            IoUringSQEFlags::empty(),
+        )
+    };
+    write_await_single_entry(&mut uring, entry, user_data);
+    assert_eq!(
+        stat(old_path).unwrap().st_ino,
+        stat(new_path).unwrap().st_ino
+    );
+    unlink(new_path).unwrap();
+}
+
 #[test]
 fn uring_single_mkdir_at() {
     let Some(mut uring) = setup_ignore_enosys(8, IoUringParamFlags::empty()) else {
@@ -552,6 +607,88 @@ fn uring_tcp_accept() {
     let _ = crate::unistd::close(srv_sock);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn uring_tcp6_accept_connect() {
+    use crate::network::{bind_inet6, listen, socket};
+    use crate::platform::SocketAddressInet6;
+    const FIFTEEN: crate::platform::NonNegativeI32 =
+        crate::platform::NonNegativeI32::comptime_checked_new(15);
+    const LOCALHOST_V6: [u8; 16] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+    let Some(mut uring) = setup_ignore_enosys(8, IoUringParamFlags::empty()) else {
+        return;
+    };
+    let srv_sock = socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    bind_inet6(srv_sock, &SocketAddressInet6::new(LOCALHOST_V6, 0)).unwrap();
+    let addr = crate::network::get_inet6_sock_name(srv_sock).unwrap();
+    listen(srv_sock, FIFTEEN).unwrap();
+    let mut peer_addr = SocketAddressInet6::new([0; 16], 0);
+    let mut peer_addr_len = SocketAddressInet6::LENGTH as u64;
+    let accept_user_data = 10013;
+    let entry = unsafe {
+        IoUringSubmissionQueueEntry::new_accept_inet6(
+            srv_sock,
+            core::ptr::addr_of_mut!(peer_addr),
+            core::ptr::addr_of_mut!(peer_addr_len),
+            SocketFlags::SOCK_CLOEXEC,
+            accept_user_data,
+            IoUringSQEFlags::IOSQE_ASYNC,
+        )
+    };
+    let next_slot = uring.get_next_sqe_slot().unwrap();
+    next_slot.write(entry);
+    uring.flush_submission_queue();
+    io_uring_enter(uring.fd, 1, 0, IoUringEnterFlags::empty()).unwrap();
+    let conn_sock = socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    let connect_user_data = 10014;
+    let entry = unsafe {
+        IoUringSubmissionQueueEntry::new_connect_inet6(
+            conn_sock,
+            &addr,
+            connect_user_data,
+            IoUringSQEFlags::empty(),
+        )
+    };
+    let next_slot = uring.get_next_sqe_slot().unwrap();
+    next_slot.write(entry);
+    uring.flush_submission_queue();
+    io_uring_enter(uring.fd, 1, 2, IoUringEnterFlags::IORING_ENTER_GETEVENTS).unwrap();
+    let mut accepted = None;
+    let mut connected = false;
+    for _ in 0..2 {
+        let cqe = uring.get_next_cqe().unwrap();
+        assert!(cqe.0.res >= 0, "Failed res for cqe: {cqe:?}");
+        match cqe.0.user_data {
+            10013 => accepted = Some(Fd::try_new(cqe.0.res).unwrap()),
+            10014 => connected = true,
+            ud => panic!("Bad user data {ud} in cqe {cqe:?}"),
+        }
+    }
+    assert!(connected);
+    let server_to_client_socket = accepted.unwrap();
+    // The kernel filled in the peer's address
+    assert_eq!(SocketAddressInet6::LENGTH as u64, peer_addr_len);
+    let client_name = crate::network::get_inet6_sock_name(conn_sock).unwrap();
+    assert_eq!(client_name.ipv6_addr(), peer_addr.ipv6_addr());
+    crate::unistd::write(server_to_client_socket, b"Ping!").unwrap();
+    let mut ret_buf = [0u8; 5];
+    let read = crate::unistd::read(conn_sock, &mut ret_buf).unwrap();
+    assert_eq!(b"Ping!", &ret_buf[..read]);
+    let _ = crate::unistd::close(server_to_client_socket);
+    let _ = crate::unistd::close(conn_sock);
+    let _ = crate::unistd::close(srv_sock);
+}
+
 fn write_await_single_entry(
     uring: &mut IoUring,
     entry: IoUringSubmissionQueueEntry,
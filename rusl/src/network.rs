@@ -1,17 +1,31 @@
-pub use accept::{accept_inet, accept_unix};
-pub use bind::{bind_inet, bind_unix};
-pub use connect::{connect_inet, connect_unix};
+pub use accept::{accept_inet, accept_inet6, accept_ip, accept_unix};
+pub use bind::{bind_inet, bind_inet6, bind_unix};
+pub use connect::{connect_inet, connect_inet6, connect_unix};
 pub use listen::listen;
 
+pub use sockopt::{
+    get_broadcast, get_ipv6_only, get_keepalive, get_linger, get_multicast_loop_v4,
+    get_multicast_ttl_v4, get_peer_credentials, get_recv_buffer_size, get_recv_timeout,
+    get_reuse_addr, get_reuse_port, get_send_buffer_size, get_send_timeout, get_socket_error,
+    get_tcp_keepidle, get_tcp_nodelay, getsockopt, join_multicast_v4, leave_multicast_v4,
+    set_broadcast, set_ipv6_only, set_keepalive, set_linger, set_multicast_loop_v4,
+    set_multicast_ttl_v4, set_recv_buffer_size, set_recv_timeout, set_reuse_addr, set_reuse_port,
+    set_send_buffer_size, set_send_timeout, set_tcp_keepidle, set_tcp_nodelay, setsockopt,
+};
+
+pub use socket::{
+    get_inet6_sock_name, get_inet_sock_name, get_ip_peer_name, get_ip_sock_name,
+    get_unix_peer_name, get_unix_sock_name, recvfrom_inet, recvfrom_ip, recvmmsg, sendmmsg,
+    sendto_inet, sendto_inet6, shutdown, socket,
+};
 #[cfg(feature = "alloc")]
-pub use socket::{get_inet_sock_name, get_unix_sock_name, recvmsg, sendmsg, socket};
-#[cfg(not(feature = "alloc"))]
-pub use socket::{get_inet_sock_name, get_unix_sock_name, socket};
+pub use socket::{recvmsg, sendmsg};
 
 mod accept;
 mod bind;
 mod connect;
 mod listen;
 mod socket;
+mod sockopt;
 #[cfg(all(test, feature = "alloc"))]
 mod test;
@@ -1,7 +1,10 @@
 use core::mem::MaybeUninit;
 use sc::syscall;
 
-use crate::platform::{Fd, SocketAddressInet, SocketAddressUnix, SocketArgUnix, SocketFlags};
+use crate::platform::{
+    Fd, SocketAddressInet, SocketAddressInet6, SocketAddressIp, SocketAddressUnix, SocketArgUnix,
+    SocketFlags,
+};
 use crate::Result;
 
 /// Accept a new unix-connection and set flags on the new connection's `Fd`
@@ -42,7 +45,7 @@ pub fn accept_unix(sock_fd: Fd, flags: SocketFlags) -> Result<(Fd, SocketArgUnix
 #[inline]
 pub fn accept_inet(sock_fd: Fd, flags: SocketFlags) -> Result<(Fd, SocketAddressInet)> {
     let mut addr = MaybeUninit::zeroed();
-    let mut addr_len = core::mem::size_of::<SocketAddressUnix>();
+    let mut addr_len = SocketAddressInet::LENGTH;
     let res = unsafe {
         syscall!(
             ACCEPT4,
@@ -55,3 +58,35 @@ pub fn accept_inet(sock_fd: Fd, flags: SocketFlags) -> Result<(Fd, SocketAddress
     let fd = Fd::coerce_from_register(res, "`ACCEPT4` syscall failed")?;
     unsafe { Ok((fd, addr.assume_init())) }
 }
+
+/// Accept a new IPv6 connection and set flags on the new connection's `Fd`
+/// Accepted flags are 0, `SOCK_NONBLOCK` an `SOCK_CLOEXEC`
+/// See [Linux documentation for more details](https://man7.org/linux/man-pages/man2/accept.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn accept_inet6(sock_fd: Fd, flags: SocketFlags) -> Result<(Fd, SocketAddressInet6)> {
+    let mut addr = SocketAddressInet6::zeroed();
+    let mut addr_len = SocketAddressInet6::LENGTH;
+    let res = unsafe {
+        syscall!(
+            ACCEPT4,
+            sock_fd.0,
+            core::ptr::addr_of_mut!(addr),
+            core::ptr::addr_of_mut!(addr_len),
+            flags.0
+        )
+    };
+    let fd = Fd::coerce_from_register(res, "`ACCEPT4` syscall failed")?;
+    Ok((fd, addr))
+}
+
+/// Accept a new connection on either an IPv4 or IPv6 socket, same as [`accept_inet`] and
+/// [`accept_inet6`] but for when the socket's family isn't known
+/// # Errors
+/// See [`accept_inet`]
+#[inline]
+pub fn accept_ip(sock_fd: Fd, flags: SocketFlags) -> Result<(Fd, SocketAddressIp)> {
+    let (fd, storage) = accept_inet6(sock_fd, flags)?;
+    Ok((fd, SocketAddressIp::from_storage(&storage)))
+}
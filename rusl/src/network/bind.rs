@@ -1,6 +1,6 @@
 use sc::syscall;
 
-use crate::platform::{Fd, SocketAddressInet, SocketArgUnix};
+use crate::platform::{Fd, SocketAddressInet, SocketAddressInet6, SocketArgUnix};
 use crate::Result;
 
 /// Bind the unix-socket with the fd `sock_fd` to the address `socket_address`
@@ -39,3 +39,21 @@ pub fn bind_inet(sock_fd: Fd, socket_address_inet: &SocketAddressInet) -> Result
     bail_on_below_zero!(res, "`BIND` syscall failed");
     Ok(())
 }
+
+/// Bind the IPv6 socket with the fd `sock_fd` to the address `socket_address_inet6`
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/bind.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn bind_inet6(sock_fd: Fd, socket_address_inet6: &SocketAddressInet6) -> Result<()> {
+    let res = unsafe {
+        syscall!(
+            BIND,
+            sock_fd.0,
+            core::ptr::from_ref::<SocketAddressInet6>(socket_address_inet6),
+            SocketAddressInet6::LENGTH
+        )
+    };
+    bail_on_below_zero!(res, "`BIND` syscall failed");
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use sc::syscall;
 
 use crate::error::Result;
-use crate::platform::{Fd, SocketAddressInet, SocketArgUnix};
+use crate::platform::{Fd, SocketAddressInet, SocketAddressInet6, SocketArgUnix};
 
 /// Connect the unix-socket with the fd `sock_fd` to the address `socket_address`
 /// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/connect.2.html)
@@ -40,3 +40,21 @@ pub fn connect_inet(sock_fd: Fd, addr: &SocketAddressInet) -> Result<()> {
     bail_on_below_zero!(res, "`CONNECT` syscall failed");
     Ok(())
 }
+
+/// Connect the IPv6 socket with the fd `sock_fd` to the address `addr`
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/connect.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn connect_inet6(sock_fd: Fd, addr: &SocketAddressInet6) -> Result<()> {
+    let res = unsafe {
+        syscall!(
+            CONNECT,
+            sock_fd.0,
+            core::ptr::from_ref::<SocketAddressInet6>(addr),
+            SocketAddressInet6::LENGTH
+        )
+    };
+    bail_on_below_zero!(res, "`CONNECT` syscall failed");
+    Ok(())
+}
@@ -2,10 +2,23 @@ use core::mem::MaybeUninit;
 use sc::syscall;
 
 use crate::platform::{
-    AddressFamily, Fd, SocketAddressInet, SocketAddressUnix, SocketArgUnix, SocketOptions,
+    AddressFamily, Fd, MMsgHdr, MsgFlags, Shutdown, SocketAddressInet, SocketAddressInet6,
+    SocketAddressIp, SocketAddressUnix, SocketArgUnix, SocketOptions, TimeSpec,
 };
 use crate::Result;
 
+/// Shut down one or both halves of the connection on `sock_fd`, unlike `close` this
+/// affects every fd referring to the socket
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/shutdown.2.html)
+/// # Errors
+/// See above, `ENOTCONN` if the socket isn't connected
+#[inline]
+pub fn shutdown(sock_fd: Fd, how: Shutdown) -> Result<()> {
+    let res = unsafe { syscall!(SHUTDOWN, sock_fd.0, how as i32) };
+    bail_on_below_zero!(res, "`SHUTDOWN` syscall failed");
+    Ok(())
+}
+
 /// Create a socket with the specified `Domain`, `SocketType`, and `protocol`
 /// See [linux docs for details](https://man7.org/linux/man-pages/man2/socket.2.html)
 /// # Errors
@@ -40,6 +53,30 @@ pub fn get_unix_sock_name(sock_fd: Fd) -> Result<SocketArgUnix> {
     }
 }
 
+/// Get the address of the peer connected to the provided Unix socket [`Fd`].
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getpeername.2.html)
+/// # Errors
+/// See above, `ENOTCONN` if the socket isn't connected
+pub fn get_unix_peer_name(sock_fd: Fd) -> Result<SocketArgUnix> {
+    let mut addr = MaybeUninit::zeroed();
+    let mut addr_len = core::mem::size_of::<SocketAddressUnix>();
+    let res = unsafe {
+        syscall!(
+            GETPEERNAME,
+            sock_fd.0,
+            core::ptr::addr_of_mut!(addr),
+            core::ptr::addr_of_mut!(addr_len)
+        )
+    };
+    bail_on_below_zero!(res, "`GETPEERNAME` syscall failed");
+    unsafe {
+        Ok(SocketArgUnix {
+            addr: addr.assume_init(),
+            addr_len,
+        })
+    }
+}
+
 /// Get the socket name of the provided Inet socket [`Fd`].
 /// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getsockname.2.html)
 /// # Errors
@@ -59,6 +96,225 @@ pub fn get_inet_sock_name(sock_fd: Fd) -> Result<SocketAddressInet> {
     unsafe { Ok(addr.assume_init()) }
 }
 
+/// Get the socket name of the provided IPv6 socket [`Fd`].
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getsockname.2.html)
+/// # Errors
+/// See above
+pub fn get_inet6_sock_name(sock_fd: Fd) -> Result<SocketAddressInet6> {
+    let mut addr = SocketAddressInet6::zeroed();
+    let mut addr_len = SocketAddressInet6::LENGTH;
+    let res = unsafe {
+        syscall!(
+            GETSOCKNAME,
+            sock_fd.0,
+            core::ptr::addr_of_mut!(addr),
+            core::ptr::addr_of_mut!(addr_len)
+        )
+    };
+    bail_on_below_zero!(res, "`GETSOCKNAME` syscall failed");
+    Ok(addr)
+}
+
+/// Get the socket name of an IPv4 or IPv6 socket [`Fd`], for when the family isn't known.
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getsockname.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_ip_sock_name(sock_fd: Fd) -> Result<SocketAddressIp> {
+    Ok(SocketAddressIp::from_storage(&get_inet6_sock_name(
+        sock_fd,
+    )?))
+}
+
+/// Get the address of the peer connected to an IPv4 or IPv6 socket [`Fd`].
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getpeername.2.html)
+/// # Errors
+/// See above, `ENOTCONN` if the socket isn't connected
+pub fn get_ip_peer_name(sock_fd: Fd) -> Result<SocketAddressIp> {
+    let mut addr = SocketAddressInet6::zeroed();
+    let mut addr_len = SocketAddressInet6::LENGTH;
+    let res = unsafe {
+        syscall!(
+            GETPEERNAME,
+            sock_fd.0,
+            core::ptr::addr_of_mut!(addr),
+            core::ptr::addr_of_mut!(addr_len)
+        )
+    };
+    bail_on_below_zero!(res, "`GETPEERNAME` syscall failed");
+    Ok(SocketAddressIp::from_storage(&addr))
+}
+
+/// Send `buf` on a socket, to `addr` if provided, otherwise to the connected peer.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/sendto.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sendto_inet(
+    sock_fd: Fd,
+    buf: &[u8],
+    flags: MsgFlags,
+    addr: Option<&SocketAddressInet>,
+) -> Result<usize> {
+    let (addr_ptr, addr_len) = addr.map_or((core::ptr::null(), 0), |addr| {
+        (
+            core::ptr::from_ref::<SocketAddressInet>(addr),
+            SocketAddressInet::LENGTH,
+        )
+    });
+    let res = unsafe {
+        syscall!(
+            SENDTO,
+            sock_fd.0,
+            buf.as_ptr(),
+            buf.len(),
+            flags.bits(),
+            addr_ptr,
+            addr_len
+        )
+    };
+    bail_on_below_zero!(res, "`SENDTO` syscall failed");
+    Ok(res)
+}
+
+/// Send `buf` on an IPv6 socket, to `addr` if provided, otherwise to the connected peer.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/sendto.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sendto_inet6(
+    sock_fd: Fd,
+    buf: &[u8],
+    flags: MsgFlags,
+    addr: Option<&SocketAddressInet6>,
+) -> Result<usize> {
+    let (addr_ptr, addr_len) = addr.map_or((core::ptr::null(), 0), |addr| {
+        (
+            core::ptr::from_ref::<SocketAddressInet6>(addr),
+            SocketAddressInet6::LENGTH,
+        )
+    });
+    let res = unsafe {
+        syscall!(
+            SENDTO,
+            sock_fd.0,
+            buf.as_ptr(),
+            buf.len(),
+            flags.bits(),
+            addr_ptr,
+            addr_len
+        )
+    };
+    bail_on_below_zero!(res, "`SENDTO` syscall failed");
+    Ok(res)
+}
+
+/// Receive from an IPv4 or IPv6 socket into `buf`, returning the number of bytes received and the sender,
+/// same as [`recvfrom_inet`] but for when the socket's family isn't known.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/recv.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn recvfrom_ip(
+    sock_fd: Fd,
+    buf: &mut [u8],
+    flags: MsgFlags,
+) -> Result<(usize, SocketAddressIp)> {
+    let mut addr = SocketAddressInet6::zeroed();
+    let mut addr_len = SocketAddressInet6::LENGTH as u32;
+    let res = unsafe {
+        syscall!(
+            RECVFROM,
+            sock_fd.0,
+            buf.as_mut_ptr(),
+            buf.len(),
+            flags.bits(),
+            core::ptr::addr_of_mut!(addr),
+            core::ptr::addr_of_mut!(addr_len)
+        )
+    };
+    bail_on_below_zero!(res, "`RECVFROM` syscall failed");
+    Ok((res, SocketAddressIp::from_storage(&addr)))
+}
+
+/// Receive from a socket into `buf`, returning the number of bytes received and the sender.
+/// A datagram that doesn't fit into `buf` is truncated, unless `MSG_TRUNC` is passed
+/// the returned length is the truncated length.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/recv.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn recvfrom_inet(
+    sock_fd: Fd,
+    buf: &mut [u8],
+    flags: MsgFlags,
+) -> Result<(usize, SocketAddressInet)> {
+    let mut addr = MaybeUninit::<SocketAddressInet>::zeroed();
+    let mut addr_len = SocketAddressInet::LENGTH as u32;
+    let res = unsafe {
+        syscall!(
+            RECVFROM,
+            sock_fd.0,
+            buf.as_mut_ptr(),
+            buf.len(),
+            flags.bits(),
+            addr.as_mut_ptr(),
+            core::ptr::addr_of_mut!(addr_len)
+        )
+    };
+    bail_on_below_zero!(res, "`RECVFROM` syscall failed");
+    // Zeroed is a valid `sockaddr_in`, and the kernel wrote the sender, if any
+    unsafe { Ok((res, addr.assume_init())) }
+}
+
+/// Send multiple messages with one syscall, returns how many were sent, the length
+/// sent for each is updated in `msgs`.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/sendmmsg.2.html)
+/// # Errors
+/// See above, only if the first message couldn't be sent
+#[inline]
+pub fn sendmmsg(sock_fd: Fd, msgs: &mut [MMsgHdr], flags: MsgFlags) -> Result<usize> {
+    let res = unsafe {
+        syscall!(
+            SENDMMSG,
+            sock_fd.0,
+            msgs.as_mut_ptr(),
+            msgs.len(),
+            flags.bits()
+        )
+    };
+    bail_on_below_zero!(res, "`SENDMMSG` syscall failed");
+    Ok(res)
+}
+
+/// Receive multiple messages with one syscall, returns how many were received, the length
+/// received for each is updated in `msgs`.
+/// The `timeout` is only checked after each received message, pass `MSG_WAITFORONE`
+/// or use a non-blocking socket to not wait for the batch to fill up.
+/// See [linux docs for details](https://man7.org/linux/man-pages/man2/recvmmsg.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn recvmmsg(
+    sock_fd: Fd,
+    msgs: &mut [MMsgHdr],
+    flags: MsgFlags,
+    timeout: Option<&TimeSpec>,
+) -> Result<usize> {
+    let res = unsafe {
+        syscall!(
+            RECVMMSG,
+            sock_fd.0,
+            msgs.as_mut_ptr(),
+            msgs.len(),
+            flags.bits(),
+            timeout.map_or_else(core::ptr::null, core::ptr::from_ref::<TimeSpec>)
+        )
+    };
+    bail_on_below_zero!(res, "`RECVMMSG` syscall failed");
+    Ok(res)
+}
+
 /// Send a message on a socket, [`crate::unistd::write`] should be prefered if not sending fds.
 /// See [linux docs for details](https://man7.org/linux/man-pages/man2/send.2.html)
 /// # Errors
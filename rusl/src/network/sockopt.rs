@@ -0,0 +1,602 @@
+use core::time::Duration;
+
+use sc::syscall;
+
+use crate::error::Errno;
+use crate::platform::{Fd, IpMreq, Linger, SockTimeVal, SocketOptLevel, SocketOptName, UCred};
+use crate::Result;
+
+/// Read the value of a socket option into `value`, returning the length of the value.
+/// The value is truncated if `value` is too small.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getsockopt.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn getsockopt(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+    value: &mut [u8],
+) -> Result<usize> {
+    unsafe { getsockopt_raw(sock_fd, level, name, value.as_mut_ptr(), value.len()) }
+}
+
+/// Set a socket option to the raw bytes in `value`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/setsockopt.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn setsockopt(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+    value: &[u8],
+) -> Result<()> {
+    unsafe { setsockopt_raw(sock_fd, level, name, value.as_ptr(), value.len()) }
+}
+
+unsafe fn getsockopt_raw(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+    value: *mut u8,
+    len: usize,
+) -> Result<usize> {
+    let mut opt_len = len as u32;
+    let res = unsafe {
+        syscall!(
+            GETSOCKOPT,
+            sock_fd.0,
+            level.0,
+            name.0,
+            value,
+            core::ptr::addr_of_mut!(opt_len)
+        )
+    };
+    bail_on_below_zero!(res, "`GETSOCKOPT` syscall failed");
+    Ok(opt_len as usize)
+}
+
+unsafe fn setsockopt_raw(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+    value: *const u8,
+    len: usize,
+) -> Result<()> {
+    let res = unsafe { syscall!(SETSOCKOPT, sock_fd.0, level.0, name.0, value, len) };
+    bail_on_below_zero!(res, "`SETSOCKOPT` syscall failed");
+    Ok(())
+}
+
+fn get_value<T: Copy + Default>(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+) -> Result<T> {
+    let mut val = T::default();
+    unsafe {
+        getsockopt_raw(
+            sock_fd,
+            level,
+            name,
+            core::ptr::addr_of_mut!(val).cast(),
+            core::mem::size_of::<T>(),
+        )?;
+    }
+    Ok(val)
+}
+
+fn set_value<T: Copy>(
+    sock_fd: Fd,
+    level: SocketOptLevel,
+    name: SocketOptName,
+    val: T,
+) -> Result<()> {
+    unsafe {
+        setsockopt_raw(
+            sock_fd,
+            level,
+            name,
+            core::ptr::addr_of!(val).cast(),
+            core::mem::size_of::<T>(),
+        )
+    }
+}
+
+#[inline]
+fn get_flag(sock_fd: Fd, level: SocketOptLevel, name: SocketOptName) -> Result<bool> {
+    Ok(get_value::<i32>(sock_fd, level, name)? != 0)
+}
+
+#[inline]
+fn set_flag(sock_fd: Fd, level: SocketOptLevel, name: SocketOptName, on: bool) -> Result<()> {
+    set_value(sock_fd, level, name, i32::from(on))
+}
+
+/// Allow binding to an address that's in `TIME_WAIT` from a previous listener,
+/// set this before `bind` to be able to restart a server immediately.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_reuse_addr(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_REUSEADDR,
+        on,
+    )
+}
+
+/// Check if `SO_REUSEADDR` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_reuse_addr(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_REUSEADDR,
+    )
+}
+
+/// Allow multiple sockets owned by the same user to bind the same address,
+/// incoming connections are load balanced between them. Has to be set on every socket before `bind`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_reuse_port(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_REUSEPORT,
+        on,
+    )
+}
+
+/// Check if `SO_REUSEPORT` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_reuse_port(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_REUSEPORT,
+    )
+}
+
+/// Send keepalive probes on an idle connection.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_keepalive(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_KEEPALIVE,
+        on,
+    )
+}
+
+/// Check if `SO_KEEPALIVE` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_keepalive(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_KEEPALIVE,
+    )
+}
+
+/// Set the receive buffer size, the kernel doubles it to leave room for bookkeeping
+/// and clamps it to `/proc/sys/net/core/rmem_max`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_recv_buffer_size(sock_fd: Fd, size: usize) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_RCVBUF,
+        i32::try_from(size).unwrap_or(i32::MAX),
+    )
+}
+
+/// Get the receive buffer size, as doubled by the kernel.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_recv_buffer_size(sock_fd: Fd) -> Result<usize> {
+    let size = get_value::<i32>(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_RCVBUF,
+    )?;
+    Ok(size.unsigned_abs() as usize)
+}
+
+/// Set the send buffer size, the kernel doubles it to leave room for bookkeeping
+/// and clamps it to `/proc/sys/net/core/wmem_max`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_send_buffer_size(sock_fd: Fd, size: usize) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_SNDBUF,
+        i32::try_from(size).unwrap_or(i32::MAX),
+    )
+}
+
+/// Get the send buffer size, as doubled by the kernel.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_send_buffer_size(sock_fd: Fd) -> Result<usize> {
+    let size = get_value::<i32>(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_SNDBUF,
+    )?;
+    Ok(size.unsigned_abs() as usize)
+}
+
+fn set_timeout(sock_fd: Fd, name: SocketOptName, timeout: Option<Duration>) -> Result<()> {
+    let tv = match timeout {
+        // A zeroed timeval means no timeout, which would be surprising
+        Some(dur) if dur.is_zero() => {
+            return Err(crate::Error::with_code(
+                "Socket timeout can't be zero",
+                Errno::EINVAL.raw(),
+            ));
+        }
+        Some(dur) => {
+            // Round up so that sub-microsecond timeouts don't become zero,
+            // rounding up to a full second has to carry, the kernel rejects `tv_usec` of `1_000_000`
+            let (secs, usecs) = match dur.subsec_nanos().div_ceil(1000) {
+                1_000_000 => (dur.as_secs().saturating_add(1), 0),
+                usecs => (dur.as_secs(), usecs),
+            };
+            SockTimeVal {
+                tv_sec: i64::try_from(secs).unwrap_or(i64::MAX),
+                tv_usec: i64::from(usecs),
+            }
+        }
+        None => SockTimeVal::default(),
+    };
+    set_value(sock_fd, SocketOptLevel::SOL_SOCKET, name, tv)
+}
+
+fn get_timeout(sock_fd: Fd, name: SocketOptName) -> Result<Option<Duration>> {
+    let tv = get_value::<SockTimeVal>(sock_fd, SocketOptLevel::SOL_SOCKET, name)?;
+    if tv.tv_sec == 0 && tv.tv_usec == 0 {
+        return Ok(None);
+    }
+    Ok(Some(
+        Duration::from_secs(tv.tv_sec.unsigned_abs())
+            + Duration::from_micros(tv.tv_usec.unsigned_abs()),
+    ))
+}
+
+/// Set a timeout for blocking receives, after which they fail with `EAGAIN`.
+/// `None` blocks indefinitely. Has no effect on non-blocking sockets.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above, `EINVAL` if `timeout` is zero
+#[inline]
+pub fn set_recv_timeout(sock_fd: Fd, timeout: Option<Duration>) -> Result<()> {
+    set_timeout(sock_fd, SocketOptName::SO_RCVTIMEO, timeout)
+}
+
+/// Get the receive timeout, `None` if there is none.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_recv_timeout(sock_fd: Fd) -> Result<Option<Duration>> {
+    get_timeout(sock_fd, SocketOptName::SO_RCVTIMEO)
+}
+
+/// Set a timeout for blocking sends, after which they fail with `EAGAIN`.
+/// `None` blocks indefinitely. Has no effect on non-blocking sockets.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above, `EINVAL` if `timeout` is zero
+#[inline]
+pub fn set_send_timeout(sock_fd: Fd, timeout: Option<Duration>) -> Result<()> {
+    set_timeout(sock_fd, SocketOptName::SO_SNDTIMEO, timeout)
+}
+
+/// Get the send timeout, `None` if there is none.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_send_timeout(sock_fd: Fd) -> Result<Option<Duration>> {
+    get_timeout(sock_fd, SocketOptName::SO_SNDTIMEO)
+}
+
+/// With `Some`, `close` blocks until unsent data is sent or the timeout, with whole second
+/// precision, passes. A timeout of zero resets the connection on `close` instead of a graceful shutdown.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_linger(sock_fd: Fd, linger: Option<Duration>) -> Result<()> {
+    let linger = linger.map_or(Linger::default(), |dur| Linger {
+        l_onoff: 1,
+        l_linger: i32::try_from(dur.as_secs()).unwrap_or(i32::MAX),
+    });
+    set_value(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_LINGER,
+        linger,
+    )
+}
+
+/// Get the linger timeout, `None` if lingering is off.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_linger(sock_fd: Fd) -> Result<Option<Duration>> {
+    let linger = get_value::<Linger>(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_LINGER,
+    )?;
+    Ok((linger.l_onoff != 0)
+        .then(|| Duration::from_secs(u64::from(linger.l_linger.unsigned_abs()))))
+}
+
+/// Get and clear the pending error on the socket, for example the result of a non-blocking `connect`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_socket_error(sock_fd: Fd) -> Result<Option<Errno>> {
+    let code = get_value::<i32>(sock_fd, SocketOptLevel::SOL_SOCKET, SocketOptName::SO_ERROR)?;
+    Ok((code != 0).then(|| Errno::new(code)))
+}
+
+/// Get the credentials of the peer of a connected unix socket,
+/// on an unconnected socket the pid is 0 and the ids are `u32::MAX`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/unix.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_peer_credentials(sock_fd: Fd) -> Result<UCred> {
+    let mut cred = UCred {
+        pid: 0,
+        uid: 0,
+        gid: 0,
+    };
+    unsafe {
+        getsockopt_raw(
+            sock_fd,
+            SocketOptLevel::SOL_SOCKET,
+            SocketOptName::SO_PEERCRED,
+            core::ptr::addr_of_mut!(cred).cast(),
+            core::mem::size_of::<UCred>(),
+        )?;
+    }
+    Ok(cred)
+}
+
+/// Allow sending to broadcast addresses on a datagram socket.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_broadcast(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_BROADCAST,
+        on,
+    )
+}
+
+/// Check if `SO_BROADCAST` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/socket.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_broadcast(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::SOL_SOCKET,
+        SocketOptName::SO_BROADCAST,
+    )
+}
+
+/// Join the IPv4 multicast `group` on the interface with the address `interface`,
+/// `[0, 0, 0, 0]` lets the kernel pick one.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn join_multicast_v4(sock_fd: Fd, group: [u8; 4], interface: [u8; 4]) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_ADD_MEMBERSHIP,
+        IpMreq {
+            imr_multiaddr: u32::from_ne_bytes(group),
+            imr_interface: u32::from_ne_bytes(interface),
+        },
+    )
+}
+
+/// Leave an IPv4 multicast `group` previously joined with [`join_multicast_v4`].
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above, `EADDRNOTAVAIL` if not a member
+#[inline]
+pub fn leave_multicast_v4(sock_fd: Fd, group: [u8; 4], interface: [u8; 4]) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_DROP_MEMBERSHIP,
+        IpMreq {
+            imr_multiaddr: u32::from_ne_bytes(group),
+            imr_interface: u32::from_ne_bytes(interface),
+        },
+    )
+}
+
+/// Loop sent multicast datagrams back to local sockets that are members of the group, on by default.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_multicast_loop_v4(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_MULTICAST_LOOP,
+        on,
+    )
+}
+
+/// Check if `IP_MULTICAST_LOOP` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_multicast_loop_v4(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_MULTICAST_LOOP,
+    )
+}
+
+/// Set the time to live of sent multicast datagrams, 1 by default which keeps them
+/// on the local network.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_multicast_ttl_v4(sock_fd: Fd, ttl: u8) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_MULTICAST_TTL,
+        i32::from(ttl),
+    )
+}
+
+/// Get the time to live of sent multicast datagrams.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ip.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_multicast_ttl_v4(sock_fd: Fd) -> Result<u8> {
+    let ttl = get_value::<i32>(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IP,
+        SocketOptName::IP_MULTICAST_TTL,
+    )?;
+    Ok(u8::try_from(ttl).unwrap_or(u8::MAX))
+}
+
+/// Disable Nagle's algorithm, sending small writes immediately instead of waiting
+/// to coalesce them.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/tcp.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn set_tcp_nodelay(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_NODELAY,
+        on,
+    )
+}
+
+/// Check if `TCP_NODELAY` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/tcp.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_tcp_nodelay(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_NODELAY,
+    )
+}
+
+/// Set how long a connection has to be idle before keepalive probes are sent,
+/// with whole second precision, if `SO_KEEPALIVE` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/tcp.7.html)
+/// # Errors
+/// See above, `EINVAL` if not within `1..=32767` seconds
+#[inline]
+pub fn set_tcp_keepidle(sock_fd: Fd, idle: Duration) -> Result<()> {
+    set_value(
+        sock_fd,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_KEEPIDLE,
+        i32::try_from(idle.as_secs()).unwrap_or(i32::MAX),
+    )
+}
+
+/// Get how long a connection has to be idle before keepalive probes are sent.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/tcp.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_tcp_keepidle(sock_fd: Fd) -> Result<Duration> {
+    let secs = get_value::<i32>(
+        sock_fd,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_KEEPIDLE,
+    )?;
+    Ok(Duration::from_secs(u64::from(secs.unsigned_abs())))
+}
+
+/// Restrict an `AF_INET6` socket to IPv6, otherwise it also accepts IPv4 through mapped addresses.
+/// Has to be set before `bind`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ipv6.7.html)
+/// # Errors
+/// See above, `ENOPROTOOPT` on a non-IPv6 socket
+#[inline]
+pub fn set_ipv6_only(sock_fd: Fd, on: bool) -> Result<()> {
+    set_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IPV6,
+        SocketOptName::IPV6_V6ONLY,
+        on,
+    )
+}
+
+/// Check if `IPV6_V6ONLY` is set.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man7/ipv6.7.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn get_ipv6_only(sock_fd: Fd) -> Result<bool> {
+    get_flag(
+        sock_fd,
+        SocketOptLevel::IPPROTO_IPV6,
+        SocketOptName::IPV6_V6ONLY,
+    )
+}
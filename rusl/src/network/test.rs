@@ -1,9 +1,20 @@
 use crate::error::Errno;
-use crate::network::{get_inet_sock_name, get_unix_sock_name};
+use crate::network::{
+    get_broadcast, get_inet_sock_name, get_ipv6_only, get_keepalive, get_linger,
+    get_multicast_loop_v4, get_multicast_ttl_v4, get_peer_credentials, get_recv_buffer_size,
+    get_recv_timeout, get_reuse_addr, get_reuse_port, get_send_buffer_size, get_send_timeout,
+    get_socket_error, get_tcp_keepidle, get_tcp_nodelay, get_unix_peer_name, get_unix_sock_name,
+    getsockopt, join_multicast_v4, leave_multicast_v4, recvfrom_inet, recvmmsg, sendmmsg,
+    sendto_inet, set_broadcast, set_ipv6_only, set_keepalive, set_linger, set_multicast_loop_v4,
+    set_multicast_ttl_v4, set_recv_buffer_size, set_recv_timeout, set_reuse_addr, set_reuse_port,
+    set_send_buffer_size, set_send_timeout, set_tcp_keepidle, set_tcp_nodelay, setsockopt,
+    shutdown,
+};
 use crate::platform::{
-    AddressFamily, ControlMessageSend, IoSlice, IoSliceMut, MsgHdrBorrow, NonNegativeI32,
-    OpenFlags, PollEvents, PollFd, SocketAddressInet, SocketAddressUnix, SocketFlags,
-    SocketOptions, SocketType,
+    AddressFamily, ControlMessageSend, IoSlice, IoSliceMut, MMsgHdr, MsgFlags, MsgHdrBorrow,
+    NonNegativeI32, OpenFlags, PollEvents, PollFd, Shutdown, SocketAddressInet, SocketAddressInet6,
+    SocketAddressIp, SocketAddressUnix, SocketFlags, SocketOptLevel, SocketOptName, SocketOptions,
+    SocketType,
 };
 use crate::unistd::{close, open, unlink};
 use core::sync::atomic::{AtomicBool, Ordering};
@@ -45,6 +56,55 @@ fn read_unix_sock_name() {
     let _ = close(srv_sock);
 }
 
+#[test]
+fn unix_peer_name_and_shutdown() {
+    const FIFTEEN: NonNegativeI32 = NonNegativeI32::comptime_checked_new(15);
+    let addr_raw = unix_lit!("test-files/socket/unix-peername-test");
+    let _ = unlink(addr_raw);
+    let srv_sock = super::socket(
+        AddressFamily::AF_UNIX,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        0,
+    )
+    .unwrap();
+    let addr = SocketAddressUnix::try_from_unix(addr_raw).unwrap();
+    super::bind_unix(srv_sock, &addr).unwrap();
+    super::listen(srv_sock, FIFTEEN).unwrap();
+    expect_errno!(Errno::ENOTCONN, get_unix_peer_name(srv_sock));
+    let cl_sock = super::socket(
+        AddressFamily::AF_UNIX,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        0,
+    )
+    .unwrap();
+    super::connect_unix(cl_sock, &addr).unwrap();
+    let (accepted, _) = super::accept_unix(srv_sock, SocketFlags::SOCK_CLOEXEC).unwrap();
+    // The client sees the listener's path, the listener sees an unbound client
+    let srv_name = get_unix_peer_name(cl_sock).unwrap();
+    assert_eq!(Some(addr_raw), srv_name.path());
+    assert!(!srv_name.is_unnamed());
+    let cl_name = get_unix_peer_name(accepted).unwrap();
+    assert!(cl_name.is_unnamed());
+    assert_eq!(None, cl_name.path());
+    // Half-close, the accepted end reads what was sent then EOF, but can still write back
+    crate::unistd::write(cl_sock, b"last").unwrap();
+    shutdown(cl_sock, Shutdown::Write).unwrap();
+    expect_errno!(
+        Errno::EPIPE,
+        sendto_inet(cl_sock, b"more", MsgFlags::MSG_NOSIGNAL, None)
+    );
+    let mut buf = [0u8; 8];
+    assert_eq!(4, crate::unistd::read(accepted, &mut buf).unwrap());
+    assert_eq!(b"last", &buf[..4]);
+    assert_eq!(0, crate::unistd::read(accepted, &mut buf).unwrap());
+    crate::unistd::write(accepted, b"reply").unwrap();
+    assert_eq!(5, crate::unistd::read(cl_sock, &mut buf).unwrap());
+    assert_eq!(b"reply", &buf[..5]);
+    close(accepted).unwrap();
+    close(cl_sock).unwrap();
+    close(srv_sock).unwrap();
+}
+
 #[test]
 fn send_recv_msg() {
     const FIFTEEN: NonNegativeI32 = NonNegativeI32::comptime_checked_new(15);
@@ -278,3 +338,366 @@ fn test_tcp() {
     );
     assert_eq!(EXPECT_RES, buf);
 }
+
+#[test]
+fn inet_socket_options() {
+    let sock = super::socket(
+        AddressFamily::AF_INET,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    assert!(!get_reuse_addr(sock).unwrap());
+    set_reuse_addr(sock, true).unwrap();
+    assert!(get_reuse_addr(sock).unwrap());
+    set_reuse_port(sock, true).unwrap();
+    assert!(get_reuse_port(sock).unwrap());
+    set_keepalive(sock, true).unwrap();
+    assert!(get_keepalive(sock).unwrap());
+    set_tcp_nodelay(sock, true).unwrap();
+    assert!(get_tcp_nodelay(sock).unwrap());
+    set_tcp_keepidle(sock, Duration::from_secs(30)).unwrap();
+    assert_eq!(Duration::from_secs(30), get_tcp_keepidle(sock).unwrap());
+    expect_errno!(Errno::EINVAL, set_tcp_keepidle(sock, Duration::ZERO));
+    // The kernel doubles the requested size
+    set_recv_buffer_size(sock, 8192).unwrap();
+    assert_eq!(16384, get_recv_buffer_size(sock).unwrap());
+    set_send_buffer_size(sock, 8192).unwrap();
+    assert_eq!(16384, get_send_buffer_size(sock).unwrap());
+    assert_eq!(None, get_recv_timeout(sock).unwrap());
+    set_recv_timeout(sock, Some(Duration::from_millis(1500))).unwrap();
+    assert_eq!(
+        Some(Duration::from_millis(1500)),
+        get_recv_timeout(sock).unwrap()
+    );
+    // Rounding up to the next microsecond carries into the seconds
+    set_recv_timeout(sock, Some(Duration::from_nanos(1_999_999_999))).unwrap();
+    assert_eq!(
+        Some(Duration::from_secs(2)),
+        get_recv_timeout(sock).unwrap()
+    );
+    set_recv_timeout(sock, None).unwrap();
+    assert_eq!(None, get_recv_timeout(sock).unwrap());
+    set_send_timeout(sock, Some(Duration::from_secs(2))).unwrap();
+    assert_eq!(
+        Some(Duration::from_secs(2)),
+        get_send_timeout(sock).unwrap()
+    );
+    expect_errno!(Errno::EINVAL, set_send_timeout(sock, Some(Duration::ZERO)));
+    assert_eq!(None, get_linger(sock).unwrap());
+    set_linger(sock, Some(Duration::from_secs(3))).unwrap();
+    assert_eq!(Some(Duration::from_secs(3)), get_linger(sock).unwrap());
+    assert_eq!(None, get_socket_error(sock).unwrap());
+    expect_errno!(Errno::ENOPROTOOPT, set_ipv6_only(sock, true));
+    // Generic access to an int option
+    let mut raw = [0u8; 4];
+    let len = getsockopt(
+        sock,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_NODELAY,
+        &mut raw,
+    )
+    .unwrap();
+    assert_eq!(4, len);
+    assert_eq!(1, i32::from_ne_bytes(raw));
+    setsockopt(
+        sock,
+        SocketOptLevel::IPPROTO_TCP,
+        SocketOptName::TCP_NODELAY,
+        &0i32.to_ne_bytes(),
+    )
+    .unwrap();
+    assert!(!get_tcp_nodelay(sock).unwrap());
+    close(sock).unwrap();
+    let sock6 = super::socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    set_ipv6_only(sock6, true).unwrap();
+    assert!(get_ipv6_only(sock6).unwrap());
+    set_ipv6_only(sock6, false).unwrap();
+    assert!(!get_ipv6_only(sock6).unwrap());
+    close(sock6).unwrap();
+}
+
+#[test]
+fn unix_peer_credentials() {
+    const FIFTEEN: NonNegativeI32 = NonNegativeI32::comptime_checked_new(15);
+    let addr_raw = unix_lit!("test-files/socket/tmp-peercred");
+    let _ = unlink(addr_raw);
+    let srv_sock = super::socket(
+        AddressFamily::AF_UNIX,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        0,
+    )
+    .unwrap();
+    let addr = SocketAddressUnix::try_from_unix(addr_raw).unwrap();
+    super::bind_unix(srv_sock, &addr).unwrap();
+    super::listen(srv_sock, FIFTEEN).unwrap();
+    let cl_sock = super::socket(
+        AddressFamily::AF_UNIX,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        0,
+    )
+    .unwrap();
+    // Completes against the backlog, without an accept
+    super::connect_unix(cl_sock, &addr).unwrap();
+    let (accepted, _addr) = super::accept_unix(srv_sock, SocketFlags::SOCK_CLOEXEC).unwrap();
+    for sock in [cl_sock, accepted] {
+        let cred = get_peer_credentials(sock).unwrap();
+        assert_eq!(crate::process::get_pid(), cred.pid);
+        assert_eq!(crate::unistd::get_uid().unwrap(), cred.uid);
+        assert_eq!(crate::unistd::get_gid().unwrap(), cred.gid);
+    }
+    close(accepted).unwrap();
+    close(cl_sock).unwrap();
+    close(srv_sock).unwrap();
+}
+
+fn bound_udp_socket() -> (crate::platform::Fd, SocketAddressInet) {
+    let sock = super::socket(
+        AddressFamily::AF_INET,
+        SocketOptions::new(SocketType::SOCK_DGRAM, SocketFlags::SOCK_CLOEXEC),
+        0,
+    )
+    .unwrap();
+    super::bind_inet(sock, &SocketAddressInet::new([127, 0, 0, 1], 0)).unwrap();
+    let addr = get_inet_sock_name(sock).unwrap();
+    (sock, addr)
+}
+
+#[test]
+fn udp_sendto_recvfrom() {
+    let (a, a_addr) = bound_udp_socket();
+    let (b, b_addr) = bound_udp_socket();
+    assert_eq!(
+        5,
+        sendto_inet(a, b"Hello", MsgFlags::empty(), Some(&b_addr)).unwrap()
+    );
+    let mut buf = [0u8; 16];
+    // Peeking leaves the datagram in the queue
+    let (peeked, from) = recvfrom_inet(b, &mut buf, MsgFlags::MSG_PEEK).unwrap();
+    assert_eq!(5, peeked);
+    assert_eq!(a_addr.ipv4_addr(), from.ipv4_addr());
+    let (read, from) = recvfrom_inet(b, &mut buf[..3], MsgFlags::MSG_TRUNC).unwrap();
+    // Real length of the truncated datagram
+    assert_eq!(5, read);
+    assert_eq!(b"Hel", &buf[..3]);
+    assert_eq!(a_addr.ipv4_addr(), from.ipv4_addr());
+    expect_errno!(
+        Errno::EAGAIN,
+        recvfrom_inet(b, &mut buf, MsgFlags::MSG_DONTWAIT)
+    );
+    // Unconnected without an address
+    expect_errno!(
+        Errno::EDESTADDRREQ,
+        sendto_inet(a, b"Hello", MsgFlags::empty(), None)
+    );
+    super::connect_inet(a, &b_addr).unwrap();
+    assert_eq!(2, sendto_inet(a, b"Hi", MsgFlags::empty(), None).unwrap());
+    let (read, _from) = recvfrom_inet(b, &mut buf, MsgFlags::empty()).unwrap();
+    assert_eq!(b"Hi", &buf[..read]);
+    close(a).unwrap();
+    close(b).unwrap();
+}
+
+#[test]
+fn udp_sendmmsg_recvmmsg() {
+    let (a, a_addr) = bound_udp_socket();
+    let (b, b_addr) = bound_udp_socket();
+    let io1 = [IoSlice::new(b"first")];
+    let io2 = [IoSlice::new(b"sec"), IoSlice::new(b"ond")];
+    let mut send = [
+        MMsgHdr::new_send(&io1, Some(&b_addr)),
+        MMsgHdr::new_send(&io2, Some(&b_addr)),
+    ];
+    assert_eq!(2, sendmmsg(a, &mut send, MsgFlags::empty()).unwrap());
+    assert_eq!(5, send[0].len());
+    assert_eq!(6, send[1].len());
+    let mut buf1 = [0u8; 8];
+    let mut buf2 = [0u8; 4];
+    let mut buf3 = [0u8; 8];
+    let mut io1 = [IoSliceMut::new(&mut buf1)];
+    let mut io2 = [IoSliceMut::new(&mut buf2)];
+    let mut io3 = [IoSliceMut::new(&mut buf3)];
+    let mut from1 = SocketAddressInet::new([0, 0, 0, 0], 0);
+    let mut recv = [
+        MMsgHdr::new_recv(&mut io1, Some(&mut from1)),
+        MMsgHdr::new_recv(&mut io2, None),
+        MMsgHdr::new_recv(&mut io3, None),
+    ];
+    // Only two messages are queued, don't wait for a third
+    assert_eq!(
+        2,
+        recvmmsg(b, &mut recv, MsgFlags::MSG_WAITFORONE, None).unwrap()
+    );
+    assert_eq!(5, recv[0].len());
+    assert!(!recv[0].flags().contains(MsgFlags::MSG_TRUNC));
+    // Truncated into the smaller buffer
+    assert_eq!(4, recv[1].len());
+    assert!(recv[1].flags().contains(MsgFlags::MSG_TRUNC));
+    assert!(recv[2].is_empty());
+    assert_eq!(b"first", &buf1[..5]);
+    assert_eq!(b"seco", &buf2);
+    assert_eq!(a_addr.ipv4_addr(), from1.ipv4_addr());
+    close(a).unwrap();
+    close(b).unwrap();
+}
+
+#[test]
+fn udp_broadcast_and_multicast_options() {
+    let (sock, _addr) = bound_udp_socket();
+    assert!(!get_broadcast(sock).unwrap());
+    set_broadcast(sock, true).unwrap();
+    assert!(get_broadcast(sock).unwrap());
+    assert!(get_multicast_loop_v4(sock).unwrap());
+    set_multicast_loop_v4(sock, false).unwrap();
+    assert!(!get_multicast_loop_v4(sock).unwrap());
+    assert_eq!(1, get_multicast_ttl_v4(sock).unwrap());
+    set_multicast_ttl_v4(sock, 8).unwrap();
+    assert_eq!(8, get_multicast_ttl_v4(sock).unwrap());
+    expect_errno!(
+        Errno::EADDRNOTAVAIL,
+        leave_multicast_v4(sock, [239, 1, 2, 3], [0, 0, 0, 0])
+    );
+    // Not a multicast address
+    expect_errno!(
+        Errno::EINVAL,
+        join_multicast_v4(sock, [127, 0, 0, 1], [0, 0, 0, 0])
+    );
+    close(sock).unwrap();
+}
+
+const LOCALHOST_V6: [u8; 16] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+#[test]
+fn inet6_connect_accept() {
+    const FIFTEEN: NonNegativeI32 = NonNegativeI32::comptime_checked_new(15);
+    let srv_sock = super::socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    super::bind_inet6(srv_sock, &SocketAddressInet6::new(LOCALHOST_V6, 0)).unwrap();
+    let bound = super::get_inet6_sock_name(srv_sock).unwrap();
+    let (ip, port) = bound.ipv6_addr();
+    assert_eq!(LOCALHOST_V6, ip);
+    assert_ne!(0, port);
+    let SocketAddressIp::V6(any) = super::get_ip_sock_name(srv_sock).unwrap() else {
+        panic!("Expected an IPv6 sock name");
+    };
+    assert_eq!((ip, port), any.ipv6_addr());
+    super::listen(srv_sock, FIFTEEN).unwrap();
+    let cl_sock = super::socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    super::connect_inet6(cl_sock, &SocketAddressInet6::new(LOCALHOST_V6, port)).unwrap();
+    let (accepted, peer) = super::accept_inet6(srv_sock, SocketFlags::SOCK_CLOEXEC).unwrap();
+    let cl_name = super::get_inet6_sock_name(cl_sock).unwrap();
+    assert_eq!(cl_name.ipv6_addr(), peer.ipv6_addr());
+    let SocketAddressIp::V6(srv_peer) = super::get_ip_peer_name(cl_sock).unwrap() else {
+        panic!("Expected an IPv6 peer name");
+    };
+    assert_eq!((ip, port), srv_peer.ipv6_addr());
+    let SocketAddressIp::V6(cl_peer) = super::get_ip_peer_name(accepted).unwrap() else {
+        panic!("Expected an IPv6 peer name");
+    };
+    assert_eq!(peer.ipv6_addr(), cl_peer.ipv6_addr());
+    expect_errno!(Errno::ENOTCONN, super::get_ip_peer_name(srv_sock));
+    assert_eq!(0, peer.flowinfo());
+    assert_eq!(0, peer.scope_id());
+    crate::unistd::write(accepted, b"v6").unwrap();
+    let mut buf = [0u8; 2];
+    crate::unistd::read(cl_sock, &mut buf).unwrap();
+    assert_eq!(b"v6", &buf);
+    close(accepted).unwrap();
+    close(cl_sock).unwrap();
+    close(srv_sock).unwrap();
+}
+
+#[test]
+fn inet6_dual_stack_accepts_v4() {
+    const FIFTEEN: NonNegativeI32 = NonNegativeI32::comptime_checked_new(15);
+    let srv_sock = super::socket(
+        AddressFamily::AF_INET6,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    set_ipv6_only(srv_sock, false).unwrap();
+    super::bind_inet6(srv_sock, &SocketAddressInet6::new([0; 16], 0)).unwrap();
+    let (_ip, port) = super::get_inet6_sock_name(srv_sock).unwrap().ipv6_addr();
+    super::listen(srv_sock, FIFTEEN).unwrap();
+    let cl_sock = super::socket(
+        AddressFamily::AF_INET,
+        SocketOptions::new(SocketType::SOCK_STREAM, SocketFlags::SOCK_CLOEXEC),
+        6,
+    )
+    .unwrap();
+    super::connect_inet(cl_sock, &SocketAddressInet::new([127, 0, 0, 1], port)).unwrap();
+    let (accepted, peer) = super::accept_ip(srv_sock, SocketFlags::SOCK_CLOEXEC).unwrap();
+    // The v4 client shows up as a v4-mapped v6 address
+    let SocketAddressIp::V6(peer) = peer else {
+        panic!("Expected a v4-mapped IPv6 address");
+    };
+    assert_eq!(
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 127, 0, 0, 1],
+        peer.ipv6_addr().0
+    );
+    // A v4 socket's name is v4
+    let SocketAddressIp::V4(cl_name) = super::get_ip_sock_name(cl_sock).unwrap() else {
+        panic!("Expected an IPv4 sock name");
+    };
+    assert_eq!(cl_name.ipv4_addr().1, peer.ipv6_addr().1);
+    close(accepted).unwrap();
+    close(cl_sock).unwrap();
+    close(srv_sock).unwrap();
+}
+
+#[test]
+fn udp6_sendto_recvfrom() {
+    let bind = |addr: &SocketAddressInet6| {
+        let sock = super::socket(
+            AddressFamily::AF_INET6,
+            SocketOptions::new(SocketType::SOCK_DGRAM, SocketFlags::SOCK_CLOEXEC),
+            0,
+        )
+        .unwrap();
+        super::bind_inet6(sock, addr).unwrap();
+        sock
+    };
+    let a = bind(&SocketAddressInet6::new(LOCALHOST_V6, 0));
+    let b = bind(&SocketAddressInet6::new(LOCALHOST_V6, 0));
+    let a_addr = super::get_inet6_sock_name(a).unwrap();
+    let b_addr = super::get_inet6_sock_name(b).unwrap();
+    assert_eq!(
+        3,
+        super::sendto_inet6(a, b"six", MsgFlags::empty(), Some(&b_addr)).unwrap()
+    );
+    let mut buf = [0u8; 8];
+    let (read, from) = super::recvfrom_ip(b, &mut buf, MsgFlags::empty()).unwrap();
+    assert_eq!(b"six", &buf[..read]);
+    let SocketAddressIp::V6(from) = from else {
+        panic!("Expected an IPv6 sender");
+    };
+    assert_eq!(a_addr.ipv6_addr(), from.ipv6_addr());
+    // Also works for v4 sockets
+    let (c, c_addr) = bound_udp_socket();
+    let (d, d_addr) = bound_udp_socket();
+    sendto_inet(c, b"four", MsgFlags::empty(), Some(&d_addr)).unwrap();
+    let (read, from) = super::recvfrom_ip(d, &mut buf, MsgFlags::empty()).unwrap();
+    assert_eq!(b"four", &buf[..read]);
+    let SocketAddressIp::V4(from) = from else {
+        panic!("Expected an IPv4 sender");
+    };
+    assert_eq!(c_addr.ipv4_addr(), from.ipv4_addr());
+    for sock in [a, b, c, d] {
+        close(sock).unwrap();
+    }
+}
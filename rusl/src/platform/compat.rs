@@ -4,19 +4,33 @@ pub use clone::*;
 pub use dirent::*;
 pub use elf::*;
 pub use epoll::*;
+pub use eventfd::*;
+pub use falloc::*;
 pub use fcntl::*;
 pub use futex::*;
 pub use hidio::*;
+pub use inotify::*;
 pub use io_uring::*;
+pub use landlock::*;
+pub use memfd::*;
 pub use mman::*;
 pub use mount::*;
+pub use pidfd::*;
 pub use poll::*;
+pub use random::*;
 pub use renameat::*;
+pub use resource::*;
+pub use sched::*;
+pub use seccomp::*;
 pub use signal::*;
+pub use signalfd::*;
 pub use socket::*;
+pub use sockopt::*;
+pub use splice::*;
 pub use stat::*;
 pub use termios::*;
 pub use time::*;
+pub use timerfd::*;
 pub use uio::*;
 pub use usb::*;
 pub use utsname::*;
@@ -27,19 +41,33 @@ mod clone;
 mod dirent;
 mod elf;
 mod epoll;
+mod eventfd;
+mod falloc;
 mod fcntl;
 mod futex;
 mod hidio;
+mod inotify;
 mod io_uring;
+mod landlock;
+mod memfd;
 mod mman;
 mod mount;
+mod pidfd;
 mod poll;
+mod random;
 mod renameat;
+mod resource;
+mod sched;
+mod seccomp;
 mod signal;
+mod signalfd;
 mod socket;
+mod sockopt;
+mod splice;
 mod stat;
 mod termios;
 mod time;
+mod timerfd;
 mod uio;
 mod usb;
 mod utsname;
@@ -0,0 +1,10 @@
+/// Not in the bindings, `EFD_SEMAPHORE` from `include/uapi/linux/eventfd.h`, the others
+/// are the same as their `O_` counterparts.
+transparent_bitflags! {
+    pub struct EventFdFlags: i32 {
+        const DEFAULT = 0;
+        const EFD_SEMAPHORE = 1;
+        const EFD_CLOEXEC = linux_rust_bindings::fcntl::O_CLOEXEC;
+        const EFD_NONBLOCK = linux_rust_bindings::fcntl::O_NONBLOCK;
+    }
+}
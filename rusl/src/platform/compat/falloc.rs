@@ -0,0 +1,14 @@
+/// Modes for [`fallocate`](https://man7.org/linux/man-pages/man2/fallocate.2.html).
+/// Not in the bindings, values from `include/uapi/linux/falloc.h`
+transparent_bitflags! {
+    pub struct FallocateFlags: i32 {
+        const DEFAULT = 0;
+        const FALLOC_FL_KEEP_SIZE = 0x01;
+        const FALLOC_FL_PUNCH_HOLE = 0x02;
+        const FALLOC_FL_NO_HIDE_STALE = 0x04;
+        const FALLOC_FL_COLLAPSE_RANGE = 0x08;
+        const FALLOC_FL_ZERO_RANGE = 0x10;
+        const FALLOC_FL_INSERT_RANGE = 0x20;
+        const FALLOC_FL_UNSHARE_RANGE = 0x40;
+    }
+}
@@ -15,6 +15,40 @@ impl FcntlFileStatusCmd {
     }
 }
 
+/// Seals that can be placed on a file created by [`memfd_create`](https://man7.org/linux/man-pages/man2/memfd_create.2.html)
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+transparent_bitflags! {
+    pub struct SealFlags: i32 {
+        const DEFAULT = 0;
+        const F_SEAL_SEAL = linux_rust_bindings::fcntl::F_SEAL_SEAL;
+        const F_SEAL_SHRINK = linux_rust_bindings::fcntl::F_SEAL_SHRINK;
+        const F_SEAL_GROW = linux_rust_bindings::fcntl::F_SEAL_GROW;
+        const F_SEAL_WRITE = linux_rust_bindings::fcntl::F_SEAL_WRITE;
+        const F_SEAL_FUTURE_WRITE = linux_rust_bindings::fcntl::F_SEAL_FUTURE_WRITE;
+        const F_SEAL_EXEC = linux_rust_bindings::fcntl::F_SEAL_EXEC;
+    }
+}
+
+/// Flags for [`sync_file_range`](https://man7.org/linux/man-pages/man2/sync_file_range.2.html).
+/// Not in the bindings, values from `include/uapi/linux/fs.h`
+transparent_bitflags! {
+    pub struct SyncFileRangeFlags: u32 {
+        const DEFAULT = 0;
+        const SYNC_FILE_RANGE_WAIT_BEFORE = 1;
+        const SYNC_FILE_RANGE_WRITE = 2;
+        const SYNC_FILE_RANGE_WAIT_AFTER = 4;
+    }
+}
+
+/// Flags for [`linkat`](https://man7.org/linux/man-pages/man2/link.2.html)
+transparent_bitflags! {
+    pub struct LinkFlags: i32 {
+        const DEFAULT = 0;
+        const AT_EMPTY_PATH = linux_rust_bindings::fcntl::AT_EMPTY_PATH;
+        const AT_SYMLINK_FOLLOW = linux_rust_bindings::fcntl::AT_SYMLINK_FOLLOW;
+    }
+}
+
 pub const AT_FDCWD: i32 = linux_rust_bindings::fcntl::AT_FDCWD;
 pub const AT_REMOVEDIR: NonNegativeI32 =
     NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_REMOVEDIR);
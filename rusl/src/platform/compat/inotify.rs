@@ -0,0 +1,113 @@
+use crate::string::unix_str::UnixStr;
+
+transparent_bitflags! {
+    pub struct InotifyFlags: i32 {
+        const DEFAULT = 0;
+        const IN_CLOEXEC = linux_rust_bindings::fcntl::O_CLOEXEC;
+        const IN_NONBLOCK = linux_rust_bindings::fcntl::O_NONBLOCK;
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/linux/inotify.h`
+transparent_bitflags! {
+    pub struct InotifyMask: u32 {
+        const DEFAULT = 0;
+        const IN_ACCESS = 0x0000_0001;
+        const IN_MODIFY = 0x0000_0002;
+        const IN_ATTRIB = 0x0000_0004;
+        const IN_CLOSE_WRITE = 0x0000_0008;
+        const IN_CLOSE_NOWRITE = 0x0000_0010;
+        const IN_OPEN = 0x0000_0020;
+        const IN_MOVED_FROM = 0x0000_0040;
+        const IN_MOVED_TO = 0x0000_0080;
+        const IN_CREATE = 0x0000_0100;
+        const IN_DELETE = 0x0000_0200;
+        const IN_DELETE_SELF = 0x0000_0400;
+        const IN_MOVE_SELF = 0x0000_0800;
+        const IN_UNMOUNT = 0x0000_2000;
+        const IN_Q_OVERFLOW = 0x0000_4000;
+        const IN_IGNORED = 0x0000_8000;
+        const IN_CLOSE = 0x0000_0018;
+        const IN_MOVE = 0x0000_00c0;
+        const IN_ALL_EVENTS = 0x0000_0fff;
+        const IN_ONLYDIR = 0x0100_0000;
+        const IN_DONT_FOLLOW = 0x0200_0000;
+        const IN_EXCL_UNLINK = 0x0400_0000;
+        const IN_MASK_CREATE = 0x1000_0000;
+        const IN_MASK_ADD = 0x2000_0000;
+        const IN_ISDIR = 0x4000_0000;
+        const IN_ONESHOT = 0x8000_0000;
+    }
+}
+
+/// A parsed `struct inotify_event`, the name is only present for events on
+/// entries inside a watched directory.
+#[derive(Debug, Copy, Clone)]
+pub struct InotifyEvent<'a> {
+    pub wd: i32,
+    pub mask: InotifyMask,
+    pub cookie: u32,
+    pub name: Option<&'a UnixStr>,
+}
+
+impl<'a> InotifyEvent<'a> {
+    /// The size of the fixed part of an `inotify_event`, `wd`, `mask`, `cookie`, and `len`
+    pub const HEADER_SIZE: usize = 16;
+    /// A buffer of at least this size is guaranteed to fit at least one event
+    pub const MIN_BUF_SIZE: usize = Self::HEADER_SIZE + 256;
+
+    /// Try to parse an event from the start of a buffer filled by reading an inotify fd,
+    /// returns the event and the number of bytes it occupied, the next event starts there.
+    /// Returns `None` if there isn't a complete event at the start of the buffer.
+    #[must_use]
+    pub fn try_from_bytes(buf: &'a [u8]) -> Option<(Self, usize)> {
+        let header = buf.get(..Self::HEADER_SIZE)?;
+        let wd = i32::from_ne_bytes(header[0..4].try_into().ok()?);
+        let mask = u32::from_ne_bytes(header[4..8].try_into().ok()?);
+        let cookie = u32::from_ne_bytes(header[8..12].try_into().ok()?);
+        let len = u32::from_ne_bytes(header[12..16].try_into().ok()?) as usize;
+        let total = Self::HEADER_SIZE + len;
+        let name_bytes = buf.get(Self::HEADER_SIZE..total)?;
+        // The name is null-padded to alignment, it's absent if `len` is 0
+        let name = name_bytes
+            .iter()
+            .position(|b| *b == crate::platform::NULL_BYTE)
+            .map(|nul| unsafe { UnixStr::from_bytes_unchecked(&name_bytes[..=nul]) });
+        Some((
+            Self {
+                wd,
+                mask: InotifyMask(mask),
+                cookie,
+                name,
+            },
+            total,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_events() {
+        let mut buf = [0u8; 16 + 8 + 16];
+        buf[0..4].copy_from_slice(&1i32.to_ne_bytes());
+        buf[4..8].copy_from_slice(&InotifyMask::IN_CREATE.bits().to_ne_bytes());
+        buf[12..16].copy_from_slice(&8u32.to_ne_bytes());
+        buf[16..19].copy_from_slice(b"abc");
+        buf[24..28].copy_from_slice(&2i32.to_ne_bytes());
+        buf[28..32].copy_from_slice(&InotifyMask::IN_DELETE_SELF.bits().to_ne_bytes());
+        let (first, used) = InotifyEvent::try_from_bytes(&buf).unwrap();
+        assert_eq!(24, used);
+        assert_eq!(1, first.wd);
+        assert!(first.mask.contains(InotifyMask::IN_CREATE));
+        assert_eq!("abc", first.name.unwrap().as_str().unwrap());
+        let (second, used) = InotifyEvent::try_from_bytes(&buf[used..]).unwrap();
+        assert_eq!(16, used);
+        assert_eq!(2, second.wd);
+        assert!(second.mask.contains(InotifyMask::IN_DELETE_SELF));
+        assert!(second.name.is_none());
+        assert!(InotifyEvent::try_from_bytes(&buf[..10]).is_none());
+    }
+}
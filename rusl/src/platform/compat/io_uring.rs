@@ -11,9 +11,10 @@ use linux_rust_bindings::io_uring::{
 };
 
 use crate::platform::{
-    comptime_i32_to_u32, comptime_u32_to_u8, AddressFamily, Fd, Mode, OpenFlags, PollEvents,
-    RenameFlags, SocketAddressInet, SocketAddressUnix, SocketArgUnix, SocketFlags, SocketOptions,
-    Statx, StatxFlags, StatxMask, TimeSpec, AT_FDCWD, AT_REMOVEDIR,
+    comptime_i32_to_u32, comptime_u32_to_u8, AddressFamily, Fd, LinkFlags, Mode, OpenFlags,
+    PollEvents, RenameFlags, SocketAddressInet, SocketAddressInet6, SocketAddressUnix,
+    SocketArgUnix, SocketFlags, SocketOptions, Statx, StatxFlags, StatxMask, TimeSpec, AT_FDCWD,
+    AT_REMOVEDIR,
 };
 use crate::string::unix_str::UnixStr;
 use crate::unistd::munmap;
@@ -544,6 +545,85 @@ impl IoUringSubmissionQueueEntry {
         })
     }
 
+    /// Creates a new entry that will execute an equivalent to a `symlinkat` syscall.  
+    /// # Safety
+    /// The references to `target` and `link_path` needs to live until this entry is submitted to the kernel.  
+    #[inline]
+    #[must_use]
+    pub unsafe fn new_symlinkat(
+        target: &UnixStr,
+        dir_fd: Option<Fd>,
+        link_path: &UnixStr,
+        user_data: u64,
+        sqe_flags: IoUringSQEFlags,
+    ) -> Self {
+        Self(io_uring_sqe {
+            opcode: IoUringOp::Symlinkat as u8,
+            flags: sqe_flags.bits(),
+            ioprio: 0,
+            fd: unpack_dir_fd(dir_fd),
+            __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
+                addr2: link_path.0.as_ptr() as u64,
+            },
+            __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
+                addr: target.0.as_ptr() as u64,
+            },
+            len: 0,
+            __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 { rename_flags: 0 },
+            user_data,
+            __bindgen_anon_4: io_uring_sqe__bindgen_ty_4 { buf_index: 0 },
+            personality: 0,
+            __bindgen_anon_5: io_uring_sqe__bindgen_ty_5 { file_index: 0 },
+            __bindgen_anon_6: io_uring_sqe__bindgen_ty_6 {
+                __bindgen_anon_1: __BindgenUnionField::new(),
+                cmd: __BindgenUnionField::new(),
+                bindgen_union_field: [0; 2],
+            },
+        })
+    }
+
+    /// Creates a new entry that will execute an equivalent to a `linkat` syscall.  
+    /// # Safety
+    /// The references to `old_path` and `new_path` needs to live until this entry is submitted to the kernel.  
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_sign_loss)]
+    pub unsafe fn new_linkat(
+        old_dir_fd: Option<Fd>,
+        old_path: &UnixStr,
+        new_dir_fd: Option<Fd>,
+        new_path: &UnixStr,
+        flags: LinkFlags,
+        user_data: u64,
+        sqe_flags: IoUringSQEFlags,
+    ) -> Self {
+        Self(io_uring_sqe {
+            opcode: IoUringOp::Linkat as u8,
+            flags: sqe_flags.bits(),
+            ioprio: 0,
+            fd: unpack_dir_fd(old_dir_fd),
+            __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
+                addr2: new_path.0.as_ptr() as u64,
+            },
+            __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
+                addr: old_path.0.as_ptr() as u64,
+            },
+            len: unpack_dir_fd(new_dir_fd) as u32,
+            __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 {
+                hardlink_flags: flags.bits() as u32,
+            },
+            user_data,
+            __bindgen_anon_4: io_uring_sqe__bindgen_ty_4 { buf_index: 0 },
+            personality: 0,
+            __bindgen_anon_5: io_uring_sqe__bindgen_ty_5 { file_index: 0 },
+            __bindgen_anon_6: io_uring_sqe__bindgen_ty_6 {
+                __bindgen_anon_1: __BindgenUnionField::new(),
+                cmd: __BindgenUnionField::new(),
+                bindgen_union_field: [0; 2],
+            },
+        })
+    }
+
     /// Creates a new socket. Will execute an equivalent to an `socket2` syscall.  
     #[inline]
     #[must_use]
@@ -592,8 +672,9 @@ impl IoUringSubmissionQueueEntry {
             flags: sqe_flags.bits(),
             ioprio: 0,
             fd: socket.0,
+            // The address length goes in `addr2` by value
             __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
-                off: core::ptr::addr_of!(sockaddr.addr_len) as u64,
+                off: sockaddr.addr_len as u64,
             },
             __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
                 addr: core::ptr::addr_of!(sockaddr.addr) as u64,
@@ -632,10 +713,10 @@ impl IoUringSubmissionQueueEntry {
             ioprio: 0,
             fd: socket.0,
             __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
-                addr2: sockaddr as u64,
+                addr2: addr_len as u64,
             },
             __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
-                addr: addr_len as u64,
+                addr: sockaddr as u64,
             },
             len: 0,
             __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 {
@@ -673,10 +754,10 @@ impl IoUringSubmissionQueueEntry {
             ioprio: 0,
             fd: socket.0,
             __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
-                addr2: sockaddr as u64,
+                addr2: addr_len as u64,
             },
             __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
-                addr: addr_len as u64,
+                addr: sockaddr as u64,
             },
             len: 0,
             __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 {
@@ -694,6 +775,83 @@ impl IoUringSubmissionQueueEntry {
         })
     }
 
+    /// Accepts a new IPv6 socket connection. Will execute an equivalent to an `accept4` syscall.
+    /// # Safety
+    /// `sockaddr` and `addr_len` needs to live until the kernel has processed this accept call
+    /// (accepted a client and returned a CQE).
+    #[inline]
+    #[must_use]
+    pub unsafe fn new_accept_inet6(
+        socket: Fd,
+        sockaddr: *mut SocketAddressInet6,
+        addr_len: *mut u64,
+        socket_flags: SocketFlags,
+        user_data: u64,
+        sqe_flags: IoUringSQEFlags,
+    ) -> Self {
+        Self(io_uring_sqe {
+            opcode: IoUringOp::Accept as u8,
+            flags: sqe_flags.bits(),
+            ioprio: 0,
+            fd: socket.0,
+            __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
+                addr2: addr_len as u64,
+            },
+            __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
+                addr: sockaddr as u64,
+            },
+            len: 0,
+            __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 {
+                accept_flags: socket_flags.0,
+            },
+            user_data,
+            __bindgen_anon_4: io_uring_sqe__bindgen_ty_4 { buf_index: 0 },
+            personality: 0,
+            __bindgen_anon_5: io_uring_sqe__bindgen_ty_5 { file_index: 0 },
+            __bindgen_anon_6: io_uring_sqe__bindgen_ty_6 {
+                __bindgen_anon_1: __BindgenUnionField::default(),
+                cmd: __BindgenUnionField::default(),
+                bindgen_union_field: [0; 2],
+            },
+        })
+    }
+
+    /// Connects an IPv6 socket. Will execute an equivalent to a `connect` syscall.
+    /// # Safety
+    /// `sockaddr` needs to live until this entry is passed to the kernel
+    #[inline]
+    #[must_use]
+    pub unsafe fn new_connect_inet6(
+        socket: Fd,
+        sockaddr: &SocketAddressInet6,
+        user_data: u64,
+        sqe_flags: IoUringSQEFlags,
+    ) -> Self {
+        Self(io_uring_sqe {
+            opcode: IoUringOp::Connect as u8,
+            flags: sqe_flags.bits(),
+            ioprio: 0,
+            fd: socket.0,
+            __bindgen_anon_1: io_uring_sqe__bindgen_ty_1 {
+                off: SocketAddressInet6::LENGTH as u64,
+            },
+            __bindgen_anon_2: io_uring_sqe__bindgen_ty_2 {
+                addr: core::ptr::from_ref::<SocketAddressInet6>(sockaddr) as u64,
+            },
+            len: 0,
+            __bindgen_anon_3: io_uring_sqe__bindgen_ty_3 { rw_flags: 0 },
+            user_data,
+            __bindgen_anon_4: io_uring_sqe__bindgen_ty_4 { buf_index: 0 },
+            personality: 0,
+            __bindgen_anon_5: io_uring_sqe__bindgen_ty_5 { file_index: 0 },
+            __bindgen_anon_6: io_uring_sqe__bindgen_ty_6 {
+                __bindgen_anon_1: __BindgenUnionField::new(),
+                cmd: __BindgenUnionField::new(),
+                bindgen_union_field: [0; 2],
+            },
+        })
+    }
+
     /// Enters a timeout entry, will produce a cqe with result `-ETIME` on elapse or 0
     /// if `await_completions` is specified and that number of cqes have completed during
     /// the timeout duration.  
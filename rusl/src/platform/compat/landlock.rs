@@ -0,0 +1,84 @@
+//! Not in the bindings, values from `include/uapi/linux/landlock.h`
+
+/// Rights that a ruleset handles, anything handled is denied unless a rule allows it
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LandlockRulesetAttr {
+    pub handled_access_fs: LandlockAccessFs,
+    /// Requires ABI version 4
+    pub handled_access_net: LandlockAccessNet,
+}
+
+/// Allow `allowed_access` on the file or directory hierarchy at `parent_fd`,
+/// which is preferably opened with `O_PATH`
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct LandlockPathBeneathAttr {
+    pub allowed_access: LandlockAccessFs,
+    pub parent_fd: i32,
+}
+
+/// Allow `allowed_access` on a TCP `port`, requires ABI version 4
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct LandlockNetPortAttr {
+    pub allowed_access: LandlockAccessNet,
+    pub port: u64,
+}
+
+transparent_bitflags! {
+    pub struct LandlockAccessFs: u64 {
+        const DEFAULT = 0;
+        const LANDLOCK_ACCESS_FS_EXECUTE = 1;
+        const LANDLOCK_ACCESS_FS_WRITE_FILE = 1 << 1;
+        const LANDLOCK_ACCESS_FS_READ_FILE = 1 << 2;
+        const LANDLOCK_ACCESS_FS_READ_DIR = 1 << 3;
+        const LANDLOCK_ACCESS_FS_REMOVE_DIR = 1 << 4;
+        const LANDLOCK_ACCESS_FS_REMOVE_FILE = 1 << 5;
+        const LANDLOCK_ACCESS_FS_MAKE_CHAR = 1 << 6;
+        const LANDLOCK_ACCESS_FS_MAKE_DIR = 1 << 7;
+        const LANDLOCK_ACCESS_FS_MAKE_REG = 1 << 8;
+        const LANDLOCK_ACCESS_FS_MAKE_SOCK = 1 << 9;
+        const LANDLOCK_ACCESS_FS_MAKE_FIFO = 1 << 10;
+        const LANDLOCK_ACCESS_FS_MAKE_BLOCK = 1 << 11;
+        const LANDLOCK_ACCESS_FS_MAKE_SYM = 1 << 12;
+        /// ABI version 2
+        const LANDLOCK_ACCESS_FS_REFER = 1 << 13;
+        /// ABI version 3
+        const LANDLOCK_ACCESS_FS_TRUNCATE = 1 << 14;
+        /// ABI version 5
+        const LANDLOCK_ACCESS_FS_IOCTL_DEV = 1 << 15;
+    }
+}
+
+impl LandlockAccessFs {
+    /// The rights that apply to files, as opposed to directories
+    pub const FILE_ACCESS: Self = Self(
+        Self::LANDLOCK_ACCESS_FS_EXECUTE.0
+            | Self::LANDLOCK_ACCESS_FS_WRITE_FILE.0
+            | Self::LANDLOCK_ACCESS_FS_READ_FILE.0
+            | Self::LANDLOCK_ACCESS_FS_TRUNCATE.0
+            | Self::LANDLOCK_ACCESS_FS_IOCTL_DEV.0,
+    );
+
+    /// The rights supported by a given ABI version
+    #[must_use]
+    pub const fn supported_by_abi(abi: u32) -> Self {
+        let bits = match abi {
+            0 => 0,
+            1 => (1 << 13) - 1,
+            2 => (1 << 14) - 1,
+            3 | 4 => (1 << 15) - 1,
+            _ => (1 << 16) - 1,
+        };
+        Self(bits)
+    }
+}
+
+transparent_bitflags! {
+    pub struct LandlockAccessNet: u64 {
+        const DEFAULT = 0;
+        const LANDLOCK_ACCESS_NET_BIND_TCP = 1;
+        const LANDLOCK_ACCESS_NET_CONNECT_TCP = 1 << 1;
+    }
+}
@@ -0,0 +1,11 @@
+/// Not in the bindings, values from `include/uapi/linux/memfd.h`.
+transparent_bitflags! {
+    pub struct MemfdFlags: u32 {
+        const DEFAULT = 0;
+        const MFD_CLOEXEC = 0x0001;
+        const MFD_ALLOW_SEALING = 0x0002;
+        const MFD_HUGETLB = 0x0004;
+        const MFD_NOEXEC_SEAL = 0x0008;
+        const MFD_EXEC = 0x0010;
+    }
+}
@@ -67,3 +67,100 @@ transparent_bitflags! {
         const MAP_HUGE_16GB = linux_rust_bindings::mman::MAP_HUGE_16GB as u32;
     }
 }
+
+/// Advice for [`madvise`](https://man7.org/linux/man-pages/man2/madvise.2.html).
+/// Not in the bindings, values from `include/uapi/asm-generic/mman-common.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MemoryAdvice {
+    Normal,
+    Random,
+    Sequential,
+    WillNeed,
+    DontNeed,
+    Free,
+    Remove,
+    DontFork,
+    DoFork,
+    Mergeable,
+    Unmergeable,
+    HugePage,
+    NoHugePage,
+    DontDump,
+    DoDump,
+    WipeOnFork,
+    KeepOnFork,
+    Cold,
+    PageOut,
+    PopulateRead,
+    PopulateWrite,
+    DontNeedLocked,
+    Collapse,
+}
+
+impl MemoryAdvice {
+    pub(crate) const fn into_raw(self) -> i32 {
+        match self {
+            Self::Normal => 0,
+            Self::Random => 1,
+            Self::Sequential => 2,
+            Self::WillNeed => 3,
+            Self::DontNeed => 4,
+            Self::Free => 8,
+            Self::Remove => 9,
+            Self::DontFork => 10,
+            Self::DoFork => 11,
+            Self::Mergeable => 12,
+            Self::Unmergeable => 13,
+            Self::HugePage => 14,
+            Self::NoHugePage => 15,
+            Self::DontDump => 16,
+            Self::DoDump => 17,
+            Self::WipeOnFork => 18,
+            Self::KeepOnFork => 19,
+            Self::Cold => 20,
+            Self::PageOut => 21,
+            Self::PopulateRead => 22,
+            Self::PopulateWrite => 23,
+            Self::DontNeedLocked => 24,
+            Self::Collapse => 25,
+        }
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/linux/mman.h`
+transparent_bitflags! {
+    pub struct MremapFlags: u32 {
+        const DEFAULT = 0;
+        const MREMAP_MAYMOVE = 1;
+        const MREMAP_FIXED = 2;
+        const MREMAP_DONTUNMAP = 4;
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/asm-generic/mman-common.h`
+transparent_bitflags! {
+    pub struct MsyncFlags: i32 {
+        const DEFAULT = 0;
+        const MS_ASYNC = 1;
+        const MS_INVALIDATE = 2;
+        const MS_SYNC = 4;
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/asm-generic/mman-common.h`
+transparent_bitflags! {
+    pub struct MlockFlags: u32 {
+        const DEFAULT = 0;
+        const MLOCK_ONFAULT = 1;
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/asm-generic/mman.h`
+transparent_bitflags! {
+    pub struct MlockAllFlags: i32 {
+        const DEFAULT = 0;
+        const MCL_CURRENT = 1;
+        const MCL_FUTURE = 2;
+        const MCL_ONFAULT = 4;
+    }
+}
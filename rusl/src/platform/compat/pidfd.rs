@@ -0,0 +1,6 @@
+transparent_bitflags! {
+    pub struct PidFdOpenFlags: u32 {
+        const DEFAULT = 0;
+        const PIDFD_NONBLOCK = linux_rust_bindings::fcntl::O_NONBLOCK as u32;
+    }
+}
@@ -0,0 +1,12 @@
+/// Not in the bindings, values from `include/uapi/linux/random.h`
+transparent_bitflags! {
+    pub struct GetRandomFlags: u32 {
+        const DEFAULT = 0;
+        /// Return `EAGAIN` instead of blocking if the entropy pool isn't initialized
+        const GRND_NONBLOCK = 0x0001;
+        /// Legacy, draw from the blocking pool, same as no flags since 5.6
+        const GRND_RANDOM = 0x0002;
+        /// Never block, even if the entropy pool isn't initialized, since 5.6
+        const GRND_INSECURE = 0x0004;
+    }
+}
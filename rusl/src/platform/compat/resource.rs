@@ -0,0 +1,82 @@
+/// A resource to limit with `prlimit64`, not in the bindings, values from
+/// `include/uapi/asm-generic/resource.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Resource {
+    /// CPU time in seconds
+    Cpu,
+    /// Max size of files the process creates, in bytes
+    Fsize,
+    /// Max size of the data segment, in bytes
+    Data,
+    /// Max size of the main thread's stack, in bytes
+    Stack,
+    /// Max size of core dumps, in bytes, 0 disables them
+    Core,
+    /// Resident set size, has no effect
+    Rss,
+    /// Max number of threads for the real user id
+    Nproc,
+    /// One more than the highest file descriptor number that can be opened
+    Nofile,
+    /// Max bytes of memory that can be locked with `mlock`
+    Memlock,
+    /// Max size of the virtual address space, in bytes
+    As,
+    /// Max number of file locks, has no effect
+    Locks,
+    /// Max number of queued signals for the real user id
+    Sigpending,
+    /// Max bytes for POSIX message queues for the real user id
+    Msgqueue,
+    /// Ceiling for raising the nice value, as `20 - limit`
+    Nice,
+    /// Ceiling for the real-time priority
+    Rtprio,
+    /// CPU time in microseconds for real-time scheduled processes without blocking
+    Rttime,
+}
+
+impl Resource {
+    #[inline]
+    #[must_use]
+    pub(crate) const fn into_raw(self) -> i32 {
+        match self {
+            Resource::Cpu => 0,
+            Resource::Fsize => 1,
+            Resource::Data => 2,
+            Resource::Stack => 3,
+            Resource::Core => 4,
+            Resource::Rss => 5,
+            Resource::Nproc => 6,
+            Resource::Nofile => 7,
+            Resource::Memlock => 8,
+            Resource::As => 9,
+            Resource::Locks => 10,
+            Resource::Sigpending => 11,
+            Resource::Msgqueue => 12,
+            Resource::Nice => 13,
+            Resource::Rtprio => 14,
+            Resource::Rttime => 15,
+        }
+    }
+}
+
+/// A soft (`cur`) and hard (`max`) limit, the soft limit is what's enforced and can be raised
+/// up to the hard limit, raising the hard limit requires `CAP_SYS_RESOURCE`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RLimit {
+    pub cur: u64,
+    pub max: u64,
+}
+
+impl RLimit {
+    /// No limit
+    pub const INFINITY: u64 = u64::MAX;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(cur: u64, max: u64) -> Self {
+        Self { cur, max }
+    }
+}
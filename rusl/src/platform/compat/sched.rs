@@ -0,0 +1,143 @@
+/// A set of CPUs, like `cpu_set_t` it fits CPUs `0..1024`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct CpuSet {
+    bits: [u64; 16],
+}
+
+impl CpuSet {
+    /// The number of CPUs that fit in the set, CPUs above this are ignored
+    pub const MAX_CPUS: usize = 1024;
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bits: [0; 16] }
+    }
+
+    /// Add a CPU to this set
+    #[inline]
+    pub fn add(&mut self, cpu: usize) -> &mut Self {
+        if cpu < Self::MAX_CPUS {
+            self.bits[cpu / 64] |= 1 << (cpu % 64);
+        }
+        self
+    }
+
+    /// Remove a CPU from this set
+    #[inline]
+    pub fn remove(&mut self, cpu: usize) -> &mut Self {
+        if cpu < Self::MAX_CPUS {
+            self.bits[cpu / 64] &= !(1 << (cpu % 64));
+        }
+        self
+    }
+
+    /// Check if this set contains a CPU
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, cpu: usize) -> bool {
+        cpu < Self::MAX_CPUS && self.bits[cpu / 64] & (1 << (cpu % 64)) != 0
+    }
+
+    /// The number of CPUs in this set
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Check if this set contains no CPUs
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|word| *word == 0)
+    }
+
+    /// Iterate over the CPUs in this set, in ascending order
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Self::MAX_CPUS).filter(|cpu| self.contains(*cpu))
+    }
+
+    #[inline]
+    pub(crate) const fn byte_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+}
+
+/// A scheduling policy, not in the bindings, values from `include/uapi/linux/sched.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SchedPolicy {
+    /// The default time-sharing policy
+    Other,
+    /// Real-time first in first out
+    Fifo,
+    /// Real-time round robin
+    Rr,
+    /// Like `Other`, for CPU-bound non-interactive work
+    Batch,
+    /// Lower priority than a nice value of 19
+    Idle,
+    /// Requires parameters that `sched_setscheduler` can't provide, only reported
+    Deadline,
+}
+
+impl SchedPolicy {
+    /// Set on the raw policy to reset to the default policy in children
+    pub(crate) const SCHED_RESET_ON_FORK: i32 = 0x4000_0000;
+
+    #[inline]
+    #[must_use]
+    pub(crate) const fn into_raw(self) -> i32 {
+        match self {
+            SchedPolicy::Other => 0,
+            SchedPolicy::Fifo => 1,
+            SchedPolicy::Rr => 2,
+            SchedPolicy::Batch => 3,
+            SchedPolicy::Idle => 5,
+            SchedPolicy::Deadline => 6,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) const fn from_raw(raw: i32) -> Option<Self> {
+        match raw & !Self::SCHED_RESET_ON_FORK {
+            0 => Some(SchedPolicy::Other),
+            1 => Some(SchedPolicy::Fifo),
+            2 => Some(SchedPolicy::Rr),
+            3 => Some(SchedPolicy::Batch),
+            5 => Some(SchedPolicy::Idle),
+            6 => Some(SchedPolicy::Deadline),
+            _ => None,
+        }
+    }
+}
+
+/// What the `who` of `getpriority`/`setpriority` refers to,
+/// not in the bindings, values from `include/uapi/linux/resource.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PriorityWhich {
+    /// A process id, or the calling thread if 0
+    Process,
+    /// A process group id, or the caller's process group if 0
+    Pgrp,
+    /// A user id, or the caller's real user id if 0
+    User,
+}
+
+impl PriorityWhich {
+    #[inline]
+    #[must_use]
+    pub(crate) const fn into_raw(self) -> i32 {
+        match self {
+            PriorityWhich::Process => 0,
+            PriorityWhich::Pgrp => 1,
+            PriorityWhich::User => 2,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Not in the bindings, values from `include/uapi/linux/seccomp.h`, `include/uapi/linux/filter.h`,
+//! `include/uapi/linux/bpf_common.h` and `include/uapi/linux/audit.h`
+
+/// A single classic BPF instruction
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SockFilter {
+    pub code: u16,
+    pub jt: u8,
+    pub jf: u8,
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// A statement, an instruction that doesn't jump
+    #[inline]
+    #[must_use]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// A conditional jump, skipping `jt` instructions if true, otherwise `jf`
+    #[inline]
+    #[must_use]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+/// A classic BPF program, borrows its instructions
+#[repr(C)]
+#[derive(Debug)]
+pub struct SockFprog<'a> {
+    len: u16,
+    filter: *const SockFilter,
+    _pd: core::marker::PhantomData<&'a [SockFilter]>,
+}
+
+impl<'a> SockFprog<'a> {
+    /// Returns `None` if there are more than `BPF_MAXINSNS` instructions
+    #[inline]
+    #[must_use]
+    pub fn new(filter: &'a [SockFilter]) -> Option<Self> {
+        if filter.len() > BPF_MAXINSNS {
+            return None;
+        }
+        Some(Self {
+            len: filter.len() as u16,
+            filter: filter.as_ptr(),
+            _pd: core::marker::PhantomData,
+        })
+    }
+}
+
+pub const BPF_MAXINSNS: usize = 4096;
+
+// Instruction classes
+pub const BPF_LD: u16 = 0x00;
+pub const BPF_ALU: u16 = 0x04;
+pub const BPF_JMP: u16 = 0x05;
+pub const BPF_RET: u16 = 0x06;
+// Sizes
+pub const BPF_W: u16 = 0x00;
+// Modes
+pub const BPF_ABS: u16 = 0x20;
+// Alu and jump operations
+pub const BPF_AND: u16 = 0x50;
+pub const BPF_JA: u16 = 0x00;
+pub const BPF_JEQ: u16 = 0x10;
+pub const BPF_JGT: u16 = 0x20;
+pub const BPF_JGE: u16 = 0x30;
+pub const BPF_JSET: u16 = 0x40;
+// Sources
+pub const BPF_K: u16 = 0x00;
+
+/// Offsets into the `struct seccomp_data` that filters operate on
+pub const SECCOMP_DATA_NR_OFFSET: u32 = 0;
+pub const SECCOMP_DATA_ARCH_OFFSET: u32 = 4;
+pub const SECCOMP_DATA_IP_OFFSET: u32 = 8;
+pub const SECCOMP_DATA_ARGS_OFFSET: u32 = 16;
+
+// Filter return values, the highest 16 bits are the action, the lowest the data
+pub const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+pub const SECCOMP_RET_KILL_THREAD: u32 = 0x0000_0000;
+pub const SECCOMP_RET_TRAP: u32 = 0x0003_0000;
+pub const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+pub const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+pub const SECCOMP_RET_TRACE: u32 = 0x7ff0_0000;
+pub const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+pub const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+pub const SECCOMP_RET_DATA: u32 = 0x0000_ffff;
+
+pub const AUDIT_ARCH_X86_64: u32 = 0xc000_003e;
+pub const AUDIT_ARCH_AARCH64: u32 = 0xc000_00b7;
+/// The audit arch of the target this is compiled for
+#[cfg(target_arch = "x86_64")]
+pub const AUDIT_ARCH_NATIVE: u32 = AUDIT_ARCH_X86_64;
+#[cfg(target_arch = "aarch64")]
+pub const AUDIT_ARCH_NATIVE: u32 = AUDIT_ARCH_AARCH64;
+/// Set on syscall numbers of the x32 ABI, which shares the `x86_64` audit arch
+pub const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+transparent_bitflags! {
+    pub struct SeccompFlags: u32 {
+        const DEFAULT = 0;
+        /// Synchronize the filter to all threads of the process
+        const SECCOMP_FILTER_FLAG_TSYNC = 1;
+        /// Log all actions except `SECCOMP_RET_ALLOW`
+        const SECCOMP_FILTER_FLAG_LOG = 1 << 1;
+        /// Disable speculative store bypass mitigation
+        const SECCOMP_FILTER_FLAG_SPEC_ALLOW = 1 << 2;
+        /// Return a notification fd for `SECCOMP_RET_USER_NOTIF`
+        const SECCOMP_FILTER_FLAG_NEW_LISTENER = 1 << 3;
+        /// With `TSYNC`, fail with `ESRCH` instead of returning the thread id that couldn't be synced
+        const SECCOMP_FILTER_FLAG_TSYNC_ESRCH = 1 << 4;
+    }
+}
@@ -113,6 +113,25 @@ impl SignalKind {
     pub const SIGSTKSZ: Self = Self(NonNegativeI32::comptime_checked_new(
         linux_rust_bindings::signal::SIGSTKSZ,
     ));
+
+    /// Create a `SignalKind` from a raw signal number,
+    /// returns `None` if it's outside of the valid range `1..=64`.
+    #[inline]
+    #[must_use]
+    pub const fn from_raw(signo: i32) -> Option<Self> {
+        if signo > 0 && signo <= SIGSET_SIGNALS {
+            Some(Self(NonNegativeI32(signo)))
+        } else {
+            None
+        }
+    }
+
+    /// Get the raw signal number
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        self.0.value()
+    }
 }
 
 transparent_bitflags! {
@@ -163,6 +182,51 @@ impl SigSetT {
             ],
         }
     }
+
+    /// Add a signal to this set
+    #[inline]
+    pub fn add(&mut self, signal: SignalKind) -> &mut Self {
+        let mask = self.mask() | Self::bit(signal);
+        self.__val[0] = MaybeUninit::new(mask);
+        self
+    }
+
+    /// Remove a signal from this set
+    #[inline]
+    pub fn remove(&mut self, signal: SignalKind) -> &mut Self {
+        let mask = self.mask() & !Self::bit(signal);
+        self.__val[0] = MaybeUninit::new(mask);
+        self
+    }
+
+    /// Check if this set contains a signal
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, signal: SignalKind) -> bool {
+        self.mask() & Self::bit(signal) != 0
+    }
+
+    /// Check if this set contains no signals
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.mask() == 0
+    }
+
+    #[inline]
+    fn mask(&self) -> u64 {
+        // Safety: The first word is always initialized on construction, and it's the only
+        // part that the kernel reads or writes, see `KERNEL_SIGSET_SIZE`.
+        unsafe { self.__val[0].assume_init() }
+    }
+
+    #[inline]
+    #[expect(clippy::cast_sign_loss)]
+    const fn bit(signal: SignalKind) -> u64 {
+        // Signal numbers start at 1, a `SignalKind` above 64 would make no sense here,
+        // the only one that exists is `SIGSTKSZ` which isn't actually a signal.
+        1u64.wrapping_shl((signal.0.value() - 1) as u32)
+    }
 }
 
 impl Default for SigSetT {
@@ -171,5 +235,77 @@ impl Default for SigSetT {
     }
 }
 
+/// How to change the signal mask with `sigprocmask`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SigprocmaskHow {
+    /// Add the provided signals to the blocked set
+    Block,
+    /// Remove the provided signals from the blocked set
+    Unblock,
+    /// Replace the blocked set with the provided signals
+    SetMask,
+}
+
+impl SigprocmaskHow {
+    pub(crate) const fn into_raw(self) -> i32 {
+        match self {
+            Self::Block => linux_rust_bindings::signal::SIG_BLOCK,
+            Self::Unblock => linux_rust_bindings::signal::SIG_UNBLOCK,
+            Self::SetMask => linux_rust_bindings::signal::SIG_SETMASK,
+        }
+    }
+}
+
+/// An alternate stack for signal handlers to run on, see `sigaltstack`
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SignalStack {
+    pub ss_sp: *mut core::ffi::c_void,
+    pub ss_flags: SignalStackFlags,
+    pub ss_size: usize,
+}
+
+impl SignalStack {
+    /// A stack at `sp` of `size` bytes, to be enabled with `sigaltstack`
+    #[inline]
+    #[must_use]
+    pub const fn new(sp: *mut core::ffi::c_void, size: usize) -> Self {
+        Self {
+            ss_sp: sp,
+            ss_flags: SignalStackFlags::empty(),
+            ss_size: size,
+        }
+    }
+
+    /// A stack that disables the current alternate stack when passed to `sigaltstack`
+    #[inline]
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self {
+            ss_sp: core::ptr::null_mut(),
+            ss_flags: SignalStackFlags::SS_DISABLE,
+            ss_size: 0,
+        }
+    }
+}
+
+// Not in the bindings, values from `include/uapi/linux/signal.h`
+transparent_bitflags! {
+    pub struct SignalStackFlags: i32 {
+        const DEFAULT = 0;
+        /// The thread is currently executing on the alternate stack (only reported)
+        const SS_ONSTACK = 1;
+        /// The alternate stack is disabled
+        const SS_DISABLE = 2;
+        /// Disable the alternate stack while a handler is running on it
+        const SS_AUTODISARM = 1 << 31;
+    }
+}
+
+/// The kernel's `sigset_t` is 64 bits on both aarch64 and `x86_64`, even though
+/// the userspace one is 1024 bits.
+pub(crate) const KERNEL_SIGSET_SIZE: usize = 8;
+const SIGSET_SIGNALS: i32 = 64;
+
 pub const SIG_DFL: usize = 0;
 pub const SIG_IGN: usize = 1;
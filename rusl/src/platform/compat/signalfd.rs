@@ -0,0 +1,91 @@
+use crate::platform::SignalKind;
+
+transparent_bitflags! {
+    pub struct SignalFdFlags: i32 {
+        const DEFAULT = 0;
+        const SFD_CLOEXEC = linux_rust_bindings::fcntl::O_CLOEXEC;
+        const SFD_NONBLOCK = linux_rust_bindings::fcntl::O_NONBLOCK;
+    }
+}
+
+/// What gets read from a signalfd, `struct signalfd_siginfo` isn't in the bindings,
+/// it's the same 128 bytes on aarch64 and `x86_64`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/signalfd.2.html)
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SignalFdSigInfo {
+    pub ssi_signo: u32,
+    pub ssi_errno: i32,
+    pub ssi_code: i32,
+    pub ssi_pid: u32,
+    pub ssi_uid: u32,
+    pub ssi_fd: i32,
+    pub ssi_tid: u32,
+    pub ssi_band: u32,
+    pub ssi_overrun: u32,
+    pub ssi_trapno: u32,
+    pub ssi_status: i32,
+    pub ssi_int: i32,
+    pub ssi_ptr: u64,
+    pub ssi_utime: u64,
+    pub ssi_stime: u64,
+    pub ssi_addr: u64,
+    pub ssi_addr_lsb: u16,
+    _pad2: u16,
+    pub ssi_syscall: i32,
+    pub ssi_call_addr: u64,
+    pub ssi_arch: u32,
+    _pad: [u8; 28],
+}
+
+impl SignalFdSigInfo {
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    #[inline]
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self {
+            ssi_signo: 0,
+            ssi_errno: 0,
+            ssi_code: 0,
+            ssi_pid: 0,
+            ssi_uid: 0,
+            ssi_fd: 0,
+            ssi_tid: 0,
+            ssi_band: 0,
+            ssi_overrun: 0,
+            ssi_trapno: 0,
+            ssi_status: 0,
+            ssi_int: 0,
+            ssi_ptr: 0,
+            ssi_utime: 0,
+            ssi_stime: 0,
+            ssi_addr: 0,
+            ssi_addr_lsb: 0,
+            _pad2: 0,
+            ssi_syscall: 0,
+            ssi_call_addr: 0,
+            ssi_arch: 0,
+            _pad: [0; 28],
+        }
+    }
+
+    /// The signal that was received, `None` if the signal number is invalid,
+    /// which it only will be if this wasn't populated by the kernel.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_possible_wrap)]
+    pub const fn signal(&self) -> Option<SignalKind> {
+        SignalKind::from_raw(self.ssi_signo as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn siginfo_is_kernel_size() {
+        assert_eq!(128, SignalFdSigInfo::SIZE);
+    }
+}
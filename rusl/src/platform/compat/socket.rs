@@ -98,6 +98,29 @@ transparent_bitflags!(
     }
 );
 
+/// Flags for the send and receive family of syscalls.
+/// Not in the bindings, values from `include/linux/socket.h`
+transparent_bitflags!(
+    pub struct MsgFlags: i32 {
+        const DEFAULT = 0;
+        const MSG_OOB = 0x1;
+        /// Receive without removing the data from the queue
+        const MSG_PEEK = 0x2;
+        const MSG_DONTROUTE = 0x4;
+        /// On receive, return the real length of a datagram even if it was truncated,
+        /// set in the received flags if it was
+        const MSG_TRUNC = 0x20;
+        /// Don't block, for this call only
+        const MSG_DONTWAIT = 0x40;
+        const MSG_WAITALL = 0x100;
+        const MSG_CONFIRM = 0x800;
+        /// Don't raise `SIGPIPE` on a broken stream
+        const MSG_NOSIGNAL = 0x4000;
+        /// For `recvmmsg`, don't block after the first message has been received
+        const MSG_WAITFORONE = 0x10000;
+    }
+);
+
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone)]
 pub struct SocketAddressInet(pub(crate) linux_rust_bindings::socket::sockaddr_in);
@@ -135,12 +158,162 @@ impl SocketAddressInet {
     }
 }
 
+/// `struct sockaddr_in6`, not in the bindings, layout from `include/uapi/linux/in6.h`
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SocketAddressInet6 {
+    sin6_family: u16,
+    /// Network byte order
+    sin6_port: u16,
+    /// Network byte order
+    sin6_flowinfo: u32,
+    sin6_addr: [u8; 16],
+    sin6_scope_id: u32,
+}
+
+impl SocketAddressInet6 {
+    pub const LENGTH: usize = core::mem::size_of::<Self>();
+
+    /// An address with a zero flow label and scope id
+    #[must_use]
+    pub const fn new(ip_addr: [u8; 16], port: u16) -> Self {
+        Self {
+            sin6_family: AddressFamily::AF_INET6.0,
+            sin6_port: port.to_be(),
+            sin6_flowinfo: 0,
+            sin6_addr: ip_addr,
+            sin6_scope_id: 0,
+        }
+    }
+
+    /// Set the flow label, in host byte order
+    #[must_use]
+    pub const fn with_flowinfo(mut self, flowinfo: u32) -> Self {
+        self.sin6_flowinfo = flowinfo.to_be();
+        self
+    }
+
+    /// Set the scope id, the interface index for link-local addresses
+    #[must_use]
+    pub const fn with_scope_id(mut self, scope_id: u32) -> Self {
+        self.sin6_scope_id = scope_id;
+        self
+    }
+
+    /// Get the address bytes and the port, in host byte order
+    #[must_use]
+    pub const fn ipv6_addr(&self) -> ([u8; 16], u16) {
+        (self.sin6_addr, u16::from_be(self.sin6_port))
+    }
+
+    /// Get the flow label, in host byte order
+    #[must_use]
+    pub const fn flowinfo(&self) -> u32 {
+        u32::from_be(self.sin6_flowinfo)
+    }
+
+    #[must_use]
+    pub const fn scope_id(&self) -> u32 {
+        self.sin6_scope_id
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) const fn zeroed() -> Self {
+        Self {
+            sin6_family: 0,
+            sin6_port: 0,
+            sin6_flowinfo: 0,
+            sin6_addr: [0; 16],
+            sin6_scope_id: 0,
+        }
+    }
+}
+
+/// An IPv4 or IPv6 socket address, for calls on sockets of either family
+#[derive(Debug, Copy, Clone)]
+pub enum SocketAddressIp {
+    V4(SocketAddressInet),
+    V6(SocketAddressInet6),
+}
+
+impl SocketAddressIp {
+    /// Get the `AddressFamily` of the socket address
+    #[inline]
+    #[must_use]
+    pub const fn family(&self) -> AddressFamily {
+        match self {
+            Self::V4(_) => AddressFamily::AF_INET,
+            Self::V6(_) => AddressFamily::AF_INET6,
+        }
+    }
+
+    /// Interpret an address that the kernel wrote into storage big enough for either family
+    #[must_use]
+    pub(crate) fn from_storage(storage: &SocketAddressInet6) -> Self {
+        if storage.sin6_family == AddressFamily::AF_INET.0 {
+            // `sockaddr_in` is smaller than `sockaddr_in6` and has a lower alignment
+            Self::V4(unsafe {
+                core::ptr::from_ref(storage)
+                    .cast::<SocketAddressInet>()
+                    .read()
+            })
+        } else {
+            Self::V6(*storage)
+        }
+    }
+}
+
+/// Which halves of a connection to shut down.
+/// Not in the bindings, values from `include/linux/net.h`
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Shutdown {
+    /// Further receives are disallowed
+    Read = 0,
+    /// Further sends are disallowed, the peer reads EOF once it has received what was sent
+    Write = 1,
+    /// Both of the above
+    Both = 2,
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct SocketArgUnix {
     pub(crate) addr: SocketAddressUnix,
     pub(crate) addr_len: usize,
 }
 
+impl SocketArgUnix {
+    /// Check if this is the address of an unnamed socket, such as one end of a `socketpair`
+    /// or a connected socket that was never bound
+    #[inline]
+    #[must_use]
+    pub const fn is_unnamed(&self) -> bool {
+        self.addr_len <= core::mem::size_of::<linux_rust_bindings::socket::__kernel_sa_family_t>()
+    }
+
+    /// Get the path of a socket bound to a path, `None` for unnamed and abstract sockets
+    #[must_use]
+    pub fn path(&self) -> Option<&UnixStr> {
+        let path_len = self
+            .addr_len
+            .checked_sub(core::mem::size_of::<
+                linux_rust_bindings::socket::__kernel_sa_family_t,
+            >())?
+            .min(self.addr.0.sun_path.len());
+        // `c_char` and `u8` have the same layout
+        let path = unsafe {
+            core::slice::from_raw_parts(self.addr.0.sun_path.as_ptr().cast::<u8>(), path_len)
+        };
+        // Abstract socket paths start with a null byte
+        if path.first().is_none_or(|b| *b == 0) {
+            return None;
+        }
+        let null = path.iter().position(|b| *b == 0)?;
+        UnixStr::try_from_bytes(&path[..=null]).ok()
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone)]
 pub struct SocketAddressUnix(pub(crate) linux_rust_bindings::socket::sockaddr_un);
@@ -199,6 +372,103 @@ impl SocketAddressUnix {
     }
 }
 
+/// `struct msghdr` for use within [`MMsgHdr`], without control messages
+#[repr(C)]
+#[derive(Debug)]
+struct RawMsgHdr {
+    msg_name: *mut u8,
+    msg_namelen: u32,
+    msg_iov: *mut linux_rust_bindings::uio::iovec,
+    msg_iovlen: usize,
+    msg_control: *mut u8,
+    msg_controllen: usize,
+    msg_flags: i32,
+}
+
+/// A message for [`sendmmsg`](crate::network::sendmmsg) or [`recvmmsg`](crate::network::recvmmsg),
+/// borrowing its buffers and address for as long as it lives
+#[repr(C)]
+#[derive(Debug)]
+pub struct MMsgHdr<'a> {
+    msg_hdr: RawMsgHdr,
+    msg_len: u32,
+    _p: core::marker::PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> MMsgHdr<'a> {
+    /// A message to send from `io`, to `addr` if the socket isn't connected
+    #[must_use]
+    pub fn new_send(
+        io: &'a [crate::platform::IoSlice<'a>],
+        addr: Option<&'a SocketAddressInet>,
+    ) -> Self {
+        let (name, name_len) = addr.map_or((core::ptr::null_mut(), 0), |addr| {
+            (
+                core::ptr::from_ref(addr).cast_mut().cast(),
+                SocketAddressInet::LENGTH as u32,
+            )
+        });
+        Self::new(name, name_len, io.as_ptr().cast_mut().cast(), io.len())
+    }
+
+    /// A message to receive into `io`, writing the sender to `addr` if provided
+    #[must_use]
+    pub fn new_recv(
+        io: &'a mut [crate::platform::IoSliceMut<'a>],
+        addr: Option<&'a mut SocketAddressInet>,
+    ) -> Self {
+        let (name, name_len) = addr.map_or((core::ptr::null_mut(), 0), |addr| {
+            (
+                core::ptr::from_mut(addr).cast(),
+                SocketAddressInet::LENGTH as u32,
+            )
+        });
+        Self::new(name, name_len, io.as_mut_ptr().cast(), io.len())
+    }
+
+    fn new(
+        msg_name: *mut u8,
+        msg_namelen: u32,
+        msg_iov: *mut linux_rust_bindings::uio::iovec,
+        msg_iovlen: usize,
+    ) -> Self {
+        Self {
+            msg_hdr: RawMsgHdr {
+                msg_name,
+                msg_namelen,
+                msg_iov,
+                msg_iovlen,
+                msg_control: core::ptr::null_mut(),
+                msg_controllen: 0,
+                msg_flags: 0,
+            },
+            msg_len: 0,
+            _p: core::marker::PhantomData,
+        }
+    }
+
+    /// The number of bytes sent or received for this message, set by the kernel
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.msg_len as usize
+    }
+
+    /// If no bytes were sent or received for this message
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.msg_len == 0
+    }
+
+    /// Flags set by the kernel on a received message, such as [`MsgFlags::MSG_TRUNC`]
+    #[inline]
+    #[must_use]
+    pub const fn flags(&self) -> MsgFlags {
+        MsgFlags(self.msg_hdr.msg_flags)
+    }
+}
+
 //  #define CMSG_LEN(len)   (CMSG_ALIGN (sizeof (struct cmsghdr)) + (len))
 #[cfg(feature = "alloc")]
 macro_rules! cmsg_len {
@@ -285,6 +555,10 @@ macro_rules! __mhdr_end {
     };
 }
 
+/// Flag for [`recvmsg`](crate::network::recvmsg), sets `O_CLOEXEC` on fds received through `SCM_RIGHTS`.
+/// Not in the bindings, value from `include/linux/socket.h`
+pub const MSG_CMSG_CLOEXEC: i32 = 0x4000_0000;
+
 #[derive(Debug)]
 #[cfg(feature = "alloc")]
 pub enum ControlMessageSend<'a> {
@@ -0,0 +1,84 @@
+use crate::platform::{GidT, PidT, UidT};
+
+/// The level a socket option is defined at, the socket itself or a protocol.
+/// Not in the bindings, values from `include/linux/socket.h` and `include/uapi/linux/in.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SocketOptLevel(pub(crate) i32);
+
+impl SocketOptLevel {
+    pub const SOL_SOCKET: Self = Self(1);
+    pub const IPPROTO_IP: Self = Self(0);
+    pub const IPPROTO_TCP: Self = Self(6);
+    pub const IPPROTO_UDP: Self = Self(17);
+    pub const IPPROTO_IPV6: Self = Self(41);
+}
+
+/// The name of a socket option, only meaningful together with its [`SocketOptLevel`].
+/// Not in the bindings, values from `include/uapi/asm-generic/socket.h`,
+/// `include/uapi/linux/tcp.h`, and `include/uapi/linux/in6.h`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SocketOptName(pub(crate) i32);
+
+impl SocketOptName {
+    // `SOL_SOCKET`
+    pub const SO_REUSEADDR: Self = Self(2);
+    pub const SO_TYPE: Self = Self(3);
+    pub const SO_ERROR: Self = Self(4);
+    pub const SO_BROADCAST: Self = Self(6);
+    pub const SO_SNDBUF: Self = Self(7);
+    pub const SO_RCVBUF: Self = Self(8);
+    pub const SO_KEEPALIVE: Self = Self(9);
+    pub const SO_LINGER: Self = Self(13);
+    pub const SO_REUSEPORT: Self = Self(15);
+    pub const SO_PEERCRED: Self = Self(17);
+    /// Takes a 64-bit `struct timeval` on 64-bit architectures
+    pub const SO_RCVTIMEO: Self = Self(20);
+    /// Takes a 64-bit `struct timeval` on 64-bit architectures
+    pub const SO_SNDTIMEO: Self = Self(21);
+    // `IPPROTO_IP`
+    pub const IP_TTL: Self = Self(2);
+    pub const IP_MULTICAST_TTL: Self = Self(33);
+    pub const IP_MULTICAST_LOOP: Self = Self(34);
+    pub const IP_ADD_MEMBERSHIP: Self = Self(35);
+    pub const IP_DROP_MEMBERSHIP: Self = Self(36);
+    // `IPPROTO_TCP`
+    pub const TCP_NODELAY: Self = Self(1);
+    pub const TCP_KEEPIDLE: Self = Self(4);
+    pub const TCP_KEEPINTVL: Self = Self(5);
+    pub const TCP_KEEPCNT: Self = Self(6);
+    // `IPPROTO_IPV6`
+    pub const IPV6_V6ONLY: Self = Self(26);
+}
+
+/// Credentials of the process on the other end of a unix socket,
+/// as they were when it called `connect` or `socketpair`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UCred {
+    pub pid: PidT,
+    pub uid: UidT,
+    pub gid: GidT,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct Linger {
+    pub(crate) l_onoff: i32,
+    pub(crate) l_linger: i32,
+}
+
+/// `struct ip_mreq`, both addresses in network byte order
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct IpMreq {
+    pub(crate) imr_multiaddr: u32,
+    pub(crate) imr_interface: u32,
+}
+
+/// `struct timeval` as used by `SO_RCVTIMEO`/`SO_SNDTIMEO`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct SockTimeVal {
+    pub(crate) tv_sec: i64,
+    pub(crate) tv_usec: i64,
+}
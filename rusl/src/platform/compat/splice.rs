@@ -0,0 +1,11 @@
+/// Flags for [`splice`](https://man7.org/linux/man-pages/man2/splice.2.html), `tee`, and `vmsplice`.
+/// Not in the bindings, values from `include/linux/splice.h`
+transparent_bitflags! {
+    pub struct SpliceFlags: u32 {
+        const DEFAULT = 0;
+        const SPLICE_F_MOVE = 1;
+        const SPLICE_F_NONBLOCK = 2;
+        const SPLICE_F_MORE = 4;
+        const SPLICE_F_GIFT = 8;
+    }
+}
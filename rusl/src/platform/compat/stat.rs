@@ -4,7 +4,7 @@ use crate::platform::TimeSpec;
 pub type Stat = linux_rust_bindings::stat::stat;
 
 #[repr(transparent)]
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub struct Statx(pub(crate) linux_rust_bindings::stat::statx);
 
 impl Statx {
@@ -20,6 +20,66 @@ impl Statx {
         self.0.stx_size
     }
 
+    /// Preferred block size for I/O
+    #[inline]
+    #[must_use]
+    pub const fn blksize(&self) -> u32 {
+        self.0.stx_blksize
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn nlink(&self) -> u32 {
+        self.0.stx_nlink
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn uid(&self) -> u32 {
+        self.0.stx_uid
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn gid(&self) -> u32 {
+        self.0.stx_gid
+    }
+
+    /// File type and permissions
+    #[inline]
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        Mode(self.0.stx_mode as u32)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn ino(&self) -> u64 {
+        self.0.stx_ino
+    }
+
+    /// Number of 512-byte blocks allocated
+    #[inline]
+    #[must_use]
+    pub const fn blocks(&self) -> u64 {
+        self.0.stx_blocks
+    }
+
+    /// Device containing the file, encoded the same way as `st_dev` from `stat`
+    #[inline]
+    #[must_use]
+    pub const fn dev(&self) -> u64 {
+        makedev(self.0.stx_dev_major, self.0.stx_dev_minor)
+    }
+
+    /// Device represented by the file if it's a special file,
+    /// encoded the same way as `st_rdev` from `stat`
+    #[inline]
+    #[must_use]
+    pub const fn rdev(&self) -> u64 {
+        makedev(self.0.stx_rdev_major, self.0.stx_rdev_minor)
+    }
+
     /// Last access
     #[inline]
     #[must_use]
@@ -53,6 +113,15 @@ impl Statx {
     }
 }
 
+// Same as glibc's `makedev` in `sys/sysmacros.h`
+const fn makedev(major: u32, minor: u32) -> u64 {
+    let (major, minor) = (major as u64, minor as u64);
+    ((major & 0xffff_f000) << 32)
+        | ((major & 0x0000_0fff) << 8)
+        | ((minor & 0xffff_ff00) << 12)
+        | (minor & 0x0000_00ff)
+}
+
 transparent_bitflags! {
     pub struct StatxMask: u32 {
         const DEFAULT = 0;
@@ -78,6 +147,7 @@ transparent_bitflags! {
     pub struct StatxFlags: NonNegativeI32 {
         const DEFAULT = NonNegativeI32::comptime_checked_new(0);
         const AT_SYMLINK_FOLLOW = NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_SYMLINK_FOLLOW);
+        const AT_SYMLINK_NOFOLLOW = NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_SYMLINK_NOFOLLOW);
         const AT_NO_AUTOMOUNT = NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_NO_AUTOMOUNT);
         const AT_EMPTY_PATH = NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_EMPTY_PATH);
         const AT_STATX_SYNC_TYPE = NonNegativeI32::comptime_checked_new(linux_rust_bindings::fcntl::AT_STATX_SYNC_TYPE);
@@ -48,6 +48,13 @@ impl From<i32> for ClockId {
 pub struct TimeSpec(__kernel_timespec);
 
 impl TimeSpec {
+    /// Passed to [`utimensat`](crate::unistd::utimensat) to set a timestamp to the current time.
+    /// Not in the bindings, value from `include/uapi/linux/stat.h`
+    pub const UTIME_NOW: Self = Self::new(0, (1 << 30) - 1);
+    /// Passed to [`utimensat`](crate::unistd::utimensat) to leave a timestamp unchanged.
+    /// Not in the bindings, value from `include/uapi/linux/stat.h`
+    pub const UTIME_OMIT: Self = Self::new(0, (1 << 30) - 2);
+
     #[inline]
     #[must_use]
     pub const fn new_zeroed() -> Self {
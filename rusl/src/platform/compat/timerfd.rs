@@ -0,0 +1,58 @@
+use crate::platform::TimeSpec;
+
+transparent_bitflags! {
+    pub struct TimerFdFlags: i32 {
+        const DEFAULT = 0;
+        const TFD_CLOEXEC = linux_rust_bindings::fcntl::O_CLOEXEC;
+        const TFD_NONBLOCK = linux_rust_bindings::fcntl::O_NONBLOCK;
+    }
+}
+
+/// Not in the bindings, values from `include/uapi/linux/timerfd.h`
+transparent_bitflags! {
+    pub struct TimerFdSetFlags: i32 {
+        const DEFAULT = 0;
+        const TFD_TIMER_ABSTIME = 1;
+        const TFD_TIMER_CANCEL_ON_SET = 1 << 1;
+    }
+}
+
+/// `__kernel_itimerspec`, an initial expiration and an interval for subsequent expirations.
+/// A zeroed `value` disarms the timer, a zeroed `interval` makes it fire only once.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ITimerSpec {
+    it_interval: TimeSpec,
+    it_value: TimeSpec,
+}
+
+impl ITimerSpec {
+    #[inline]
+    #[must_use]
+    pub const fn new(value: TimeSpec, interval: TimeSpec) -> Self {
+        Self {
+            it_interval: interval,
+            it_value: value,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self::new(TimeSpec::new_zeroed(), TimeSpec::new_zeroed())
+    }
+
+    /// The time until the next expiration, or the absolute time of the next expiration
+    /// if set with `TFD_TIMER_ABSTIME`
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> TimeSpec {
+        self.it_value
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn interval(&self) -> TimeSpec {
+        self.it_interval
+    }
+}
@@ -1,3 +1,5 @@
+use crate::platform::{Fd, PidT, UidT};
+
 transparent_bitflags! {
     pub struct WaitFlags: i32 {
         const DEFAULT = 0;
@@ -20,3 +22,109 @@ transparent_bitflags! {
         const WCONTINUED = WaitFlags::WCONTINUED;
     }
 }
+
+/// Which children to wait for with `waitid`
+#[derive(Debug, Copy, Clone)]
+pub enum WaitIdType {
+    /// Any child
+    All,
+    /// The child with this pid
+    Pid(PidT),
+    /// Any child in this process group
+    Pgid(PidT),
+    /// The child referred to by this pidfd
+    PidFd(Fd),
+}
+
+impl WaitIdType {
+    /// `P_ALL`, `P_PID`, `P_PGID`, and `P_PIDFD` from `include/uapi/linux/wait.h`
+    #[expect(clippy::cast_sign_loss)]
+    pub(crate) const fn into_raw(self) -> (i32, u32) {
+        match self {
+            Self::All => (0, 0),
+            Self::Pid(pid) => (1, pid as u32),
+            Self::Pgid(pgid) => (2, pgid as u32),
+            Self::PidFd(fd) => (3, fd.value() as u32),
+        }
+    }
+}
+
+/// What happened to a child, the `si_code` of a `SIGCHLD`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChildState {
+    Exited,
+    Killed,
+    Dumped,
+    Trapped,
+    Stopped,
+    Continued,
+}
+
+/// The part of `siginfo_t` that `waitid` fills in, same layout on aarch64 and `x86_64`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WaitIdInfo {
+    si_signo: i32,
+    si_errno: i32,
+    si_code: i32,
+    _pad0: i32,
+    si_pid: i32,
+    si_uid: u32,
+    si_status: i32,
+    _pad: [i32; 25],
+    _align: [u64; 0],
+}
+
+impl WaitIdInfo {
+    #[inline]
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self {
+            si_signo: 0,
+            si_errno: 0,
+            si_code: 0,
+            _pad0: 0,
+            si_pid: 0,
+            si_uid: 0,
+            si_status: 0,
+            _pad: [0; 25],
+            _align: [],
+        }
+    }
+
+    /// The pid of the child
+    #[inline]
+    #[must_use]
+    pub const fn pid(&self) -> PidT {
+        self.si_pid
+    }
+
+    /// The real user id of the child
+    #[inline]
+    #[must_use]
+    pub const fn uid(&self) -> UidT {
+        self.si_uid
+    }
+
+    /// The exit code if the child exited, otherwise the signal that caused the state change
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> i32 {
+        self.si_status
+    }
+
+    /// What happened to the child, `CLD_*` from `include/uapi/asm-generic/siginfo.h`
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> Option<ChildState> {
+        match self.si_code {
+            1 => Some(ChildState::Exited),
+            2 => Some(ChildState::Killed),
+            3 => Some(ChildState::Dumped),
+            4 => Some(ChildState::Trapped),
+            5 => Some(ChildState::Stopped),
+            6 => Some(ChildState::Continued),
+            _ => None,
+        }
+    }
+}
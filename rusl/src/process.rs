@@ -1,16 +1,41 @@
 pub use clone::{clone, clone3, fork};
 pub use execve::execve;
 pub use exit::exit;
-pub use get_pid::get_pid;
-pub use signal::{add_signal_action, CatchSignal, SaSignalaction, SigInfo};
-pub use wait::wait_pid;
+pub use get_pid::{get_pid, get_ppid, get_tid};
+pub use kill::{kill, tgkill};
+pub use landlock::{
+    landlock_abi_version, landlock_add_rule, landlock_create_ruleset, landlock_restrict_self,
+    LandlockRule,
+};
+pub use pidfd::{pidfd_getfd, pidfd_open, pidfd_send_signal};
+pub use prctl::{prctl, PrctlOption, TASK_COMM_LEN};
+pub use prlimit::prlimit64;
+pub use sched::{
+    getcpu, getpriority, sched_getaffinity, sched_getaffinity_mask, sched_getscheduler,
+    sched_setaffinity, sched_setscheduler, sched_yield, setpriority,
+};
+pub use seccomp::{seccomp_get_action_avail, seccomp_set_mode_filter, seccomp_set_mode_strict};
+pub use signal::{
+    add_signal_action, sigaltstack, sigpending, sigprocmask, sigtimedwait, CatchSignal,
+    SaSignalaction, SigInfo,
+};
+pub use signalfd::signalfd;
+pub use wait::{wait_id, wait_pid};
 
 mod execve;
 mod exit;
 
 mod clone;
 mod get_pid;
+mod kill;
+mod landlock;
+mod pidfd;
+mod prctl;
+mod prlimit;
+mod sched;
+mod seccomp;
 mod signal;
+mod signalfd;
 #[cfg(test)]
 mod test;
 mod wait;
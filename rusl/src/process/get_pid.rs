@@ -11,3 +11,25 @@ pub fn get_pid() -> PidT {
     let res = unsafe { syscall!(GETPID) };
     res as PidT
 }
+
+/// Get the pid of the parent of the calling process
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/getppid.2.html)
+/// Always successful
+#[inline]
+#[must_use]
+#[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn get_ppid() -> PidT {
+    let res = unsafe { syscall!(GETPPID) };
+    res as PidT
+}
+
+/// Get the thread id of the calling thread, for the main thread it's the same as the pid
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/gettid.2.html)
+/// Always successful
+#[inline]
+#[must_use]
+#[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn get_tid() -> PidT {
+    let res = unsafe { syscall!(GETTID) };
+    res as PidT
+}
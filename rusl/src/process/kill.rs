@@ -0,0 +1,50 @@
+use sc::syscall;
+
+use crate::platform::{PidT, SignalKind};
+
+/// Send a signal to a process, or a process group.
+/// A positive `pid` targets that process, `0` the caller's process group,
+/// `-1` every process the caller may signal, and any other negative `pid` the process group `-pid`.
+/// Prefer `pidfd_send_signal` for children, since a pid can be reused once it's been waited for.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/kill.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn kill(pid: PidT, signal: SignalKind) -> crate::Result<()> {
+    let res = unsafe { syscall!(KILL, pid, signal.value()) };
+    bail_on_below_zero!(res, "`KILL` syscall failed");
+    Ok(())
+}
+
+/// Send a signal to the thread `tid` in the thread group (process) `tgid`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/tgkill.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn tgkill(tgid: PidT, tid: PidT, signal: SignalKind) -> crate::Result<()> {
+    let res = unsafe { syscall!(TGKILL, tgid, tid, signal.value()) };
+    bail_on_below_zero!(res, "`TGKILL` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{SignalKind, TimeSpec, WaitPidFlags};
+    use crate::process::{exit, fork, kill, wait_pid};
+    use crate::time::nanosleep;
+
+    #[test]
+    fn kill_child() {
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            let _ = nanosleep(&TimeSpec::new(10, 0), None);
+            exit(0);
+        }
+        kill(pid, SignalKind::SIGKILL).unwrap();
+        let res = wait_pid(pid, WaitPidFlags::empty()).unwrap();
+        // Terminated by the signal
+        assert_eq!(SignalKind::SIGKILL.value(), res.status & 0x7f);
+        expect_errno!(Errno::ESRCH, kill(pid, SignalKind::SIGKILL));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::platform::{Fd, LandlockNetPortAttr, LandlockPathBeneathAttr, LandlockRulesetAttr};
+
+// Not in `sc`, syscalls added after 5.1 share numbers across architectures
+const LANDLOCK_CREATE_RULESET: usize = 444;
+const LANDLOCK_ADD_RULE: usize = 445;
+const LANDLOCK_RESTRICT_SELF: usize = 446;
+
+// Not in the bindings, values from `include/uapi/linux/landlock.h`
+const LANDLOCK_CREATE_RULESET_VERSION: usize = 1;
+const LANDLOCK_RULE_PATH_BENEATH: usize = 1;
+const LANDLOCK_RULE_NET_PORT: usize = 2;
+
+/// A rule to add to a landlock ruleset
+#[derive(Debug, Copy, Clone)]
+pub enum LandlockRule<'a> {
+    PathBeneath(&'a LandlockPathBeneathAttr),
+    NetPort(&'a LandlockNetPortAttr),
+}
+
+/// Create a new landlock ruleset handling the rights in `attr`, the returned fd is `O_CLOEXEC`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html)
+/// # Errors
+/// See above, `ENOSYS` or `EOPNOTSUPP` if landlock isn't supported or enabled
+#[inline]
+pub fn landlock_create_ruleset(attr: &LandlockRulesetAttr) -> crate::Result<Fd> {
+    let res = unsafe {
+        sc::syscall3(
+            LANDLOCK_CREATE_RULESET,
+            core::ptr::from_ref::<LandlockRulesetAttr>(attr) as usize,
+            core::mem::size_of::<LandlockRulesetAttr>(),
+            0,
+        )
+    };
+    Fd::coerce_from_register(res, "`LANDLOCK_CREATE_RULESET` syscall failed")
+}
+
+/// Get the highest landlock ABI version supported by the kernel
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html)
+/// # Errors
+/// See above, `ENOSYS` or `EOPNOTSUPP` if landlock isn't supported or enabled
+#[inline]
+pub fn landlock_abi_version() -> crate::Result<u32> {
+    let res = unsafe {
+        sc::syscall3(
+            LANDLOCK_CREATE_RULESET,
+            0,
+            0,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        )
+    };
+    bail_on_below_zero!(res, "`LANDLOCK_CREATE_RULESET` syscall failed");
+    Ok(res as u32)
+}
+
+/// Add a rule to the ruleset at `ruleset_fd`
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html)
+/// # Errors
+/// See above, `EINVAL` if the rule allows rights that the ruleset doesn't handle
+#[inline]
+#[expect(clippy::cast_sign_loss)]
+pub fn landlock_add_rule(ruleset_fd: Fd, rule: LandlockRule<'_>) -> crate::Result<()> {
+    let (rule_type, rule_attr) = match rule {
+        LandlockRule::PathBeneath(attr) => (
+            LANDLOCK_RULE_PATH_BENEATH,
+            core::ptr::from_ref::<LandlockPathBeneathAttr>(attr) as usize,
+        ),
+        LandlockRule::NetPort(attr) => (
+            LANDLOCK_RULE_NET_PORT,
+            core::ptr::from_ref::<LandlockNetPortAttr>(attr) as usize,
+        ),
+    };
+    let res = unsafe {
+        sc::syscall4(
+            LANDLOCK_ADD_RULE,
+            ruleset_fd.0 as usize,
+            rule_type,
+            rule_attr,
+            0,
+        )
+    };
+    bail_on_below_zero!(res, "`LANDLOCK_ADD_RULE` syscall failed");
+    Ok(())
+}
+
+/// Restrict the calling thread with the ruleset at `ruleset_fd`, restrictions are inherited
+/// by children and can't be lifted.
+/// Requires either `CAP_SYS_ADMIN` or `no_new_privs` to have been set with `prctl`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html)
+/// # Errors
+/// See above
+#[inline]
+#[expect(clippy::cast_sign_loss)]
+pub fn landlock_restrict_self(ruleset_fd: Fd) -> crate::Result<()> {
+    let res = unsafe { sc::syscall2(LANDLOCK_RESTRICT_SELF, ruleset_fd.0 as usize, 0) };
+    bail_on_below_zero!(res, "`LANDLOCK_RESTRICT_SELF` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{
+        LandlockAccessFs, LandlockPathBeneathAttr, LandlockRulesetAttr, OpenFlags, WaitPidFlags,
+    };
+    use crate::process::{
+        exit, fork, landlock_abi_version, landlock_add_rule, landlock_create_ruleset,
+        landlock_restrict_self, prctl, wait_pid, LandlockRule, PrctlOption,
+    };
+    use crate::unistd::{close, open};
+
+    #[test]
+    fn restrict_reads_to_test_files() {
+        match landlock_abi_version() {
+            Ok(abi) => assert!(abi > 0),
+            // Not built into or enabled in this kernel
+            Err(e) if matches!(e.code, Some(Errno::ENOSYS | Errno::EOPNOTSUPP)) => return,
+            Err(e) => panic!("{e}"),
+        }
+        let attr = LandlockRulesetAttr {
+            handled_access_fs: LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_FILE,
+            ..LandlockRulesetAttr::default()
+        };
+        let ruleset = landlock_create_ruleset(&attr).unwrap();
+        let dir = open(
+            unix_lit!("test-files"),
+            OpenFlags::O_PATH | OpenFlags::O_CLOEXEC,
+        )
+        .unwrap();
+        let beneath = LandlockPathBeneathAttr {
+            allowed_access: LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_FILE,
+            parent_fd: dir.value(),
+        };
+        landlock_add_rule(ruleset, LandlockRule::PathBeneath(&beneath)).unwrap();
+        close(dir).unwrap();
+        // Can't be undone, restrict a child
+        let child = unsafe { fork().unwrap() };
+        if child == 0 {
+            prctl(PrctlOption::SetNoNewPrivs).unwrap();
+            landlock_restrict_self(ruleset).unwrap();
+            let mut code = 0;
+            if open(unix_lit!("test-files/can_open.txt"), OpenFlags::O_RDONLY).is_err() {
+                code |= 1;
+            }
+            match open(unix_lit!("Cargo.toml"), OpenFlags::O_RDONLY) {
+                Err(e) if e.code == Some(Errno::EACCES) => {}
+                _ => code |= 2,
+            }
+            exit(code);
+        }
+        close(ruleset).unwrap();
+        let res = wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+}
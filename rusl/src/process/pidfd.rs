@@ -0,0 +1,137 @@
+use sc::syscall;
+
+use crate::platform::{Fd, PidFdOpenFlags, PidT, SignalKind};
+
+/// Get a file descriptor referring to the process with the provided `pid`.
+/// The fd becomes readable when the process exits, and unlike the `pid`, it can't
+/// be reused to refer to some other process.
+/// The fd is always opened with `O_CLOEXEC`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/pidfd_open.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn pidfd_open(pid: PidT, flags: PidFdOpenFlags) -> crate::Result<Fd> {
+    let res = unsafe { syscall!(PIDFD_OPEN, pid, flags.bits()) };
+    Fd::coerce_from_register(res, "`PIDFD_OPEN` syscall failed")
+}
+
+/// Send a signal to the process referred to by `pidfd`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn pidfd_send_signal(pidfd: Fd, signal: SignalKind) -> crate::Result<()> {
+    let res = unsafe { syscall!(PIDFD_SEND_SIGNAL, pidfd.0, signal.value(), 0, 0) };
+    bail_on_below_zero!(res, "`PIDFD_SEND_SIGNAL` syscall failed");
+    Ok(())
+}
+
+/// Duplicate the file descriptor `target_fd` of the process referred to by `pidfd`
+/// into this process. Requires ptrace access to that process.
+/// The new fd is always opened with `O_CLOEXEC`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn pidfd_getfd(pidfd: Fd, target_fd: Fd) -> crate::Result<Fd> {
+    let res = unsafe { syscall!(PIDFD_GETFD, pidfd.0, target_fd.0, 0) };
+    Fd::coerce_from_register(res, "`PIDFD_GETFD` syscall failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{
+        ChildState, PidFdOpenFlags, PollEvents, PollFd, SignalKind, TimeSpec, WaitFlags, WaitIdType,
+    };
+    use crate::process::{exit, fork, pidfd_getfd, pidfd_open, pidfd_send_signal, wait_id};
+    use crate::select::ppoll;
+    use crate::time::nanosleep;
+    use crate::unistd::{close, pipe, read, write};
+
+    #[test]
+    fn pidfd_poll_and_wait_exit() {
+        unsafe {
+            let child = fork().unwrap();
+            if child == 0 {
+                exit(3);
+            }
+            let pidfd = pidfd_open(child, PidFdOpenFlags::empty()).unwrap();
+            let mut poll_fds = [PollFd::new(pidfd, PollEvents::POLLIN)];
+            let ready = ppoll(&mut poll_fds, Some(&TimeSpec::new(5, 0)), None).unwrap();
+            assert_eq!(1, ready);
+            let info = wait_id(WaitIdType::PidFd(pidfd), WaitFlags::WEXITED)
+                .unwrap()
+                .unwrap();
+            assert_eq!(child, info.pid());
+            assert_eq!(Some(ChildState::Exited), info.state());
+            assert_eq!(3, info.status());
+            close(pidfd).unwrap();
+        }
+    }
+
+    #[test]
+    fn pidfd_signal_child() {
+        unsafe {
+            let child = fork().unwrap();
+            if child == 0 {
+                loop {
+                    let _ = nanosleep(&TimeSpec::new(1, 0), None);
+                }
+            }
+            let pidfd = pidfd_open(child, PidFdOpenFlags::PIDFD_NONBLOCK).unwrap();
+            let nothing_yet = wait_id(
+                WaitIdType::PidFd(pidfd),
+                WaitFlags::WEXITED | WaitFlags::WNOHANG,
+            );
+            // Could theoretically error with `EAGAIN` because of `PIDFD_NONBLOCK`
+            if let Ok(res) = nothing_yet {
+                assert!(res.is_none());
+            }
+            pidfd_send_signal(pidfd, SignalKind::SIGKILL).unwrap();
+            let info = wait_id(WaitIdType::PidFd(pidfd), WaitFlags::WEXITED);
+            let info = match info {
+                Err(e) if e.code == Some(Errno::EAGAIN) => {
+                    let mut poll_fds = [PollFd::new(pidfd, PollEvents::POLLIN)];
+                    ppoll(&mut poll_fds, Some(&TimeSpec::new(5, 0)), None).unwrap();
+                    wait_id(WaitIdType::PidFd(pidfd), WaitFlags::WEXITED).unwrap()
+                }
+                res => res.unwrap(),
+            }
+            .unwrap();
+            assert_eq!(Some(ChildState::Killed), info.state());
+            assert_eq!(SignalKind::SIGKILL.value(), info.status());
+            close(pidfd).unwrap();
+        }
+    }
+
+    #[test]
+    fn pidfd_getfd_from_child() {
+        unsafe {
+            let pipe = pipe().unwrap();
+            let child = fork().unwrap();
+            if child == 0 {
+                loop {
+                    let _ = nanosleep(&TimeSpec::new(1, 0), None);
+                }
+            }
+            close(pipe.out_pipe).unwrap();
+            let pidfd = pidfd_open(child, PidFdOpenFlags::empty()).unwrap();
+            match pidfd_getfd(pidfd, pipe.out_pipe) {
+                Ok(stolen) => {
+                    write(stolen, b"hi").unwrap();
+                    let mut buf = [0u8; 2];
+                    read(pipe.in_pipe, &mut buf).unwrap();
+                    assert_eq!(b"hi", &buf);
+                    close(stolen).unwrap();
+                }
+                // Ptrace restrictions in some environments
+                Err(e) => assert_eq!(Some(Errno::EPERM), e.code),
+            }
+            pidfd_send_signal(pidfd, SignalKind::SIGKILL).unwrap();
+            wait_id(WaitIdType::PidFd(pidfd), WaitFlags::WEXITED).unwrap();
+            close(pidfd).unwrap();
+            close(pipe.in_pipe).unwrap();
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use sc::syscall;
+
+use crate::platform::SignalKind;
+use crate::string::unix_str::UnixStr;
+
+// Not in the bindings, values from `include/uapi/linux/prctl.h`
+const PR_SET_PDEATHSIG: i32 = 1;
+const PR_GET_PDEATHSIG: i32 = 2;
+const PR_GET_DUMPABLE: i32 = 3;
+const PR_SET_DUMPABLE: i32 = 4;
+const PR_SET_NAME: i32 = 15;
+const PR_GET_NAME: i32 = 16;
+const PR_CAPBSET_DROP: i32 = 24;
+const PR_SET_CHILD_SUBREAPER: i32 = 36;
+const PR_GET_CHILD_SUBREAPER: i32 = 37;
+const PR_SET_NO_NEW_PRIVS: i32 = 38;
+const PR_GET_NO_NEW_PRIVS: i32 = 39;
+
+/// Max length of a thread name, including the null byte
+pub const TASK_COMM_LEN: usize = 16;
+
+/// Operations on the calling thread or process, see `prctl`
+#[derive(Debug)]
+pub enum PrctlOption<'a> {
+    /// Set the name of the calling thread, names longer than 15 bytes are truncated
+    SetName(&'a UnixStr),
+    /// Write the null-terminated name of the calling thread into the buffer
+    GetName(&'a mut [u8; TASK_COMM_LEN]),
+    /// Signal to get when the parent thread dies, `None` clears it.
+    /// Note that it's the thread that forked this process, not the whole parent process.
+    /// Cleared on fork.
+    SetPdeathsig(Option<SignalKind>),
+    /// Write the current parent-death signal into the provided value, 0 if none
+    GetPdeathsig(&'a mut i32),
+    /// Whether the process can produce core dumps and be ptraced by others
+    SetDumpable(bool),
+    /// Returns 1 if the process is dumpable, otherwise 0
+    GetDumpable,
+    /// Never grant privileges on `execve`, f.e. through setuid binaries, can't be unset.
+    /// Required to install seccomp filters without `CAP_SYS_ADMIN`.
+    SetNoNewPrivs,
+    /// Returns 1 if `no_new_privs` is set, otherwise 0
+    GetNoNewPrivs,
+    /// Become the reaper of orphaned descendants, instead of init
+    SetChildSubreaper(bool),
+    /// Write 1 into the provided value if this process is a subreaper, otherwise 0
+    GetChildSubreaper(&'a mut i32),
+    /// Drop a capability from the calling thread's bounding set, requires `CAP_SETPCAP`
+    CapbsetDrop(u32),
+}
+
+/// Perform an operation on the calling thread or process.
+/// Returns the syscall's result, which is only meaningful for the options that document one.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/prctl.2.html)
+/// # Errors
+/// See above
+pub fn prctl(option: PrctlOption<'_>) -> crate::Result<usize> {
+    let res = unsafe {
+        match option {
+            PrctlOption::SetName(name) => syscall!(PRCTL, PR_SET_NAME, name.as_ptr()),
+            PrctlOption::GetName(buf) => syscall!(PRCTL, PR_GET_NAME, buf.as_mut_ptr()),
+            PrctlOption::SetPdeathsig(sig) => {
+                syscall!(PRCTL, PR_SET_PDEATHSIG, sig.map_or(0, SignalKind::value))
+            }
+            PrctlOption::GetPdeathsig(sig) => {
+                syscall!(PRCTL, PR_GET_PDEATHSIG, core::ptr::from_mut(sig))
+            }
+            PrctlOption::SetDumpable(dumpable) => {
+                syscall!(PRCTL, PR_SET_DUMPABLE, usize::from(dumpable))
+            }
+            PrctlOption::GetDumpable => syscall!(PRCTL, PR_GET_DUMPABLE),
+            // Unused arguments have to be 0
+            PrctlOption::SetNoNewPrivs => syscall!(PRCTL, PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0),
+            PrctlOption::GetNoNewPrivs => syscall!(PRCTL, PR_GET_NO_NEW_PRIVS, 0, 0, 0, 0),
+            PrctlOption::SetChildSubreaper(reaper) => {
+                syscall!(PRCTL, PR_SET_CHILD_SUBREAPER, usize::from(reaper))
+            }
+            PrctlOption::GetChildSubreaper(reaper) => {
+                syscall!(PRCTL, PR_GET_CHILD_SUBREAPER, core::ptr::from_mut(reaper))
+            }
+            PrctlOption::CapbsetDrop(cap) => syscall!(PRCTL, PR_CAPBSET_DROP, cap),
+        }
+    };
+    bail_on_below_zero!(res, "`PRCTL` syscall failed");
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{SignalKind, WaitPidFlags};
+    use crate::process::{exit, fork, prctl, wait_pid, PrctlOption, TASK_COMM_LEN};
+
+    #[test]
+    fn set_get_thread_name() {
+        // Test threads are separate, so renaming this one is fine
+        prctl(PrctlOption::SetName(unix_lit!("rusl-prctl-test-long"))).unwrap();
+        let mut name = [0u8; TASK_COMM_LEN];
+        prctl(PrctlOption::GetName(&mut name)).unwrap();
+        assert_eq!(b"rusl-prctl-test\0", &name);
+    }
+
+    #[test]
+    fn dumpable_roundtrip() {
+        assert_eq!(1, prctl(PrctlOption::GetDumpable).unwrap());
+        prctl(PrctlOption::SetDumpable(true)).unwrap();
+        assert_eq!(1, prctl(PrctlOption::GetDumpable).unwrap());
+    }
+
+    #[test]
+    fn irreversible_options_in_child() {
+        // These affect the whole process and can't be undone, so try them in a child
+        let child = unsafe { fork().unwrap() };
+        if child == 0 {
+            let mut code = 0;
+            prctl(PrctlOption::SetPdeathsig(Some(SignalKind::SIGTERM))).unwrap();
+            let mut sig = 0;
+            prctl(PrctlOption::GetPdeathsig(&mut sig)).unwrap();
+            if sig != SignalKind::SIGTERM.value() {
+                code |= 1;
+            }
+            prctl(PrctlOption::SetNoNewPrivs).unwrap();
+            if prctl(PrctlOption::GetNoNewPrivs).unwrap() != 1 {
+                code |= 2;
+            }
+            prctl(PrctlOption::SetChildSubreaper(true)).unwrap();
+            let mut reaper = 0;
+            prctl(PrctlOption::GetChildSubreaper(&mut reaper)).unwrap();
+            if reaper != 1 {
+                code |= 4;
+            }
+            // Dropping `CAP_SYS_BOOT` needs `CAP_SETPCAP`, which we may not have
+            match prctl(PrctlOption::CapbsetDrop(22)) {
+                Ok(_) => {}
+                Err(e) if e.code == Some(Errno::EPERM) => {}
+                Err(_) => code |= 8,
+            }
+            exit(code);
+        }
+        let res = wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+}
@@ -0,0 +1,70 @@
+use core::mem::MaybeUninit;
+
+use sc::syscall;
+
+use crate::platform::{PidT, RLimit, Resource};
+
+/// Get and/or set the limit of `resource` for the process `pid`, or the calling process if `0`.
+/// A `None` `new_limit` only gets the current limit. Returns the previous limit.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/prlimit.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn prlimit64(
+    pid: PidT,
+    resource: Resource,
+    new_limit: Option<&RLimit>,
+) -> crate::Result<RLimit> {
+    let mut old = MaybeUninit::<RLimit>::uninit();
+    let res = unsafe {
+        syscall!(
+            PRLIMIT64,
+            pid,
+            resource.into_raw(),
+            new_limit.map_or_else(core::ptr::null, core::ptr::from_ref::<RLimit>),
+            old.as_mut_ptr()
+        )
+    };
+    bail_on_below_zero!(res, "`PRLIMIT64` syscall failed");
+    // Safety: Written on success
+    Ok(unsafe { old.assume_init() })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{RLimit, Resource, WaitPidFlags};
+    use crate::process::{exit, fork, prlimit64, wait_pid};
+
+    #[test]
+    fn get_nofile() {
+        let limit = prlimit64(0, Resource::Nofile, None).unwrap();
+        assert!(limit.cur <= limit.max);
+        assert!(limit.cur > 0);
+    }
+
+    #[test]
+    fn lower_core_in_child() {
+        let child = unsafe { fork().unwrap() };
+        if child == 0 {
+            let mut code = 0;
+            let orig = prlimit64(0, Resource::Core, None).unwrap();
+            let new = RLimit::new(0, orig.max);
+            if prlimit64(0, Resource::Core, Some(&new)).unwrap() != orig {
+                code |= 1;
+            }
+            if prlimit64(0, Resource::Core, None).unwrap() != new {
+                code |= 2;
+            }
+            // Soft above hard
+            let bad = RLimit::new(2, 1);
+            match prlimit64(0, Resource::Core, Some(&bad)) {
+                Err(e) if e.code == Some(Errno::EINVAL) => {}
+                _ => code |= 4,
+            }
+            exit(code);
+        }
+        let res = wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+}
@@ -0,0 +1,237 @@
+use sc::syscall;
+
+use crate::platform::{CpuSet, PidT, PriorityWhich, SchedPolicy};
+
+/// Get the CPUs that the thread `tid` may run on, or the calling thread if `0`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sched_getaffinity(tid: PidT) -> crate::Result<CpuSet> {
+    let mut set = CpuSet::new();
+    let res = unsafe {
+        syscall!(
+            SCHED_GETAFFINITY,
+            tid,
+            CpuSet::byte_size(),
+            core::ptr::addr_of_mut!(set)
+        )
+    };
+    bail_on_below_zero!(res, "`SCHED_GETAFFINITY` syscall failed");
+    Ok(set)
+}
+
+/// Get the CPUs that the thread `tid` may run on, or the calling thread if `0`, as a bit mask
+/// in `mask`, returning the number of bytes written.
+/// For machines where the kernel's mask is larger than a [`CpuSet`].
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html)
+/// # Errors
+/// See above, `EINVAL` if `mask` is smaller than the kernel's mask
+#[inline]
+pub fn sched_getaffinity_mask(tid: PidT, mask: &mut [u64]) -> crate::Result<usize> {
+    let res = unsafe {
+        syscall!(
+            SCHED_GETAFFINITY,
+            tid,
+            core::mem::size_of_val(mask),
+            mask.as_mut_ptr()
+        )
+    };
+    bail_on_below_zero!(res, "`SCHED_GETAFFINITY` syscall failed");
+    Ok(res)
+}
+
+/// Restrict the thread `tid`, or the calling thread if `0`, to run on the CPUs in `set`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_setaffinity.2.html)
+/// # Errors
+/// See above, `EINVAL` if `set` contains no online CPUs that the thread is allowed to use
+#[inline]
+pub fn sched_setaffinity(tid: PidT, set: &CpuSet) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            SCHED_SETAFFINITY,
+            tid,
+            CpuSet::byte_size(),
+            core::ptr::from_ref::<CpuSet>(set)
+        )
+    };
+    bail_on_below_zero!(res, "`SCHED_SETAFFINITY` syscall failed");
+    Ok(())
+}
+
+/// Give up the CPU, the calling thread is moved to the end of the queue for its priority.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_yield.2.html)
+/// Always successful
+#[inline]
+pub fn sched_yield() {
+    unsafe {
+        syscall!(SCHED_YIELD);
+    }
+}
+
+/// Set the scheduling policy and priority of the thread `tid`, or the calling thread if `0`.
+/// The priority has to be `0` for the non real-time policies, and `1..=99` for `Fifo` and `Rr`.
+/// If `reset_on_fork`, children revert to the default policy.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sched_setscheduler(
+    tid: PidT,
+    policy: SchedPolicy,
+    priority: i32,
+    reset_on_fork: bool,
+) -> crate::Result<()> {
+    let mut raw_policy = policy.into_raw();
+    if reset_on_fork {
+        raw_policy |= SchedPolicy::SCHED_RESET_ON_FORK;
+    }
+    // `struct sched_param` only contains the priority
+    let res = unsafe {
+        syscall!(
+            SCHED_SETSCHEDULER,
+            tid,
+            raw_policy,
+            core::ptr::from_ref::<i32>(&priority)
+        )
+    };
+    bail_on_below_zero!(res, "`SCHED_SETSCHEDULER` syscall failed");
+    Ok(())
+}
+
+/// Get the scheduling policy of the thread `tid`, or the calling thread if `0`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sched_getscheduler.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sched_getscheduler(tid: PidT) -> crate::Result<SchedPolicy> {
+    let res = unsafe { syscall!(SCHED_GETSCHEDULER, tid) };
+    bail_on_below_zero!(res, "`SCHED_GETSCHEDULER` syscall failed");
+    SchedPolicy::from_raw(res as i32)
+        .ok_or_else(|| crate::Error::no_code("`SCHED_GETSCHEDULER` returned an unknown policy"))
+}
+
+/// Get the CPU and NUMA node that the calling thread is currently running on,
+/// which may have changed by the time this returns.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getcpu.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn getcpu() -> crate::Result<(u32, u32)> {
+    let mut cpu = 0u32;
+    let mut node = 0u32;
+    let res = unsafe {
+        syscall!(
+            GETCPU,
+            core::ptr::addr_of_mut!(cpu),
+            core::ptr::addr_of_mut!(node),
+            0
+        )
+    };
+    bail_on_below_zero!(res, "`GETCPU` syscall failed");
+    Ok((cpu, node))
+}
+
+/// Get the nice value, `-20..=19` where lower is higher priority, of `who`.
+/// For a group or user, the highest priority of its processes.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getpriority.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn getpriority(which: PriorityWhich, who: u32) -> crate::Result<i32> {
+    let res = unsafe { syscall!(GETPRIORITY, which.into_raw(), who) };
+    bail_on_below_zero!(res, "`GETPRIORITY` syscall failed");
+    // The syscall returns `20 - nice` to stay positive
+    Ok(20 - res as i32)
+}
+
+/// Set the nice value of `who`, values outside of `-20..=19` are clamped.
+/// Lowering it requires `CAP_SYS_NICE`, or a high enough `RLIMIT_NICE`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/setpriority.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn setpriority(which: PriorityWhich, who: u32, nice: i32) -> crate::Result<()> {
+    let res = unsafe { syscall!(SETPRIORITY, which.into_raw(), who, nice) };
+    bail_on_below_zero!(res, "`SETPRIORITY` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{CpuSet, PriorityWhich, SchedPolicy, WaitPidFlags};
+    use crate::process::{
+        exit, fork, getcpu, getpriority, sched_getaffinity, sched_getaffinity_mask,
+        sched_getscheduler, sched_setaffinity, sched_setscheduler, sched_yield, setpriority,
+        wait_pid,
+    };
+
+    #[test]
+    fn affinity_pin_and_restore() {
+        let orig = sched_getaffinity(0).unwrap();
+        assert!(!orig.is_empty());
+        let first = orig.iter().next().unwrap();
+        let mut pinned = CpuSet::new();
+        pinned.add(first);
+        sched_setaffinity(0, &pinned).unwrap();
+        assert_eq!(pinned, sched_getaffinity(0).unwrap());
+        sched_yield();
+        assert_eq!(first, getcpu().unwrap().0 as usize);
+        expect_errno!(Errno::EINVAL, sched_setaffinity(0, &CpuSet::new()));
+        sched_setaffinity(0, &orig).unwrap();
+        assert_eq!(orig, sched_getaffinity(0).unwrap());
+    }
+
+    #[test]
+    fn affinity_mask_matches_cpu_set() {
+        let set = sched_getaffinity(0).unwrap();
+        let mut mask = [0u64; 128];
+        let written = sched_getaffinity_mask(0, &mut mask).unwrap();
+        assert_eq!(0, written % 8);
+        let count: u32 = mask[..written / 8]
+            .iter()
+            .map(|word| word.count_ones())
+            .sum();
+        assert_eq!(set.count(), count as usize);
+        expect_errno!(Errno::EINVAL, sched_getaffinity_mask(0, &mut []));
+    }
+
+    #[test]
+    fn cpu_set_ops() {
+        let mut set = CpuSet::new();
+        set.add(0).add(63).add(64).add(1023).add(1024);
+        assert_eq!(4, set.count());
+        assert!(set.contains(64));
+        assert!(!set.contains(1024));
+        set.remove(63);
+        assert_eq!(
+            &[0, 64, 1023],
+            set.iter().collect::<std::vec::Vec<_>>().as_slice()
+        );
+    }
+
+    #[test]
+    fn scheduler_and_priority_in_child() {
+        // Lowering priority can't be undone without privileges, do it in a child
+        let child = unsafe { fork().unwrap() };
+        if child == 0 {
+            let mut code = 0;
+            if sched_getscheduler(0).ok() != Some(SchedPolicy::Other) {
+                code |= 1;
+            }
+            sched_setscheduler(0, SchedPolicy::Batch, 0, true).unwrap();
+            if sched_getscheduler(0).ok() != Some(SchedPolicy::Batch) {
+                code |= 2;
+            }
+            let nice = getpriority(PriorityWhich::Process, 0).unwrap();
+            setpriority(PriorityWhich::Process, 0, 19).unwrap();
+            if getpriority(PriorityWhich::Process, 0).ok() != Some(19) || nice > 19 {
+                code |= 4;
+            }
+            exit(code);
+        }
+        let res = wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+}
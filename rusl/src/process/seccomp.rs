@@ -0,0 +1,110 @@
+use sc::syscall;
+
+use crate::platform::{SeccompFlags, SockFprog};
+
+// Not in the bindings, values from `include/uapi/linux/seccomp.h`
+const SECCOMP_SET_MODE_STRICT: u32 = 0;
+const SECCOMP_SET_MODE_FILTER: u32 = 1;
+const SECCOMP_GET_ACTION_AVAIL: u32 = 2;
+
+/// Only allow `read`, `write`, `_exit` and `sigreturn` from now on, anything else kills the thread.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/seccomp.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn seccomp_set_mode_strict() -> crate::Result<()> {
+    let res = unsafe { syscall!(SECCOMP, SECCOMP_SET_MODE_STRICT, 0, 0) };
+    bail_on_below_zero!(res, "`SECCOMP` syscall failed");
+    Ok(())
+}
+
+/// Install a BPF filter that every following syscall of the calling thread, and its
+/// future children, is checked against.
+/// Requires either `CAP_SYS_ADMIN` or `no_new_privs` to have been set with `prctl`.
+/// Returns a notification fd if `SECCOMP_FILTER_FLAG_NEW_LISTENER` is set, otherwise 0.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/seccomp.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn seccomp_set_mode_filter(prog: &SockFprog<'_>, flags: SeccompFlags) -> crate::Result<usize> {
+    let res = unsafe {
+        syscall!(
+            SECCOMP,
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            core::ptr::from_ref::<SockFprog>(prog)
+        )
+    };
+    bail_on_below_zero!(res, "`SECCOMP` syscall failed");
+    Ok(res)
+}
+
+/// Check whether the kernel supports a filter return action, f.e. `SECCOMP_RET_KILL_PROCESS`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/seccomp.2.html)
+/// # Errors
+/// See above, `EOPNOTSUPP` if the action isn't supported
+#[inline]
+pub fn seccomp_get_action_avail(action: u32) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            SECCOMP,
+            SECCOMP_GET_ACTION_AVAIL,
+            0,
+            core::ptr::from_ref::<u32>(&action)
+        )
+    };
+    bail_on_below_zero!(res, "`SECCOMP` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{
+        SeccompFlags, SockFilter, SockFprog, WaitPidFlags, AUDIT_ARCH_NATIVE, BPF_ABS, BPF_JEQ,
+        BPF_JMP, BPF_K, BPF_LD, BPF_RET, BPF_W, SECCOMP_DATA_ARCH_OFFSET, SECCOMP_DATA_NR_OFFSET,
+        SECCOMP_RET_ALLOW, SECCOMP_RET_ERRNO, SECCOMP_RET_KILL_PROCESS,
+    };
+    use crate::process::{
+        exit, fork, prctl, seccomp_get_action_avail, seccomp_set_mode_filter, wait_pid, PrctlOption,
+    };
+    use crate::unistd::get_uid;
+
+    #[test]
+    fn actions_available() {
+        seccomp_get_action_avail(SECCOMP_RET_ALLOW).unwrap();
+        seccomp_get_action_avail(SECCOMP_RET_KILL_PROCESS).unwrap();
+        expect_errno!(Errno::EOPNOTSUPP, seccomp_get_action_avail(0x1234_0000));
+    }
+
+    #[test]
+    #[expect(clippy::cast_sign_loss)]
+    fn filter_makes_getuid_fail() {
+        let filter = [
+            SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, SECCOMP_DATA_ARCH_OFFSET),
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, AUDIT_ARCH_NATIVE, 1, 0),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+            SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, SECCOMP_DATA_NR_OFFSET),
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, sc::nr::GETUID as u32, 0, 1),
+            SockFilter::stmt(
+                BPF_RET | BPF_K,
+                SECCOMP_RET_ERRNO | Errno::EPERM.raw() as u32,
+            ),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW),
+        ];
+        let prog = SockFprog::new(&filter).unwrap();
+        // Filters can't be removed, so install it in a child
+        let child = unsafe { fork().unwrap() };
+        if child == 0 {
+            prctl(PrctlOption::SetNoNewPrivs).unwrap();
+            seccomp_set_mode_filter(&prog, SeccompFlags::empty()).unwrap();
+            let code = match get_uid() {
+                Err(e) if e.code == Some(Errno::EPERM) => 0,
+                _ => 1,
+            };
+            exit(code);
+        }
+        let res = wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+}
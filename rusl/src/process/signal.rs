@@ -4,7 +4,10 @@ use core::mem::MaybeUninit;
 
 use sc::syscall;
 
-use crate::platform::{NonNegativeI32, SaMask, SigSetT, SIG_DFL, SIG_IGN};
+use crate::platform::{
+    NonNegativeI32, SaMask, SigSetT, SignalStack, SigprocmaskHow, TimeSpec, KERNEL_SIGSET_SIZE,
+    SIG_DFL, SIG_IGN,
+};
 
 /// This struct can differ between architectures, it's the same on aarch64 and `x86_64` though.
 #[repr(C)]
@@ -121,8 +124,138 @@ pub unsafe fn add_signal_action(
         signal.into_raw().value(),
         constructed_action.as_ptr(),
         0,
-        8
+        KERNEL_SIGSET_SIZE
     );
     bail_on_below_zero!(res, "`RT_SIGACTION` syscall failed");
     Ok(())
 }
+
+/// Examine and/or change the signal mask of the calling thread, returns the previous mask.
+/// A `None` `set` leaves the mask unchanged, and `how` is ignored.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sigprocmask.2.html)
+/// # Errors
+/// See above
+pub fn sigprocmask(how: SigprocmaskHow, set: Option<&SigSetT>) -> crate::Result<SigSetT> {
+    let mut old = SigSetT::new();
+    let res = unsafe {
+        syscall!(
+            RT_SIGPROCMASK,
+            how.into_raw(),
+            set.map_or_else(core::ptr::null, core::ptr::from_ref::<SigSetT>),
+            core::ptr::addr_of_mut!(old),
+            KERNEL_SIGSET_SIZE
+        )
+    };
+    bail_on_below_zero!(res, "`RT_SIGPROCMASK` syscall failed");
+    Ok(old)
+}
+
+/// Get the set of signals that are pending for delivery to the calling thread,
+/// i.e. raised while blocked.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sigpending.2.html)
+/// # Errors
+/// See above
+pub fn sigpending() -> crate::Result<SigSetT> {
+    let mut set = SigSetT::new();
+    let res = unsafe {
+        syscall!(
+            RT_SIGPENDING,
+            core::ptr::addr_of_mut!(set),
+            KERNEL_SIGSET_SIZE
+        )
+    };
+    bail_on_below_zero!(res, "`RT_SIGPENDING` syscall failed");
+    Ok(set)
+}
+
+/// Wait for one of the signals in `set` to become pending and consume it.
+/// The signals in `set` should be blocked first, otherwise they may get handled
+/// according to their disposition instead.
+/// A `None` `timeout` waits indefinitely.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sigtimedwait.2.html)
+/// # Errors
+/// See above, `EAGAIN` if no signal arrived before the timeout
+pub fn sigtimedwait(set: &SigSetT, timeout: Option<&TimeSpec>) -> crate::Result<SigInfo> {
+    let mut info = MaybeUninit::<SigInfo>::zeroed();
+    let res = unsafe {
+        syscall!(
+            RT_SIGTIMEDWAIT,
+            core::ptr::from_ref::<SigSetT>(set),
+            info.as_mut_ptr(),
+            timeout.map_or_else(core::ptr::null, core::ptr::from_ref::<TimeSpec>),
+            KERNEL_SIGSET_SIZE
+        )
+    };
+    bail_on_below_zero!(res, "`RT_SIGTIMEDWAIT` syscall failed");
+    // Safety: Zeroed is a valid `SigInfo`, and the kernel filled it in
+    Ok(unsafe { info.assume_init() })
+}
+
+/// Set and/or get the alternate signal stack of the calling thread, returns the previous stack.
+/// A `None` `stack` leaves the current stack as is.
+/// Handlers only run on it if installed with `SA_ONSTACK`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sigaltstack.2.html)
+/// # Errors
+/// See above
+/// # Safety
+/// The memory described by `stack` has to stay valid for as long as it's the alternate stack,
+/// and not be used for anything else, since a signal can arrive at any point.
+pub unsafe fn sigaltstack(stack: Option<&SignalStack>) -> crate::Result<SignalStack> {
+    let mut old = MaybeUninit::<SignalStack>::zeroed();
+    let res = syscall!(
+        SIGALTSTACK,
+        stack.map_or_else(core::ptr::null, core::ptr::from_ref::<SignalStack>),
+        old.as_mut_ptr()
+    );
+    bail_on_below_zero!(res, "`SIGALTSTACK` syscall failed");
+    Ok(old.assume_init())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{
+        SigSetT, SignalKind, SignalStack, SignalStackFlags, SigprocmaskHow, TimeSpec,
+    };
+    use crate::process::{
+        get_pid, get_tid, sigaltstack, sigpending, sigprocmask, sigtimedwait, tgkill,
+    };
+
+    #[test]
+    fn tgkill_pending_then_wait() {
+        let mut set = SigSetT::new();
+        set.add(SignalKind::SIGUSR1);
+        let old = sigprocmask(SigprocmaskHow::Block, Some(&set)).unwrap();
+        // Thread directed, so it doesn't land on another test thread
+        tgkill(get_pid(), get_tid(), SignalKind::SIGUSR1).unwrap();
+        assert!(sigpending().unwrap().contains(SignalKind::SIGUSR1));
+        let info = sigtimedwait(&set, Some(&TimeSpec::new(1, 0))).unwrap();
+        assert_eq!(SignalKind::SIGUSR1.value(), info.si_signo);
+        assert!(!sigpending().unwrap().contains(SignalKind::SIGUSR1));
+        expect_errno!(
+            Errno::EAGAIN,
+            sigtimedwait(&set, Some(&TimeSpec::new(0, 1_000_000)))
+        );
+        sigprocmask(SigprocmaskHow::SetMask, Some(&old)).unwrap();
+    }
+
+    #[test]
+    fn set_and_restore_alt_stack() {
+        let mut stack = std::vec![0u8; 64 * 1024];
+        let alt = SignalStack::new(stack.as_mut_ptr().cast(), stack.len());
+        unsafe {
+            let old = sigaltstack(Some(&alt)).unwrap();
+            let current = sigaltstack(None).unwrap();
+            assert_eq!(alt.ss_sp, current.ss_sp);
+            assert_eq!(alt.ss_size, current.ss_size);
+            assert_eq!(SignalStackFlags::empty(), current.ss_flags);
+            sigaltstack(Some(&SignalStack::disabled())).unwrap();
+            let current = sigaltstack(None).unwrap();
+            assert!(current.ss_flags.contains(SignalStackFlags::SS_DISABLE));
+            sigaltstack(Some(&old)).unwrap();
+            // Too small to be usable
+            let tiny = SignalStack::new(stack.as_mut_ptr().cast(), 16);
+            expect_errno!(Errno::ENOMEM, sigaltstack(Some(&tiny)));
+        }
+    }
+}
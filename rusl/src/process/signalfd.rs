@@ -0,0 +1,70 @@
+use sc::syscall;
+
+use crate::platform::{Fd, SigSetT, SignalFdFlags, KERNEL_SIGSET_SIZE};
+
+/// Create a file descriptor that accepts the signals in `mask`, or if `fd` is provided,
+/// replace the mask of that existing signalfd.
+/// The signals should be blocked with `sigprocmask` first, otherwise they'll get handled
+/// according to their default disposition before they can be read.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/signalfd.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn signalfd(fd: Option<Fd>, mask: &SigSetT, flags: SignalFdFlags) -> crate::Result<Fd> {
+    let res = unsafe {
+        syscall!(
+            SIGNALFD4,
+            fd.map_or(-1, Fd::value),
+            core::ptr::from_ref::<SigSetT>(mask),
+            KERNEL_SIGSET_SIZE,
+            flags.bits()
+        )
+    };
+    Fd::coerce_from_register(res, "`SIGNALFD4` syscall failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use sc::syscall;
+
+    use crate::platform::{SigSetT, SignalFdFlags, SignalFdSigInfo, SignalKind, SigprocmaskHow};
+    use crate::process::{get_pid, signalfd, sigprocmask};
+    use crate::unistd::{close, read};
+
+    #[test]
+    fn signalfd_reads_blocked_signal() {
+        let mut set = SigSetT::new();
+        set.add(SignalKind::SIGUSR2);
+        let old = sigprocmask(SigprocmaskHow::Block, Some(&set)).unwrap();
+        let fd = signalfd(None, &set, SignalFdFlags::SFD_CLOEXEC).unwrap();
+        // Needs to be thread directed, a process directed signal could end up on another
+        // test thread which doesn't have it blocked.
+        unsafe {
+            let tid = syscall!(GETTID);
+            let res = syscall!(TGKILL, get_pid(), tid, SignalKind::SIGUSR2.value());
+            assert_eq!(0, res);
+        }
+        let mut info = SignalFdSigInfo::new_zeroed();
+        let buf = unsafe {
+            core::slice::from_raw_parts_mut(
+                core::ptr::addr_of_mut!(info).cast::<u8>(),
+                SignalFdSigInfo::SIZE,
+            )
+        };
+        let read_bytes = read(fd, buf).unwrap();
+        assert_eq!(SignalFdSigInfo::SIZE, read_bytes);
+        assert_eq!(SignalKind::SIGUSR2.value(), info.signal().unwrap().value());
+        close(fd).unwrap();
+        sigprocmask(SigprocmaskHow::SetMask, Some(&old)).unwrap();
+    }
+
+    #[test]
+    fn sigprocmask_reports_previous() {
+        let mut set = SigSetT::new();
+        set.add(SignalKind::SIGWINCH);
+        let old = sigprocmask(SigprocmaskHow::Block, Some(&set)).unwrap();
+        let current = sigprocmask(SigprocmaskHow::Block, None).unwrap();
+        assert!(current.contains(SignalKind::SIGWINCH));
+        sigprocmask(SigprocmaskHow::SetMask, Some(&old)).unwrap();
+    }
+}
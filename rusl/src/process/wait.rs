@@ -1,6 +1,6 @@
 use core::ptr::addr_of_mut;
 
-use crate::platform::WaitPidFlags;
+use crate::platform::{WaitFlags, WaitIdInfo, WaitIdType, WaitPidFlags};
 use sc::syscall;
 
 #[derive(Debug, Copy, Clone)]
@@ -24,3 +24,21 @@ pub fn wait_pid(pid: i32, flags: WaitPidFlags) -> crate::Result<WaitPidResult> {
         status: wstatus,
     })
 }
+
+/// Waits for a state change in the specified children, returns `None` if `WNOHANG` was
+/// specified and no child has changed state.
+/// Use `WaitIdType::PidFd` to wait on a pidfd.
+/// See [Linux docs for details](https://man7.org/linux/man-pages/man2/waitid.2.html)
+/// # Errors
+/// See above
+pub fn wait_id(id: WaitIdType, flags: WaitFlags) -> crate::Result<Option<WaitIdInfo>> {
+    let (id_type, id) = id.into_raw();
+    let mut info = WaitIdInfo::new_zeroed();
+    let res = unsafe { syscall!(WAITID, id_type, id, addr_of_mut!(info), flags.bits(), 0) };
+    bail_on_below_zero!(res, "`WAITID` syscall failed");
+    // Linux zeroes the info if `WNOHANG` is specified and there's nothing to report
+    if info.pid() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(info))
+}
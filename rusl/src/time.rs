@@ -1,5 +1,7 @@
 pub use clock_get_time::{clock_get_monotonic_time, clock_get_real_time, clock_get_time};
 pub use sleep::{nanosleep, nanosleep_same_ptr};
+pub use timerfd::{timerfd_create, timerfd_gettime, timerfd_settime};
 
 mod clock_get_time;
 mod sleep;
+mod timerfd;
@@ -0,0 +1,90 @@
+use sc::syscall;
+
+use crate::platform::{ClockId, Fd, ITimerSpec, TimerFdFlags, TimerFdSetFlags};
+use crate::Result;
+
+/// Create a new timer that notifies through a file descriptor, it starts disarmed.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/timerfd_create.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn timerfd_create(clock_id: ClockId, flags: TimerFdFlags) -> Result<Fd> {
+    let res = unsafe { syscall!(TIMERFD_CREATE, clock_id.0, flags.bits()) };
+    Fd::coerce_from_register(res, "`TIMERFD_CREATE` syscall failed")
+}
+
+/// Arm or disarm the timer, returns the previous setting.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/timerfd_settime.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn timerfd_settime(
+    fd: Fd,
+    flags: TimerFdSetFlags,
+    new_value: &ITimerSpec,
+) -> Result<ITimerSpec> {
+    let mut old = ITimerSpec::new_zeroed();
+    let res = unsafe {
+        syscall!(
+            TIMERFD_SETTIME,
+            fd.0,
+            flags.bits(),
+            core::ptr::from_ref::<ITimerSpec>(new_value),
+            core::ptr::addr_of_mut!(old)
+        )
+    };
+    bail_on_below_zero!(res, "`TIMERFD_SETTIME` syscall failed");
+    Ok(old)
+}
+
+/// Get the current setting of the timer, the `value` is always relative,
+/// even if the timer was set with an absolute time.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/timerfd_settime.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn timerfd_gettime(fd: Fd) -> Result<ITimerSpec> {
+    let mut cur = ITimerSpec::new_zeroed();
+    let res = unsafe { syscall!(TIMERFD_GETTIME, fd.0, core::ptr::addr_of_mut!(cur)) };
+    bail_on_below_zero!(res, "`TIMERFD_GETTIME` syscall failed");
+    Ok(cur)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{ClockId, ITimerSpec, TimeSpec, TimerFdFlags, TimerFdSetFlags};
+    use crate::time::{timerfd_create, timerfd_gettime, timerfd_settime};
+    use crate::unistd::{close, read};
+
+    #[test]
+    fn timerfd_fires_once() {
+        let fd = timerfd_create(ClockId::CLOCK_MONOTONIC, TimerFdFlags::TFD_CLOEXEC).unwrap();
+        let cur = timerfd_gettime(fd).unwrap();
+        assert_eq!(ITimerSpec::new_zeroed(), cur);
+        let spec = ITimerSpec::new(TimeSpec::new(0, 1_000_000), TimeSpec::new_zeroed());
+        let old = timerfd_settime(fd, TimerFdSetFlags::empty(), &spec).unwrap();
+        assert_eq!(ITimerSpec::new_zeroed(), old);
+        let mut buf = [0u8; 8];
+        assert_eq!(8, read(fd, &mut buf).unwrap());
+        assert_eq!(1, u64::from_ne_bytes(buf));
+        close(fd).unwrap();
+    }
+
+    #[test]
+    fn timerfd_nonblock_not_expired() {
+        let fd = timerfd_create(
+            ClockId::CLOCK_MONOTONIC,
+            TimerFdFlags::TFD_CLOEXEC | TimerFdFlags::TFD_NONBLOCK,
+        )
+        .unwrap();
+        let spec = ITimerSpec::new(TimeSpec::new(1_000, 0), TimeSpec::new(1_000, 0));
+        timerfd_settime(fd, TimerFdSetFlags::empty(), &spec).unwrap();
+        let cur = timerfd_gettime(fd).unwrap();
+        assert!(cur.value().seconds() <= 1_000);
+        assert_eq!(TimeSpec::new(1_000, 0), cur.interval());
+        let mut buf = [0u8; 8];
+        expect_errno!(Errno::EAGAIN, read(fd, &mut buf));
+        close(fd).unwrap();
+    }
+}
@@ -1,36 +1,64 @@
-pub use chdir::chdir;
+pub use chdir::{chdir, fchdir};
+pub use chmod::{fchmod, fchmodat};
+pub use chown::fchownat;
 pub use close::close;
 pub use copy_file_range::copy_file_range;
-pub use dup::{dup2, dup3};
-pub use fcntl::{fcntl_get_file_status, fcntl_set_file_status};
+pub use dup::{dup, dup2, dup3};
+pub use eventfd::eventfd;
+pub use fallocate::fallocate;
+pub use fcntl::{fcntl_add_seals, fcntl_get_file_status, fcntl_get_seals, fcntl_set_file_status};
 pub use get_dents::get_dents;
-pub use getuid::get_uid;
+pub use getcwd::getcwd;
+pub use getrandom::getrandom;
+pub use getuid::{get_egid, get_euid, get_gid, get_uid};
+pub use groups::{getgroups, setgroups};
+pub use inotify::{inotify_add_watch, inotify_init1, inotify_rm_watch};
+pub use link::{linkat, readlinkat, symlinkat};
+pub use memfd::memfd_create;
 pub use mkdir::{mkdir, mkdir_at};
-pub use mmap::{mmap, munmap};
+pub use mmap::{
+    madvise, mincore, mlock, mlock2, mlockall, mmap, mprotect, mremap, msync, munlock, munlockall,
+    munmap,
+};
 pub use mount::{mount, unmount};
 pub use open::{open, open_at, open_at_mode, open_mode, open_raw};
 pub use pipe::{pipe, pipe2};
 pub use read::{read, readv};
 pub use rename::{rename, rename_at, rename_at2, rename_flags};
 pub use seek::{lseek, Whence};
+pub use sendfile::sendfile;
 pub use setgid::setgid;
 pub use setpgid::setpgid;
 pub use setsid::setsid;
 pub use setuid::setuid;
-pub use stat::{stat, stat_fd, statat};
+pub use splice::{splice, tee, vmsplice};
+pub use stat::{stat, stat_fd, statat, statx};
 pub use swapon::swapon;
+pub use sync::{fdatasync, fsync, sync_file_range, syncfs};
+pub use truncate::ftruncate;
 pub use uname::uname;
 pub use unlink::{rmdir, unlink, unlink_at, unlink_flags, UnlinkFlags};
 pub use unshare::unshare;
+pub use utimensat::utimensat;
 pub use write::{write, writev};
 
 mod chdir;
+mod chmod;
+mod chown;
 mod close;
 mod copy_file_range;
 mod dup;
+mod eventfd;
+mod fallocate;
 mod fcntl;
 mod get_dents;
+mod getcwd;
+mod getrandom;
 mod getuid;
+mod groups;
+mod inotify;
+mod link;
+mod memfd;
 mod mkdir;
 mod mmap;
 mod mount;
@@ -39,15 +67,20 @@ mod pipe;
 mod read;
 mod rename;
 mod seek;
+mod sendfile;
 mod setgid;
 mod setpgid;
 mod setsid;
 mod setuid;
+mod splice;
 mod stat;
 mod swapon;
+mod sync;
 #[cfg(test)]
 mod test;
+mod truncate;
 mod uname;
 mod unlink;
 mod unshare;
+mod utimensat;
 mod write;
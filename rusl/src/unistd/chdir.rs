@@ -1,5 +1,6 @@
 use sc::syscall;
 
+use crate::platform::Fd;
 use crate::string::unix_str::UnixStr;
 use crate::Result;
 
@@ -13,3 +14,14 @@ pub fn chdir(path: &UnixStr) -> Result<()> {
     bail_on_below_zero!(res, "`CHDIR` syscall failed");
     Ok(())
 }
+
+/// Changes the working directory of the current process to the directory referred to by `fd`.
+/// See the [Linux docs for details](https://man7.org/linux/man-pages/man2/fchdir.2.html)
+/// # Errors
+/// See above docs
+#[inline]
+pub fn fchdir(fd: Fd) -> Result<()> {
+    let res = unsafe { syscall!(FCHDIR, fd.0) };
+    bail_on_below_zero!(res, "`FCHDIR` syscall failed");
+    Ok(())
+}
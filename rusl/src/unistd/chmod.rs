@@ -0,0 +1,67 @@
+use sc::syscall;
+
+use crate::platform::{Fd, Mode, AT_FDCWD};
+use crate::string::unix_str::UnixStr;
+
+/// Change the permissions of the file at `fd`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/chmod.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn fchmod(fd: Fd, mode: Mode) -> crate::Result<()> {
+    let res = unsafe { syscall!(FCHMOD, fd.0, mode.bits()) };
+    bail_on_below_zero!(res, "`FCHMOD` syscall failed");
+    Ok(())
+}
+
+/// Change the permissions of the file at `path` relative to `dir_fd`, or the current directory
+/// if `None`. Symlinks are always followed, their own permissions are never used.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/chmod.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn fchmodat(dir_fd: Option<Fd>, path: &UnixStr, mode: Mode) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            FCHMODAT,
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            path.as_ptr(),
+            mode.bits()
+        )
+    };
+    bail_on_below_zero!(res, "`FCHMODAT` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{Mode, OpenFlags};
+    use crate::unistd::{close, fchmod, fchmodat, open_mode, stat, unlink};
+
+    #[test]
+    fn chmod_file() {
+        let path = unix_lit!("test-files/unistd/chmod_test");
+        let _ = unlink(path);
+        let fd = open_mode(
+            path,
+            OpenFlags::O_CREAT | OpenFlags::O_WRONLY | OpenFlags::O_CLOEXEC,
+            Mode::S_IRUSR | Mode::S_IWUSR,
+        )
+        .unwrap();
+        fchmodat(None, path, Mode::from(0o751)).unwrap();
+        assert_eq!(0o751, stat(path).unwrap().st_mode & 0o7777);
+        fchmod(fd, Mode::from(0o640)).unwrap();
+        assert_eq!(0o640, stat(path).unwrap().st_mode & 0o7777);
+        expect_errno!(
+            Errno::ENOENT,
+            fchmodat(
+                None,
+                unix_lit!("test-files/unistd/missing"),
+                Mode::from(0o640)
+            )
+        );
+        close(fd).unwrap();
+        unlink(path).unwrap();
+    }
+}
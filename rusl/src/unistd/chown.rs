@@ -0,0 +1,52 @@
+use sc::syscall;
+
+use crate::platform::{DirFlags, Fd, GidT, UidT, AT_FDCWD};
+use crate::string::unix_str::UnixStr;
+
+/// Change the owner and/or group of the file at `path` relative to `dir_fd`, or the current
+/// directory if `None`. A `None` owner or group is left unchanged.
+/// With `AT_EMPTY_PATH` and an empty `path`, changes `dir_fd` itself, with `AT_SYMLINK_NOFOLLOW`
+/// changes a symlink rather than what it points to.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/chown.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn fchownat(
+    dir_fd: Option<Fd>,
+    path: &UnixStr,
+    owner: Option<UidT>,
+    group: Option<GidT>,
+    flags: DirFlags,
+) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            FCHOWNAT,
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            path.as_ptr(),
+            owner.unwrap_or(UidT::MAX),
+            group.unwrap_or(GidT::MAX),
+            flags.bits().0
+        )
+    };
+    bail_on_below_zero!(res, "`FCHOWNAT` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform::DirFlags;
+    use crate::unistd::{fchownat, stat};
+
+    #[test]
+    fn chown_to_self() {
+        let path = unix_lit!("test-files/can_stat.txt");
+        let before = stat(path).unwrap();
+        // Changing to the current owner is always allowed
+        fchownat(None, path, Some(before.st_uid), None, DirFlags::empty()).unwrap();
+        fchownat(None, path, None, Some(before.st_gid), DirFlags::empty()).unwrap();
+        fchownat(None, path, None, None, DirFlags::AT_SYMLINK_NOFOLLOW).unwrap();
+        let after = stat(path).unwrap();
+        assert_eq!(before.st_uid, after.st_uid);
+        assert_eq!(before.st_gid, after.st_gid);
+    }
+}
@@ -3,6 +3,22 @@ use sc::syscall;
 use crate::error::Errno;
 use crate::platform::{Fd, OpenFlags};
 
+/// Duplicates `old` into the lowest free fd and returns it, setting `O_CLOEXEC` on it
+/// atomically if `cloexec` is set.
+/// See the [Linux docs for details](https://man7.org/linux/man-pages/man2/dup.2.html)
+/// and for [`F_DUPFD_CLOEXEC`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn dup(old: Fd, cloexec: bool) -> crate::Result<Fd> {
+    let res = if cloexec {
+        unsafe { syscall!(FCNTL, old.0, linux_rust_bindings::fcntl::F_DUPFD_CLOEXEC, 0) }
+    } else {
+        unsafe { syscall!(DUP, old.0) }
+    };
+    Fd::coerce_from_register(res, "`DUP` syscall failed")
+}
+
 /// Duplicates `old` into `new` overwriting `new` if exists.
 /// See the [Linux docs for details](https://man7.org/linux/man-pages/man2/dup.2.html)
 /// # Errors
@@ -55,4 +71,18 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[test]
+    fn dup_sets_cloexec() {
+        let get_fd_flags =
+            |fd: Fd| unsafe { syscall!(FCNTL, fd.0, linux_rust_bindings::fcntl::F_GETFD) };
+        let cloexec = dup(STDIN, true).unwrap();
+        let inherited = dup(STDIN, false).unwrap();
+        assert_ne!(cloexec, inherited);
+        assert!(cloexec.0 > 2 && inherited.0 > 2);
+        assert_eq!(1, get_fd_flags(cloexec) & 1);
+        assert_eq!(0, get_fd_flags(inherited) & 1);
+        crate::unistd::close(cloexec).unwrap();
+        crate::unistd::close(inherited).unwrap();
+    }
 }
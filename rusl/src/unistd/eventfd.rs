@@ -0,0 +1,49 @@
+use sc::syscall;
+
+use crate::platform::{EventFdFlags, Fd};
+
+/// Create a file descriptor holding a 64-bit counter starting at `initval`.
+/// Writes add to the counter, reads return it and reset it to zero, or with `EFD_SEMAPHORE`,
+/// return 1 and decrement it.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/eventfd.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn eventfd(initval: u32, flags: EventFdFlags) -> crate::Result<Fd> {
+    let res = unsafe { syscall!(EVENTFD2, initval, flags.bits()) };
+    Fd::coerce_from_register(res, "`EVENTFD2` syscall failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::EventFdFlags;
+    use crate::unistd::{close, eventfd, read, write};
+
+    #[test]
+    fn eventfd_counts() {
+        let fd = eventfd(2, EventFdFlags::EFD_CLOEXEC | EventFdFlags::EFD_NONBLOCK).unwrap();
+        write(fd, &3u64.to_ne_bytes()).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(8, read(fd, &mut buf).unwrap());
+        assert_eq!(5, u64::from_ne_bytes(buf));
+        expect_errno!(Errno::EAGAIN, read(fd, &mut buf));
+        close(fd).unwrap();
+    }
+
+    #[test]
+    fn eventfd_semaphore() {
+        let fd = eventfd(
+            2,
+            EventFdFlags::EFD_CLOEXEC | EventFdFlags::EFD_NONBLOCK | EventFdFlags::EFD_SEMAPHORE,
+        )
+        .unwrap();
+        let mut buf = [0u8; 8];
+        read(fd, &mut buf).unwrap();
+        assert_eq!(1, u64::from_ne_bytes(buf));
+        read(fd, &mut buf).unwrap();
+        assert_eq!(1, u64::from_ne_bytes(buf));
+        expect_errno!(Errno::EAGAIN, read(fd, &mut buf));
+        close(fd).unwrap();
+    }
+}
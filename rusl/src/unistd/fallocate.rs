@@ -0,0 +1,54 @@
+use sc::syscall;
+
+use crate::platform::{FallocateFlags, Fd, OffT};
+
+/// Manipulate the allocated disk space of the file at `fd` in the range `offset..offset + len`.
+/// With no flags, space is allocated and the file size extended if needed,
+/// `FALLOC_FL_PUNCH_HOLE` deallocates the range and has to be combined with `FALLOC_FL_KEEP_SIZE`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/fallocate.2.html)
+/// # Errors
+/// See above, `EOPNOTSUPP` if the filesystem doesn't support the mode
+#[inline]
+pub fn fallocate(fd: Fd, mode: FallocateFlags, offset: OffT, len: OffT) -> crate::Result<()> {
+    let res = unsafe { syscall!(FALLOCATE, fd.0, mode.bits(), offset, len) };
+    bail_on_below_zero!(res, "`FALLOCATE` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{FallocateFlags, MemfdFlags};
+    use crate::unistd::{
+        close, fallocate, ftruncate, lseek, memfd_create, read, stat_fd, write, Whence,
+    };
+
+    #[test]
+    fn fallocate_modes() {
+        let fd = memfd_create(unix_lit!("rusl-fallocate"), MemfdFlags::MFD_CLOEXEC).unwrap();
+        fallocate(fd, FallocateFlags::empty(), 0, 8192).unwrap();
+        assert_eq!(8192, stat_fd(fd).unwrap().st_size);
+        fallocate(fd, FallocateFlags::FALLOC_FL_KEEP_SIZE, 8192, 8192).unwrap();
+        assert_eq!(8192, stat_fd(fd).unwrap().st_size);
+        assert_eq!(4, write(fd, b"data").unwrap());
+        fallocate(
+            fd,
+            FallocateFlags::FALLOC_FL_PUNCH_HOLE | FallocateFlags::FALLOC_FL_KEEP_SIZE,
+            0,
+            4096,
+        )
+        .unwrap();
+        assert_eq!(8192, stat_fd(fd).unwrap().st_size);
+        lseek(fd, 0, Whence::SET).unwrap();
+        let mut buf = [1u8; 4];
+        assert_eq!(4, read(fd, &mut buf).unwrap());
+        assert_eq!([0u8; 4], buf);
+        // Punching a hole requires keep-size
+        expect_errno!(
+            Errno::EOPNOTSUPP,
+            fallocate(fd, FallocateFlags::FALLOC_FL_PUNCH_HOLE, 0, 4096)
+        );
+        ftruncate(fd, 0).unwrap();
+        close(fd).unwrap();
+    }
+}
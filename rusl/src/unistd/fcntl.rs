@@ -1,4 +1,4 @@
-use crate::platform::{FcntlFileStatusCmd, Fd, OpenFlags};
+use crate::platform::{FcntlFileStatusCmd, Fd, OpenFlags, SealFlags};
 use crate::Result;
 use sc::syscall;
 
@@ -30,3 +30,33 @@ pub fn fcntl_set_file_status(fd: Fd, flag: OpenFlags) -> Result<()> {
     bail_on_below_zero!(res, "`FCNTL` syscall failed");
     Ok(())
 }
+
+/// Add seals to a file, the file needs to have been created with `MFD_ALLOW_SEALING`, and
+/// seals can't be removed once added.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+/// # Errors
+/// See above, `EPERM` if the file is sealed with `F_SEAL_SEAL`, `EBUSY` if adding
+/// `F_SEAL_WRITE` while there are writable shared mappings
+pub fn fcntl_add_seals(fd: Fd, seals: SealFlags) -> Result<()> {
+    let res = unsafe {
+        syscall!(
+            FCNTL,
+            fd.0,
+            linux_rust_bindings::fcntl::F_ADD_SEALS,
+            seals.bits()
+        )
+    };
+    bail_on_below_zero!(res, "`FCNTL` syscall failed");
+    Ok(())
+}
+
+/// Get the seals currently placed on a file
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+/// # Errors
+/// See above, `EINVAL` if the file doesn't support sealing
+pub fn fcntl_get_seals(fd: Fd) -> Result<SealFlags> {
+    let res = unsafe { syscall!(FCNTL, fd.0, linux_rust_bindings::fcntl::F_GET_SEALS) };
+    bail_on_below_zero!(res, "`FCNTL` syscall failed");
+    #[expect(clippy::cast_possible_truncation)]
+    Ok(SealFlags(res as i32))
+}
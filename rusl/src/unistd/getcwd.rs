@@ -0,0 +1,29 @@
+use sc::syscall;
+
+/// Write the absolute path of the current working directory into `buf`, null-terminated.
+/// Returns the length written, including the null byte.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getcwd.2.html)
+/// # Errors
+/// See above, `ERANGE` if `buf` is too small
+#[inline]
+pub fn getcwd(buf: &mut [u8]) -> crate::Result<usize> {
+    let res = unsafe { syscall!(GETCWD, buf.as_mut_ptr(), buf.len()) };
+    bail_on_below_zero!(res, "`GETCWD` syscall failed");
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::unistd::getcwd;
+
+    #[test]
+    fn getcwd_matches_std() {
+        let mut buf = [0u8; 4096];
+        let len = getcwd(&mut buf).unwrap();
+        assert_eq!(0, buf[len - 1]);
+        let expect = std::env::current_dir().unwrap();
+        assert_eq!(expect.to_str().unwrap().as_bytes(), &buf[..len - 1]);
+        expect_errno!(Errno::ERANGE, getcwd(&mut buf[..1]));
+    }
+}
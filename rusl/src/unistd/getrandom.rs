@@ -0,0 +1,46 @@
+use sc::syscall;
+
+use crate::platform::GetRandomFlags;
+
+/// Fill `buf` with random bytes from the kernel, returning how many were written.
+/// Reads of up to 256 bytes are never short once the entropy pool is initialized,
+/// larger reads may be interrupted by a signal.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getrandom.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> crate::Result<usize> {
+    let res = unsafe { syscall!(GETRANDOM, buf.as_mut_ptr(), buf.len(), flags.bits()) };
+    bail_on_below_zero!(res, "`GETRANDOM` syscall failed");
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::GetRandomFlags;
+    use crate::unistd::getrandom;
+
+    #[test]
+    fn getrandom_fills() {
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        assert_eq!(64, getrandom(&mut a, GetRandomFlags::empty()).unwrap());
+        assert_eq!(
+            64,
+            getrandom(&mut b, GetRandomFlags::GRND_NONBLOCK).unwrap()
+        );
+        assert_ne!(a, b);
+        assert_eq!(
+            0,
+            getrandom(&mut [], GetRandomFlags::GRND_INSECURE).unwrap()
+        );
+        expect_errno!(
+            Errno::EINVAL,
+            getrandom(
+                &mut a,
+                GetRandomFlags::GRND_RANDOM | GetRandomFlags::GRND_INSECURE
+            )
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use sc::syscall;
 
-use crate::platform::UidT;
+use crate::platform::{GidT, UidT};
 
 /// Gets the userid of this process
 /// See the [linux docs](https://man7.org/linux/man-pages/man2/getuid.2.html) for details
@@ -12,3 +12,49 @@ pub fn get_uid() -> crate::Result<UidT> {
     // We're trusting the syscall [API here](https://man7.org/linux/man-pages/man2/getuid.2.html)
     Ok(res as UidT)
 }
+
+/// Gets the effective userid of this process, used for permission checks
+/// See the [linux docs](https://man7.org/linux/man-pages/man2/geteuid.2.html) for details
+/// # Errors
+/// See above
+pub fn get_euid() -> crate::Result<UidT> {
+    let res = unsafe { syscall!(GETEUID) };
+    bail_on_below_zero!(res, "`GETEUID` failed");
+    Ok(res as UidT)
+}
+
+/// Gets the group id of this process
+/// See the [linux docs](https://man7.org/linux/man-pages/man2/getgid.2.html) for details
+/// # Errors
+/// See above
+pub fn get_gid() -> crate::Result<GidT> {
+    let res = unsafe { syscall!(GETGID) };
+    bail_on_below_zero!(res, "`GETGID` failed");
+    Ok(res as GidT)
+}
+
+/// Gets the effective group id of this process, used for permission checks
+/// See the [linux docs](https://man7.org/linux/man-pages/man2/getegid.2.html) for details
+/// # Errors
+/// See above
+pub fn get_egid() -> crate::Result<GidT> {
+    let res = unsafe { syscall!(GETEGID) };
+    bail_on_below_zero!(res, "`GETEGID` failed");
+    Ok(res as GidT)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unistd::test::proc_status_ids;
+    use crate::unistd::{get_egid, get_euid, get_gid, get_uid};
+
+    #[test]
+    fn ids_match_proc_status() {
+        let uids = proc_status_ids("Uid:");
+        let gids = proc_status_ids("Gid:");
+        assert_eq!(uids[0], get_uid().unwrap());
+        assert_eq!(uids[1], get_euid().unwrap());
+        assert_eq!(gids[0], get_gid().unwrap());
+        assert_eq!(gids[1], get_egid().unwrap());
+    }
+}
@@ -0,0 +1,45 @@
+use sc::syscall;
+
+use crate::platform::GidT;
+
+/// Write the supplementary group ids of this process into `groups`, returning how many were written.
+/// An empty `groups` returns the number of supplementary groups without writing anything.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/getgroups.2.html)
+/// # Errors
+/// See above, `EINVAL` if `groups` is too small
+#[inline]
+pub fn getgroups(groups: &mut [GidT]) -> crate::Result<usize> {
+    let res = unsafe { syscall!(GETGROUPS, groups.len(), groups.as_mut_ptr()) };
+    bail_on_below_zero!(res, "`GETGROUPS` syscall failed");
+    Ok(res)
+}
+
+/// Set the supplementary group ids of this process, requires `CAP_SETGID`
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/setgroups.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn setgroups(groups: &[GidT]) -> crate::Result<()> {
+    let res = unsafe { syscall!(SETGROUPS, groups.len(), groups.as_ptr()) };
+    bail_on_below_zero!(res, "`SETGROUPS` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::unistd::getgroups;
+    use crate::unistd::test::proc_status_ids;
+
+    #[test]
+    fn get_groups() {
+        let num = getgroups(&mut []).unwrap();
+        let mut groups = [0; 256];
+        assert_eq!(num, getgroups(&mut groups).unwrap());
+        let expect = proc_status_ids("Groups:");
+        assert_eq!(expect.as_slice(), &groups[..num]);
+        if num > 0 {
+            expect_errno!(Errno::EINVAL, getgroups(&mut groups[..num - 1]));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use sc::syscall;
+
+use crate::platform::{Fd, InotifyFlags, InotifyMask};
+use crate::string::unix_str::UnixStr;
+
+/// Create a new inotify instance, events can be read from the returned fd and parsed with
+/// `InotifyEvent::try_from_bytes`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/inotify_init.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn inotify_init1(flags: InotifyFlags) -> crate::Result<Fd> {
+    let res = unsafe { syscall!(INOTIFY_INIT1, flags.bits()) };
+    Fd::coerce_from_register(res, "`INOTIFY_INIT1` syscall failed")
+}
+
+/// Add a watch for `path` to the inotify instance, or modify the existing one,
+/// returns the watch descriptor that events for `path` will be tagged with.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn inotify_add_watch(fd: Fd, path: &UnixStr, mask: InotifyMask) -> crate::Result<i32> {
+    let res = unsafe { syscall!(INOTIFY_ADD_WATCH, fd.0, path.as_ptr(), mask.bits()) };
+    bail_on_below_zero!(res, "`INOTIFY_ADD_WATCH` syscall failed");
+    // Watch descriptors are positive ints
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    Ok(res as i32)
+}
+
+/// Remove a watch from the inotify instance, an `IN_IGNORED` event will be generated for it.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn inotify_rm_watch(fd: Fd, wd: i32) -> crate::Result<()> {
+    let res = unsafe { syscall!(INOTIFY_RM_WATCH, fd.0, wd) };
+    bail_on_below_zero!(res, "`INOTIFY_RM_WATCH` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{InotifyEvent, InotifyFlags, InotifyMask, Mode, OpenFlags};
+    use crate::unistd::{
+        close, inotify_add_watch, inotify_init1, inotify_rm_watch, mkdir, open_mode, read, unlink,
+        unlink_flags, UnlinkFlags,
+    };
+
+    #[test]
+    fn inotify_create_and_delete() {
+        let dir = unix_lit!("test-files/inotify-create-delete");
+        let file = unix_lit!("test-files/inotify-create-delete/file.txt");
+        let _ = unlink(file);
+        let _ = unlink_flags(dir, UnlinkFlags::at_removedir());
+        mkdir(dir, Mode::from(0o755)).unwrap();
+        let fd = inotify_init1(InotifyFlags::IN_CLOEXEC | InotifyFlags::IN_NONBLOCK).unwrap();
+        let wd =
+            inotify_add_watch(fd, dir, InotifyMask::IN_CREATE | InotifyMask::IN_DELETE).unwrap();
+        let mut buf = [0u8; InotifyEvent::MIN_BUF_SIZE];
+        expect_errno!(Errno::EAGAIN, read(fd, &mut buf));
+        let created = open_mode(
+            file,
+            OpenFlags::O_CREAT | OpenFlags::O_WRONLY,
+            Mode::from(0o644),
+        )
+        .unwrap();
+        close(created).unwrap();
+        unlink(file).unwrap();
+        let mut read_bytes = read(fd, &mut buf).unwrap();
+        let mut offset = 0;
+        let (ev, used) = InotifyEvent::try_from_bytes(&buf[..read_bytes]).unwrap();
+        offset += used;
+        assert_eq!(wd, ev.wd);
+        assert!(ev.mask.contains(InotifyMask::IN_CREATE));
+        assert_eq!("file.txt", ev.name.unwrap().as_str().unwrap());
+        if offset == read_bytes {
+            read_bytes = read(fd, &mut buf).unwrap();
+            offset = 0;
+        }
+        let (ev, _) = InotifyEvent::try_from_bytes(&buf[offset..read_bytes]).unwrap();
+        assert!(ev.mask.contains(InotifyMask::IN_DELETE));
+        assert_eq!("file.txt", ev.name.unwrap().as_str().unwrap());
+        inotify_rm_watch(fd, wd).unwrap();
+        expect_errno!(Errno::EINVAL, inotify_rm_watch(fd, wd));
+        close(fd).unwrap();
+        unlink_flags(dir, UnlinkFlags::at_removedir()).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+use sc::syscall;
+
+use crate::platform::{Fd, LinkFlags, AT_FDCWD};
+use crate::string::unix_str::UnixStr;
+
+/// Create a symlink at `link_path` relative to `dir_fd`, or the current directory if `None`,
+/// pointing to `target`. `target` is stored as is and doesn't have to exist.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/symlink.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn symlinkat(target: &UnixStr, dir_fd: Option<Fd>, link_path: &UnixStr) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            SYMLINKAT,
+            target.as_ptr(),
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            link_path.as_ptr()
+        )
+    };
+    bail_on_below_zero!(res, "`SYMLINKAT` syscall failed");
+    Ok(())
+}
+
+/// Read the target of the symlink at `path` relative to `dir_fd`, or the current directory if `None`,
+/// into `buf`. The target is not null-terminated, and is silently truncated if `buf` is too small,
+/// if the returned length equals the length of `buf` it may have been truncated.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/readlink.2.html)
+/// # Errors
+/// See above, `EINVAL` if `path` isn't a symlink
+#[inline]
+pub fn readlinkat(dir_fd: Option<Fd>, path: &UnixStr, buf: &mut [u8]) -> crate::Result<usize> {
+    let res = unsafe {
+        syscall!(
+            READLINKAT,
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            path.as_ptr(),
+            buf.as_mut_ptr(),
+            buf.len()
+        )
+    };
+    bail_on_below_zero!(res, "`READLINKAT` syscall failed");
+    Ok(res)
+}
+
+/// Create a hard link at `new_path` relative to `new_dir_fd` to the file at `old_path` relative
+/// to `old_dir_fd`, `None` meaning the current directory.
+/// Symlinks at `old_path` are linked to directly unless `AT_SYMLINK_FOLLOW` is passed.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/link.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn linkat(
+    old_dir_fd: Option<Fd>,
+    old_path: &UnixStr,
+    new_dir_fd: Option<Fd>,
+    new_path: &UnixStr,
+    flags: LinkFlags,
+) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            LINKAT,
+            old_dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            old_path.as_ptr(),
+            new_dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            new_path.as_ptr(),
+            flags.bits()
+        )
+    };
+    bail_on_below_zero!(res, "`LINKAT` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{LinkFlags, OpenFlags};
+    use crate::unistd::{close, linkat, open, readlinkat, stat, symlinkat, unlink};
+
+    #[test]
+    fn symlink_and_readlink() {
+        let link = unix_lit!("test-files/unistd/symlink_test");
+        let _ = unlink(link);
+        symlinkat(unix_lit!("../can_open.txt"), None, link).unwrap();
+        let mut buf = [0u8; 64];
+        let len = readlinkat(None, link, &mut buf).unwrap();
+        assert_eq!(b"../can_open.txt", &buf[..len]);
+        // Truncated silently
+        assert_eq!(4, readlinkat(None, link, &mut buf[..4]).unwrap());
+        expect_errno!(Errno::EEXIST, symlinkat(unix_lit!("other"), None, link));
+        let dir = open(unix_lit!("test-files/unistd"), OpenFlags::O_DIRECTORY).unwrap();
+        let len = readlinkat(Some(dir), unix_lit!("symlink_test"), &mut buf).unwrap();
+        assert_eq!(b"../can_open.txt", &buf[..len]);
+        expect_errno!(
+            Errno::EINVAL,
+            readlinkat(None, unix_lit!("test-files/can_open.txt"), &mut buf)
+        );
+        close(dir).unwrap();
+        unlink(link).unwrap();
+    }
+
+    #[test]
+    fn hard_link() {
+        let link = unix_lit!("test-files/unistd/hardlink_test");
+        let _ = unlink(link);
+        let orig = unix_lit!("test-files/can_stat.txt");
+        linkat(None, orig, None, link, LinkFlags::empty()).unwrap();
+        let orig_stat = stat(orig).unwrap();
+        let link_stat = stat(link).unwrap();
+        assert_eq!(orig_stat.st_ino, link_stat.st_ino);
+        assert_eq!(2, link_stat.st_nlink);
+        expect_errno!(
+            Errno::EEXIST,
+            linkat(None, orig, None, link, LinkFlags::empty())
+        );
+        unlink(link).unwrap();
+        assert_eq!(1, stat(orig).unwrap().st_nlink);
+    }
+}
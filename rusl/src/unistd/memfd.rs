@@ -0,0 +1,64 @@
+use sc::syscall;
+
+use crate::platform::{Fd, MemfdFlags};
+use crate::string::unix_str::UnixStr;
+
+/// Create an anonymous file living in memory, `name` is only used for debugging and shows
+/// up as the target of the symlink in `/proc/self/fd/`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/memfd_create.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn memfd_create(name: &UnixStr, flags: MemfdFlags) -> crate::Result<Fd> {
+    let res = unsafe { syscall!(MEMFD_CREATE, name.as_ptr(), flags.bits()) };
+    Fd::coerce_from_register(res, "`MEMFD_CREATE` syscall failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{MemfdFlags, SealFlags};
+    use crate::unistd::{
+        close, fcntl_add_seals, fcntl_get_seals, ftruncate, lseek, memfd_create, read, stat_fd,
+        write, Whence,
+    };
+
+    #[test]
+    fn memfd_read_write() {
+        let fd = memfd_create(unix_lit!("rusl-memfd"), MemfdFlags::MFD_CLOEXEC).unwrap();
+        assert_eq!(5, write(fd, b"Hello").unwrap());
+        lseek(fd, 0, Whence::SET).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(5, read(fd, &mut buf).unwrap());
+        assert_eq!(b"Hello", &buf[..5]);
+        ftruncate(fd, 4096).unwrap();
+        assert_eq!(4096, stat_fd(fd).unwrap().st_size);
+        // Not created with `MFD_ALLOW_SEALING`, so it starts out sealed with `F_SEAL_SEAL`
+        expect_errno!(Errno::EPERM, fcntl_add_seals(fd, SealFlags::F_SEAL_GROW));
+        close(fd).unwrap();
+    }
+
+    #[test]
+    fn memfd_seals() {
+        let fd = memfd_create(
+            unix_lit!("rusl-memfd-seal"),
+            MemfdFlags::MFD_CLOEXEC | MemfdFlags::MFD_ALLOW_SEALING,
+        )
+        .unwrap();
+        assert_eq!(SealFlags::empty(), fcntl_get_seals(fd).unwrap());
+        ftruncate(fd, 16).unwrap();
+        fcntl_add_seals(fd, SealFlags::F_SEAL_SHRINK | SealFlags::F_SEAL_GROW).unwrap();
+        let seals = fcntl_get_seals(fd).unwrap();
+        assert!(seals.contains(SealFlags::F_SEAL_SHRINK));
+        assert!(seals.contains(SealFlags::F_SEAL_GROW));
+        assert!(!seals.contains(SealFlags::F_SEAL_WRITE));
+        expect_errno!(Errno::EPERM, ftruncate(fd, 8));
+        expect_errno!(Errno::EPERM, ftruncate(fd, 32));
+        // Writes within the current size are fine
+        assert_eq!(5, write(fd, b"Hello").unwrap());
+        fcntl_add_seals(fd, SealFlags::F_SEAL_WRITE | SealFlags::F_SEAL_SEAL).unwrap();
+        expect_errno!(Errno::EPERM, write(fd, b"World"));
+        expect_errno!(Errno::EPERM, fcntl_add_seals(fd, SealFlags::F_SEAL_EXEC));
+        close(fd).unwrap();
+    }
+}
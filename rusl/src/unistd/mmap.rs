@@ -2,7 +2,10 @@ use core::num::NonZeroUsize;
 
 use sc::syscall;
 
-use crate::platform::{Fd, MapAdditionalFlags, MapRequiredFlag, MemoryProtection, OffT};
+use crate::platform::{
+    Fd, MapAdditionalFlags, MapRequiredFlag, MemoryAdvice, MemoryProtection, MlockAllFlags,
+    MlockFlags, MremapFlags, MsyncFlags, OffT,
+};
 
 /// Map files or devices into memory.
 /// Almost impossible to make safe, and the [linux documentation](https://man7.org/linux/man-pages/man2/mmap.2.html)
@@ -48,6 +51,160 @@ pub unsafe fn munmap(addr: usize, length: NonZeroUsize) -> crate::Result<()> {
     Ok(())
 }
 
+/// Change the protection of the pages in the range starting at `addr`, which has to be page-aligned.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mprotect.2.html)
+/// # Errors
+/// See above
+/// # Safety
+/// Removing access to memory that is still referenced will cause a segfault on access,
+/// and adding `PROT_EXEC` makes arbitrary bytes executable.
+#[inline]
+pub unsafe fn mprotect(
+    addr: usize,
+    length: NonZeroUsize,
+    memory_protection: MemoryProtection,
+) -> crate::Result<()> {
+    let res = syscall!(MPROTECT, addr, length.get(), memory_protection.bits());
+    bail_on_below_zero!(res, "`MPROTECT` syscall failed");
+    Ok(())
+}
+
+/// Give the kernel advice about how the memory starting at `addr` will be used,
+/// `addr` has to be page-aligned.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/madvise.2.html)
+/// # Errors
+/// See above
+/// # Safety
+/// Some advice, like [`MemoryAdvice::DontNeed`] and [`MemoryAdvice::Free`], changes the contents
+/// of the memory, which must not be referenced when that happens.
+#[inline]
+pub unsafe fn madvise(
+    addr: usize,
+    length: NonZeroUsize,
+    advice: MemoryAdvice,
+) -> crate::Result<()> {
+    let res = syscall!(MADVISE, addr, length.get(), advice.into_raw());
+    bail_on_below_zero!(res, "`MADVISE` syscall failed");
+    Ok(())
+}
+
+/// Expand or shrink an existing mapping, potentially moving it if `MREMAP_MAYMOVE` is specified.
+/// `new_addr` is only used with `MREMAP_FIXED`.
+/// Returns the address of the (possibly moved) mapping.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mremap.2.html)
+/// # Errors
+/// See above
+/// # Safety
+/// If the mapping moves or shrinks, any references into the old range are dangling.
+#[inline]
+pub unsafe fn mremap(
+    old_addr: usize,
+    old_size: NonZeroUsize,
+    new_size: NonZeroUsize,
+    flags: MremapFlags,
+    new_addr: Option<usize>,
+) -> crate::Result<usize> {
+    let res_ptr = syscall!(
+        MREMAP,
+        old_addr,
+        old_size.get(),
+        new_size.get(),
+        flags.bits(),
+        new_addr.unwrap_or_default()
+    );
+    bail_on_below_zero!(res_ptr, "`MREMAP` syscall failed");
+    Ok(res_ptr)
+}
+
+/// Flush changes made to a file-backed mapping starting at `addr` back to the file,
+/// `addr` has to be page-aligned.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/msync.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn msync(addr: usize, length: NonZeroUsize, flags: MsyncFlags) -> crate::Result<()> {
+    let res = unsafe { syscall!(MSYNC, addr, length.get(), flags.bits()) };
+    bail_on_below_zero!(res, "`MSYNC` syscall failed");
+    Ok(())
+}
+
+/// Lock the pages in the range starting at `addr` into RAM, preventing them from being swapped out.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mlock.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn mlock(addr: usize, length: NonZeroUsize) -> crate::Result<()> {
+    let res = unsafe { syscall!(MLOCK, addr, length.get()) };
+    bail_on_below_zero!(res, "`MLOCK` syscall failed");
+    Ok(())
+}
+
+/// Same as [`mlock`] but with flags, `MLOCK_ONFAULT` only locks pages once they're faulted in.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mlock.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn mlock2(addr: usize, length: NonZeroUsize, flags: MlockFlags) -> crate::Result<()> {
+    let res = unsafe { syscall!(MLOCK2, addr, length.get(), flags.bits()) };
+    bail_on_below_zero!(res, "`MLOCK2` syscall failed");
+    Ok(())
+}
+
+/// Unlock pages previously locked with [`mlock`], [`mlock2`], or [`mlockall`].
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mlock.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn munlock(addr: usize, length: NonZeroUsize) -> crate::Result<()> {
+    let res = unsafe { syscall!(MUNLOCK, addr, length.get()) };
+    bail_on_below_zero!(res, "`MUNLOCK` syscall failed");
+    Ok(())
+}
+
+/// Lock all pages mapped by this process, currently mapped and/or mapped in the future
+/// depending on `flags`.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mlock.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn mlockall(flags: MlockAllFlags) -> crate::Result<()> {
+    let res = unsafe { syscall!(MLOCKALL, flags.bits()) };
+    bail_on_below_zero!(res, "`MLOCKALL` syscall failed");
+    Ok(())
+}
+
+/// Unlock all pages mapped by this process.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mlock.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn munlockall() -> crate::Result<()> {
+    let res = unsafe { syscall!(MUNLOCKALL) };
+    bail_on_below_zero!(res, "`MUNLOCKALL` syscall failed");
+    Ok(())
+}
+
+/// Check which pages in the range starting at `addr` are resident in memory,
+/// the least significant bit of each byte in `vec` is set if the corresponding page is resident.
+/// `vec` needs one byte per page, since pages are never smaller than 4096 bytes, its length
+/// is checked against that.
+/// See the [linux documentation for details](https://man7.org/linux/man-pages/man2/mincore.2.html)
+/// # Errors
+/// `vec` is too short, or see above
+#[inline]
+pub fn mincore(addr: usize, length: NonZeroUsize, vec: &mut [u8]) -> crate::Result<()> {
+    if vec.len() < length.get().div_ceil(MIN_PAGE_SIZE) {
+        return Err(crate::Error::no_code(
+            "`mincore` vec too short, needs at least one byte per 4096 bytes of length",
+        ));
+    }
+    let res = unsafe { syscall!(MINCORE, addr, length.get(), vec.as_mut_ptr()) };
+    bail_on_below_zero!(res, "`MINCORE` syscall failed");
+    Ok(())
+}
+
+const MIN_PAGE_SIZE: usize = 4096;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +239,70 @@ mod tests {
             munmap(stack, sz).unwrap();
         }
     }
+
+    fn anon_page(size: usize) -> usize {
+        unsafe {
+            mmap(
+                None,
+                NonZeroUsize::new(size).unwrap(),
+                MemoryProtection::PROT_READ | MemoryProtection::PROT_WRITE,
+                MapRequiredFlag::MapPrivate,
+                MapAdditionalFlags::MAP_ANONYMOUS,
+                None,
+                0,
+            )
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn test_protect_advise_remap() {
+        unsafe {
+            let size = NonZeroUsize::new(4096).unwrap();
+            let addr = anon_page(size.get());
+            *(addr as *mut u8) = 5;
+            mprotect(addr, size, MemoryProtection::PROT_READ).unwrap();
+            assert_eq!(5, *(addr as *const u8));
+            mprotect(
+                addr,
+                size,
+                MemoryProtection::PROT_READ | MemoryProtection::PROT_WRITE,
+            )
+            .unwrap();
+            // Anonymous private pages read back as zero after `MADV_DONTNEED`
+            madvise(addr, size, MemoryAdvice::DontNeed).unwrap();
+            assert_eq!(0, *(addr as *const u8));
+            madvise(addr, size, MemoryAdvice::WillNeed).unwrap();
+            *(addr as *mut u8) = 7;
+            let new_size = NonZeroUsize::new(3 * 4096).unwrap();
+            let new_addr = mremap(addr, size, new_size, MremapFlags::MREMAP_MAYMOVE, None).unwrap();
+            assert_eq!(7, *(new_addr as *const u8));
+            *((new_addr + 2 * 4096) as *mut u8) = 9;
+            munmap(new_addr, new_size).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sync_lock_mincore() {
+        let size = NonZeroUsize::new(2 * 4096).unwrap();
+        let addr = anon_page(size.get());
+        msync(addr, size, MsyncFlags::MS_SYNC).unwrap();
+        let mut vec = [0u8; 2];
+        mincore(addr, size, &mut vec).unwrap();
+        // Nothing touched yet, so nothing is resident
+        assert_eq!([0, 0], vec);
+        unsafe { *(addr as *mut u8) = 1 };
+        mincore(addr, size, &mut vec).unwrap();
+        assert_eq!(1, vec[0] & 1);
+        assert!(mincore(addr, size, &mut [0u8; 1]).is_err());
+        // Locking can fail with `EPERM`/`ENOMEM` depending on `RLIMIT_MEMLOCK`, one page is
+        // always allowed under the default limit
+        let page = NonZeroUsize::new(4096).unwrap();
+        mlock(addr, page).unwrap();
+        munlock(addr, page).unwrap();
+        mlock2(addr, page, MlockFlags::MLOCK_ONFAULT).unwrap();
+        munlock(addr, page).unwrap();
+        munlockall().unwrap();
+        unsafe { munmap(addr, size).unwrap() };
+    }
 }
@@ -0,0 +1,59 @@
+use sc::syscall;
+
+use crate::platform::{Fd, OffT};
+
+/// Copy up to `count` bytes from `in_fd` to `out_fd` in-kernel.
+/// If `offset` is `Some`, reading starts there and the offset is updated to just past the
+/// last byte read, leaving the file offset of `in_fd` untouched, otherwise the file offset
+/// of `in_fd` is used and updated.
+/// `in_fd` has to support mmap-like operations, so it can't be a socket.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sendfile.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sendfile(
+    out_fd: Fd,
+    in_fd: Fd,
+    offset: Option<&mut OffT>,
+    count: usize,
+) -> crate::Result<usize> {
+    let res = unsafe {
+        syscall!(
+            SENDFILE,
+            out_fd.0,
+            in_fd.0,
+            offset.map_or(core::ptr::null_mut(), core::ptr::from_mut),
+            count
+        )
+    };
+    bail_on_below_zero!(res, "`SENDFILE` syscall failed");
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform::OpenFlags;
+    use crate::unistd::{close, open, pipe2, read, sendfile};
+
+    #[test]
+    fn sendfile_file_to_pipe() {
+        let file = open(unix_lit!("test-files/can_open.txt"), OpenFlags::O_RDONLY).unwrap();
+        let pipe = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        let mut offset = 1;
+        assert_eq!(
+            3,
+            sendfile(pipe.out_pipe, file, Some(&mut offset), 3).unwrap()
+        );
+        assert_eq!(4, offset);
+        let mut buf = [0u8; 8];
+        assert_eq!(3, read(pipe.in_pipe, &mut buf).unwrap());
+        assert_eq!(b"pen", &buf[..3]);
+        // Without an offset the file's own offset is used, which wasn't moved above
+        assert_eq!(2, sendfile(pipe.out_pipe, file, None, 2).unwrap());
+        assert_eq!(2, read(pipe.in_pipe, &mut buf).unwrap());
+        assert_eq!(b"op", &buf[..2]);
+        close(file).unwrap();
+        close(pipe.in_pipe).unwrap();
+        close(pipe.out_pipe).unwrap();
+    }
+}
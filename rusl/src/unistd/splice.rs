@@ -0,0 +1,152 @@
+use sc::syscall;
+
+use crate::platform::{Fd, IoSlice, OffT, SpliceFlags};
+
+/// Move up to `len` bytes between `fd_in` and `fd_out` in-kernel, one of them has to be a pipe.
+/// Offsets work like for [`sendfile`](crate::unistd::sendfile) and have to be `None` for the pipe end.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/splice.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn splice(
+    fd_in: Fd,
+    off_in: Option<&mut OffT>,
+    fd_out: Fd,
+    off_out: Option<&mut OffT>,
+    len: usize,
+    flags: SpliceFlags,
+) -> crate::Result<usize> {
+    let res = unsafe {
+        syscall!(
+            SPLICE,
+            fd_in.0,
+            off_in.map_or(core::ptr::null_mut(), core::ptr::from_mut),
+            fd_out.0,
+            off_out.map_or(core::ptr::null_mut(), core::ptr::from_mut),
+            len,
+            flags.bits()
+        )
+    };
+    bail_on_below_zero!(res, "`SPLICE` syscall failed");
+    Ok(res)
+}
+
+/// Duplicate up to `len` bytes from the pipe `fd_in` to the pipe `fd_out` without consuming them.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/tee.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn tee(fd_in: Fd, fd_out: Fd, len: usize, flags: SpliceFlags) -> crate::Result<usize> {
+    let res = unsafe { syscall!(TEE, fd_in.0, fd_out.0, len, flags.bits()) };
+    bail_on_below_zero!(res, "`TEE` syscall failed");
+    Ok(res)
+}
+
+/// Splice user memory into the pipe `fd`.
+/// The kernel may keep referencing the pages after returning, so changes to `iov` made before
+/// the data has been read from the pipe can show up on the other end.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/vmsplice.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn vmsplice(fd: Fd, iov: &[IoSlice], flags: SpliceFlags) -> crate::Result<usize> {
+    let res = unsafe { syscall!(VMSPLICE, fd.0, iov.as_ptr(), iov.len(), flags.bits()) };
+    bail_on_below_zero!(res, "`VMSPLICE` syscall failed");
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{IoSlice, OpenFlags, SpliceFlags};
+    use crate::unistd::{close, open, pipe2, read, splice, tee, vmsplice};
+
+    #[test]
+    fn splice_tee_vmsplice() {
+        let first = pipe2(OpenFlags::O_CLOEXEC | OpenFlags::O_NONBLOCK).unwrap();
+        let second = pipe2(OpenFlags::O_CLOEXEC | OpenFlags::O_NONBLOCK).unwrap();
+        let io = [IoSlice::new(b"Hello"), IoSlice::new(b" world")];
+        assert_eq!(
+            11,
+            vmsplice(first.out_pipe, &io, SpliceFlags::empty()).unwrap()
+        );
+        // Copied without being consumed
+        assert_eq!(
+            11,
+            tee(first.in_pipe, second.out_pipe, 64, SpliceFlags::empty()).unwrap()
+        );
+        let mut buf = [0u8; 16];
+        assert_eq!(11, read(second.in_pipe, &mut buf).unwrap());
+        assert_eq!(b"Hello world", &buf[..11]);
+        assert_eq!(
+            5,
+            splice(
+                first.in_pipe,
+                None,
+                second.out_pipe,
+                None,
+                5,
+                SpliceFlags::SPLICE_F_MOVE
+            )
+            .unwrap()
+        );
+        assert_eq!(5, read(second.in_pipe, &mut buf).unwrap());
+        assert_eq!(b"Hello", &buf[..5]);
+        assert_eq!(6, read(first.in_pipe, &mut buf).unwrap());
+        assert_eq!(b" world", &buf[..6]);
+        expect_errno!(
+            Errno::EAGAIN,
+            tee(
+                first.in_pipe,
+                second.out_pipe,
+                64,
+                SpliceFlags::SPLICE_F_NONBLOCK
+            )
+        );
+        for fd in [
+            first.in_pipe,
+            first.out_pipe,
+            second.in_pipe,
+            second.out_pipe,
+        ] {
+            close(fd).unwrap();
+        }
+    }
+
+    #[test]
+    fn splice_file_offset() {
+        let file = open(unix_lit!("test-files/can_open.txt"), OpenFlags::O_RDONLY).unwrap();
+        let pipe = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        let mut offset = 2;
+        let moved = splice(
+            file,
+            Some(&mut offset),
+            pipe.out_pipe,
+            None,
+            16,
+            SpliceFlags::empty(),
+        )
+        .unwrap();
+        assert_eq!(3, moved);
+        assert_eq!(5, offset);
+        let mut buf = [0u8; 8];
+        assert_eq!(3, read(pipe.in_pipe, &mut buf).unwrap());
+        assert_eq!(b"en\n", &buf[..3]);
+        // Can't specify an offset for the pipe end
+        let mut pipe_offset = 0;
+        expect_errno!(
+            Errno::ESPIPE,
+            splice(
+                file,
+                None,
+                pipe.out_pipe,
+                Some(&mut pipe_offset),
+                16,
+                SpliceFlags::empty()
+            )
+        );
+        close(file).unwrap();
+        close(pipe.in_pipe).unwrap();
+        close(pipe.out_pipe).unwrap();
+    }
+}
@@ -2,7 +2,7 @@ use core::mem::MaybeUninit;
 
 use sc::syscall;
 
-use crate::platform::{Fd, Stat, AT_FDCWD};
+use crate::platform::{Fd, Stat, Statx, StatxFlags, StatxMask, AT_FDCWD};
 use crate::string::unix_str::UnixStr;
 
 /// [stat](https://man7.org/linux/man-pages/man2/statx.2.html)
@@ -32,6 +32,36 @@ pub fn stat_fd(dir_fd: Fd) -> crate::Result<Stat> {
     do_statat(dir_fd.0, UnixStr::EMPTY)
 }
 
+/// [statx](https://man7.org/linux/man-pages/man2/statx.2.html)
+/// Gets extended file status at `path` relative to `dir_fd`, or the current directory if `None`.
+/// With `AT_EMPTY_PATH` and an empty `path`, gets the status of `dir_fd` itself.
+/// The kernel may fill in less than requested through `mask`, check [`Statx::mask`].
+/// # Errors
+/// See above docs
+#[inline]
+pub fn statx(
+    dir_fd: Option<Fd>,
+    path: &UnixStr,
+    flags: StatxFlags,
+    mask: StatxMask,
+) -> crate::Result<Statx> {
+    let mut statx = MaybeUninit::<Statx>::uninit();
+    let res = unsafe {
+        syscall!(
+            STATX,
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            path.as_ptr(),
+            flags.bits().0,
+            mask.bits(),
+            statx.as_mut_ptr()
+        )
+    };
+    bail_on_below_zero!(res, "`STATX` syscall failed");
+    // Safety:
+    // The kernel fills in the struct on success
+    Ok(unsafe { statx.assume_init() })
+}
+
 fn do_statat(fd: i32, pathname: &UnixStr) -> crate::Result<Stat> {
     let mut stat = MaybeUninit::uninit();
     let res = unsafe {
@@ -59,4 +89,47 @@ mod tests {
         stat(legit_path).unwrap();
         stat(UnixStr::EMPTY).unwrap();
     }
+
+    #[test]
+    #[expect(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+    #[cfg_attr(target_arch = "x86_64", expect(clippy::useless_conversion))]
+    fn statx_test() {
+        let legit_path = UnixStr::try_from_str("test-files/can_stat.txt\0").unwrap();
+        let old = stat(legit_path).unwrap();
+        let new = statx(
+            None,
+            legit_path,
+            StatxFlags::empty(),
+            StatxMask::STATX_BASIC_STATS,
+        )
+        .unwrap();
+        assert!(new.mask().contains(StatxMask::STATX_BASIC_STATS));
+        assert_eq!(old.st_size as u64, new.size());
+        assert_eq!(old.st_ino, new.ino());
+        assert_eq!(old.st_dev, new.dev());
+        assert_eq!(old.st_mode, new.mode().bits());
+        assert_eq!(u64::from(old.st_nlink), u64::from(new.nlink()));
+        assert_eq!(old.st_uid, new.uid());
+        assert_eq!(old.st_blocks as u64, new.blocks());
+        assert_eq!(old.st_mtime as i64, new.modified_time().seconds());
+        let fd = crate::unistd::open(legit_path, crate::platform::OpenFlags::O_RDONLY).unwrap();
+        let by_fd = statx(
+            Some(fd),
+            UnixStr::EMPTY,
+            StatxFlags::AT_EMPTY_PATH,
+            StatxMask::STATX_INO,
+        )
+        .unwrap();
+        assert_eq!(new.ino(), by_fd.ino());
+        crate::unistd::close(fd).unwrap();
+        expect_errno!(
+            crate::error::Errno::ENOENT,
+            statx(
+                None,
+                unix_lit!("test-files/does-not-exist"),
+                StatxFlags::empty(),
+                StatxMask::STATX_BASIC_STATS
+            )
+        );
+    }
 }
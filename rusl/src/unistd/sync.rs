@@ -0,0 +1,88 @@
+use sc::syscall;
+
+use crate::platform::{Fd, OffT, SyncFileRangeFlags};
+
+/// Flush all modified data and metadata of the file at `fd` to the storage device.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/fsync.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn fsync(fd: Fd) -> crate::Result<()> {
+    let res = unsafe { syscall!(FSYNC, fd.0) };
+    bail_on_below_zero!(res, "`FSYNC` syscall failed");
+    Ok(())
+}
+
+/// Like [`fsync`] but skips flushing metadata that isn't needed to read the data back,
+/// such as modification times.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/fsync.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn fdatasync(fd: Fd) -> crate::Result<()> {
+    let res = unsafe { syscall!(FDATASYNC, fd.0) };
+    bail_on_below_zero!(res, "`FDATASYNC` syscall failed");
+    Ok(())
+}
+
+/// Flush everything on the filesystem containing the file at `fd`.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sync.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn syncfs(fd: Fd) -> crate::Result<()> {
+    let res = unsafe { syscall!(SYNCFS, fd.0) };
+    bail_on_below_zero!(res, "`SYNCFS` syscall failed");
+    Ok(())
+}
+
+/// Start and/or wait for writeback of the range `offset..offset + nbytes` of the file at `fd`,
+/// an `nbytes` of zero means to the end of the file.
+/// This gives no guarantees about metadata, [`fsync`] or [`fdatasync`] is needed for durability.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/sync_file_range.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn sync_file_range(
+    fd: Fd,
+    offset: OffT,
+    nbytes: OffT,
+    flags: SyncFileRangeFlags,
+) -> crate::Result<()> {
+    let res = unsafe { syscall!(SYNC_FILE_RANGE, fd.0, offset, nbytes, flags.bits()) };
+    bail_on_below_zero!(res, "`SYNC_FILE_RANGE` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Errno;
+    use crate::platform::{OpenFlags, SyncFileRangeFlags};
+    use crate::unistd::{close, fdatasync, fsync, open, pipe2, sync_file_range, syncfs};
+
+    #[test]
+    fn sync_file() {
+        let fd = open(unix_lit!("test-files/can_open.txt"), OpenFlags::O_RDONLY).unwrap();
+        fsync(fd).unwrap();
+        fdatasync(fd).unwrap();
+        syncfs(fd).unwrap();
+        sync_file_range(
+            fd,
+            0,
+            0,
+            SyncFileRangeFlags::SYNC_FILE_RANGE_WAIT_BEFORE
+                | SyncFileRangeFlags::SYNC_FILE_RANGE_WRITE
+                | SyncFileRangeFlags::SYNC_FILE_RANGE_WAIT_AFTER,
+        )
+        .unwrap();
+        expect_errno!(
+            Errno::EINVAL,
+            sync_file_range(fd, -1, 0, SyncFileRangeFlags::SYNC_FILE_RANGE_WRITE)
+        );
+        close(fd).unwrap();
+        let pipe = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        expect_errno!(Errno::EINVAL, fsync(pipe.in_pipe));
+        close(pipe.in_pipe).unwrap();
+        close(pipe.out_pipe).unwrap();
+    }
+}
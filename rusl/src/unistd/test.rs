@@ -7,6 +7,16 @@ use crate::unistd::{
     close, fcntl_get_file_status, fcntl_set_file_status, open, open_mode, read, unlink, write,
 };
 
+/// The ids on the line starting with `prefix` in `/proc/self/status`
+pub(crate) fn proc_status_ids(prefix: &str) -> std::vec::Vec<u32> {
+    let status = std::fs::read_to_string("/proc/self/status").unwrap();
+    let line = status.lines().find(|l| l.starts_with(prefix)).unwrap();
+    line[prefix.len()..]
+        .split_whitespace()
+        .map(|id| id.parse().unwrap())
+        .collect()
+}
+
 #[test]
 fn no_write_on_read_only() {
     let fd = open(
@@ -0,0 +1,15 @@
+use sc::syscall;
+
+use crate::platform::{Fd, OffT};
+
+/// Truncate or extend the file referred to by `fd` to exactly `length` bytes,
+/// extended parts read as zeroes.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/ftruncate.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn ftruncate(fd: Fd, length: OffT) -> crate::Result<()> {
+    let res = unsafe { syscall!(FTRUNCATE, fd.0, length) };
+    bail_on_below_zero!(res, "`FTRUNCATE` syscall failed");
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use sc::syscall;
+
+use crate::platform::{DirFlags, Fd, TimeSpec, AT_FDCWD};
+use crate::string::unix_str::UnixStr;
+
+/// Change the access and modification times of the file at `path` relative to `dir_fd`,
+/// or the current directory if `dir_fd` is `None`.
+/// If `path` is `None`, the times of `dir_fd` itself are changed.
+/// `times` is `[access, modification]`, where [`TimeSpec::UTIME_NOW`] and [`TimeSpec::UTIME_OMIT`]
+/// can be used to set the current time or leave a time as is, `None` sets both to the current time.
+/// See the [Linux documentation for details](https://man7.org/linux/man-pages/man2/utimensat.2.html)
+/// # Errors
+/// See above
+#[inline]
+pub fn utimensat(
+    dir_fd: Option<Fd>,
+    path: Option<&UnixStr>,
+    times: Option<&[TimeSpec; 2]>,
+    flags: DirFlags,
+) -> crate::Result<()> {
+    let res = unsafe {
+        syscall!(
+            UTIMENSAT,
+            dir_fd.map_or(AT_FDCWD, |fd| fd.0),
+            path.map_or(core::ptr::null(), UnixStr::as_ptr),
+            times.map_or(core::ptr::null(), |t| t.as_ptr()),
+            flags.bits().0
+        )
+    };
+    bail_on_below_zero!(res, "`UTIMENSAT` syscall failed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform::{DirFlags, Mode, OpenFlags, TimeSpec};
+    use crate::unistd::{close, open_mode, stat, unlink, utimensat};
+
+    #[test]
+    #[expect(clippy::cast_possible_wrap)]
+    fn set_times() {
+        let path = unix_lit!("test-files/unistd/utimensat_test");
+        let _ = unlink(path);
+        let fd = open_mode(
+            path,
+            OpenFlags::O_CREAT | OpenFlags::O_WRONLY | OpenFlags::O_CLOEXEC,
+            Mode::S_IRUSR | Mode::S_IWUSR,
+        )
+        .unwrap();
+        let times = [TimeSpec::new(1_000, 5), TimeSpec::new(2_000, 7)];
+        utimensat(None, Some(path), Some(&times), DirFlags::empty()).unwrap();
+        let st = stat(path).unwrap();
+        assert_eq!(1_000, st.st_atime as i64);
+        assert_eq!(5, st.st_atime_nsec as i64);
+        assert_eq!(2_000, st.st_mtime as i64);
+        assert_eq!(7, st.st_mtime_nsec as i64);
+        // Only touch the access time through the fd
+        let times = [TimeSpec::new(3_000, 0), TimeSpec::UTIME_OMIT];
+        utimensat(Some(fd), None, Some(&times), DirFlags::empty()).unwrap();
+        let st = stat(path).unwrap();
+        assert_eq!(3_000, st.st_atime as i64);
+        assert_eq!(2_000, st.st_mtime as i64);
+        utimensat(Some(fd), None, None, DirFlags::empty()).unwrap();
+        assert!(stat(path).unwrap().st_mtime as i64 > 2_000);
+        close(fd).unwrap();
+        unlink(path).unwrap();
+    }
+}
@@ -2176,16 +2176,6 @@ mod tests {
     }
 
     fn random_gen_range(prng: &mut Prng, low: usize, high: usize) -> usize {
-        let lf = low as f64;
-        let hf = high as f64;
-        let res = if low == 0 {
-            let transform = hf / u64::MAX as f64;
-            (prng.next_u64() as f64 * transform) as usize
-        } else {
-            let space = hf - lf;
-            let transform = space / u64::MAX as f64;
-            (prng.next_u64() as f64 * transform + space) as usize
-        };
-        res
+        prng.gen_range(low as u64..high as u64) as usize
     }
 }
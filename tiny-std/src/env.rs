@@ -84,6 +84,25 @@ pub fn var(key: &str) -> Result<&'static str, VarError> {
     Err(VarError::Missing)
 }
 
+/// Get the current working directory of this process
+/// # Errors
+/// Os errors getting the directory, such as it having been removed
+#[cfg(feature = "alloc")]
+pub fn current_dir() -> Result<rusl::string::unix_str::UnixString, Error> {
+    Ok(rusl::string::unix_str::UnixString::try_from_vec(
+        crate::fs::current_dir_bytes()?,
+    )?)
+}
+
+/// Change the current working directory of this process to `path`
+/// # Errors
+/// Os errors relating to directory access, such as `path` not being a directory
+#[inline]
+pub fn set_current_dir(path: &UnixStr) -> Result<(), Error> {
+    rusl::unistd::chdir(path)?;
+    Ok(())
+}
+
 #[inline]
 #[must_use]
 pub fn args() -> Args {
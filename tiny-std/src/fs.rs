@@ -6,18 +6,31 @@ use core::mem::MaybeUninit;
 
 use rusl::error::Errno;
 pub use rusl::platform::Mode;
-use rusl::platform::{Dirent, OpenFlags, Stat, NULL_BYTE};
+use rusl::platform::{
+    DirFlags, Dirent, FallocateFlags, LinkFlags, OpenFlags, Statx, StatxFlags, StatxMask, TimeSpec,
+    NULL_BYTE,
+};
 use rusl::string::strlen::{buf_strlen, strlen};
 use rusl::string::unix_str::UnixStr;
+#[cfg(feature = "alloc")]
+use rusl::string::unix_str::UnixString;
 use rusl::unistd::UnlinkFlags;
 
 use crate::error::Error;
 use crate::error::Result;
 use crate::io::{Read, Write};
+use crate::time::SystemTime;
 use crate::unix::fd::{AsRawFd, BorrowedFd, OwnedFd, RawFd};
 
+mod mem_file;
 #[cfg(test)]
 mod test;
+#[cfg(feature = "alloc")]
+mod watcher;
+
+pub use mem_file::{MemFile, MemMap};
+#[cfg(feature = "alloc")]
+pub use watcher::{WatchEvent, Watcher};
 
 pub struct File(OwnedFd);
 
@@ -71,8 +84,75 @@ impl File {
     /// Os errors making the stat-syscall
     #[inline]
     pub fn metadata(&self) -> Result<Metadata> {
-        let stat = rusl::unistd::stat_fd(self.as_raw_fd())?;
-        Ok(Metadata(stat))
+        Metadata::statx(
+            Some(self.as_raw_fd()),
+            UnixStr::EMPTY,
+            StatxFlags::AT_EMPTY_PATH,
+        )
+    }
+
+    /// Flush all data and metadata of this `File` to disk
+    /// # Errors
+    /// Os errors making the fsync-syscall
+    #[inline]
+    pub fn sync_all(&self) -> Result<()> {
+        rusl::unistd::fsync(self.as_raw_fd())?;
+        Ok(())
+    }
+
+    /// Flush the data of this `File` to disk, skipping metadata
+    /// not needed to read it back, such as timestamps
+    /// # Errors
+    /// Os errors making the fdatasync-syscall
+    #[inline]
+    pub fn sync_data(&self) -> Result<()> {
+        rusl::unistd::fdatasync(self.as_raw_fd())?;
+        Ok(())
+    }
+
+    /// Truncate or extend this `File` to `size` bytes, extended parts read as zeroes.
+    /// The file offset is left unchanged.
+    /// # Errors
+    /// `size` doesn't fit in an `i64`, or the `File` isn't opened for writing
+    pub fn set_len(&self, size: u64) -> Result<()> {
+        let size = i64::try_from(size).map_err(|_| Error::no_code("File size exceeds i64::MAX"))?;
+        rusl::unistd::ftruncate(self.as_raw_fd(), size)?;
+        Ok(())
+    }
+
+    /// Allocate disk space for the range `offset..offset + len`, extending the file if needed,
+    /// so that later writes within it can't fail for lack of space
+    /// # Errors
+    /// The range doesn't fit in an `i64`, or the filesystem doesn't support allocation
+    pub fn allocate(&self, offset: u64, len: u64) -> Result<()> {
+        let offset =
+            i64::try_from(offset).map_err(|_| Error::no_code("Offset exceeds i64::MAX"))?;
+        let len = i64::try_from(len).map_err(|_| Error::no_code("Length exceeds i64::MAX"))?;
+        rusl::unistd::fallocate(self.as_raw_fd(), FallocateFlags::empty(), offset, len)?;
+        Ok(())
+    }
+
+    /// Set the permissions of this `File`
+    /// # Errors
+    /// Os errors making the fchmod-syscall, such as not owning the file
+    #[inline]
+    pub fn set_permissions(&self, perm: Permissions) -> Result<()> {
+        rusl::unistd::fchmod(self.as_raw_fd(), perm.0)?;
+        Ok(())
+    }
+
+    /// Set the access and/or modification times of this `File`
+    /// # Errors
+    /// Os errors making the utimensat-syscall, such as not owning the file
+    #[inline]
+    pub fn set_times(&self, times: FileTimes) -> Result<()> {
+        rusl::unistd::utimensat(
+            Some(self.as_raw_fd()),
+            None,
+            Some(&times.as_timespecs()),
+            DirFlags::empty(),
+        )?;
+        Ok(())
     }
 
     /// Copies `src` to `dest`, can be used to move files.
@@ -80,7 +160,7 @@ impl File {
     /// Returns a handle to the new file.
     /// # Errors
     /// Os errors relating to file access
-    #[expect(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    #[expect(clippy::cast_possible_truncation)]
     pub fn copy(&self, dest: &UnixStr) -> Result<Self> {
         let this_metadata = self.metadata()?;
         let dest = OpenOptions::new()
@@ -89,8 +169,7 @@ impl File {
             .mode(this_metadata.mode())
             .open(dest)?;
         let mut offset = 0;
-        // We don't have to care about sign loss on the st_size, it's always positive.
-        let mut remaining = this_metadata.0.st_size as u64 - offset;
+        let mut remaining = this_metadata.len() - offset;
         while remaining > 0 {
             let w = rusl::unistd::copy_file_range(
                 self.as_raw_fd(),
@@ -103,7 +182,7 @@ impl File {
                 return Ok(dest);
             }
             offset += w as u64;
-            remaining = this_metadata.0.st_size as u64 - offset;
+            remaining = this_metadata.len() - offset;
         }
         Ok(dest)
     }
@@ -168,48 +247,270 @@ pub fn write(path: &UnixStr, buf: &[u8]) -> Result<()> {
     Ok(())
 }
 #[derive(Debug, Clone)]
-pub struct Metadata(Stat);
+pub struct Metadata(Statx);
 
 impl Metadata {
+    fn statx(dir_fd: Option<RawFd>, path: &UnixStr, flags: StatxFlags) -> Result<Self> {
+        // Like `stat`, an empty path refers to `dir_fd`, or the current directory
+        let statx = rusl::unistd::statx(
+            dir_fd,
+            path,
+            flags | StatxFlags::AT_EMPTY_PATH,
+            StatxMask::STATX_BASIC_STATS | StatxMask::STATX_BTIME,
+        )?;
+        Ok(Self(statx))
+    }
+
     #[inline]
     #[must_use]
     pub fn is_dir(&self) -> bool {
-        Mode::from(self.0.st_mode) & Mode::S_IFMT == Mode::S_IFDIR
+        self.0.mode() & Mode::S_IFMT == Mode::S_IFDIR
     }
 
     #[inline]
     #[must_use]
     pub fn is_file(&self) -> bool {
-        Mode::from(self.0.st_mode) & Mode::S_IFMT == Mode::S_IFREG
+        self.0.mode() & Mode::S_IFMT == Mode::S_IFREG
     }
 
     #[inline]
     #[must_use]
     pub fn is_symlink(&self) -> bool {
-        Mode::from(self.0.st_mode) & Mode::S_IFMT == Mode::S_IFLNK
+        self.0.mode() & Mode::S_IFMT == Mode::S_IFLNK
     }
 
     #[inline]
     #[must_use]
     pub fn mode(&self) -> Mode {
-        Mode::from(self.0.st_mode)
+        self.0.mode()
     }
 
     #[inline]
     #[must_use]
-    #[expect(clippy::len_without_is_empty, clippy::cast_sign_loss)]
+    pub fn permissions(&self) -> Permissions {
+        Permissions::from(self.0.mode())
+    }
+
+    #[inline]
+    #[must_use]
+    #[expect(clippy::len_without_is_empty)]
     pub fn len(&self) -> u64 {
-        self.0.st_size as u64
+        self.0.size()
+    }
+
+    /// Time of last access
+    #[inline]
+    #[must_use]
+    pub fn accessed(&self) -> SystemTime {
+        SystemTime::from(self.0.access_time())
+    }
+
+    /// Time of last content modification
+    #[inline]
+    #[must_use]
+    pub fn modified(&self) -> SystemTime {
+        SystemTime::from(self.0.modified_time())
+    }
+
+    /// Time of last status change, such as permissions or ownership
+    #[inline]
+    #[must_use]
+    pub fn changed(&self) -> SystemTime {
+        SystemTime::from(self.0.changed_time())
+    }
+
+    /// Time of creation, `None` if the filesystem doesn't record it
+    #[inline]
+    #[must_use]
+    pub fn created(&self) -> Option<SystemTime> {
+        self.0
+            .mask()
+            .contains(StatxMask::STATX_BTIME)
+            .then(|| SystemTime::from(self.0.birth_time()))
+    }
+
+    /// User id of the owner
+    #[inline]
+    #[must_use]
+    pub fn uid(&self) -> u32 {
+        self.0.uid()
+    }
+
+    /// Group id of the owner
+    #[inline]
+    #[must_use]
+    pub fn gid(&self) -> u32 {
+        self.0.gid()
+    }
+
+    /// Inode number, together with [`Metadata::dev`] it uniquely identifies a file
+    #[inline]
+    #[must_use]
+    pub fn ino(&self) -> u64 {
+        self.0.ino()
+    }
+
+    /// Id of the device containing the file
+    #[inline]
+    #[must_use]
+    pub fn dev(&self) -> u64 {
+        self.0.dev()
+    }
+
+    /// Number of hard links to the file
+    #[inline]
+    #[must_use]
+    pub fn nlink(&self) -> u64 {
+        u64::from(self.0.nlink())
+    }
+
+    /// Number of 512-byte blocks allocated to the file
+    #[inline]
+    #[must_use]
+    pub fn blocks(&self) -> u64 {
+        self.0.blocks()
+    }
+
+    /// Preferred block size for I/O
+    #[inline]
+    #[must_use]
+    pub fn blksize(&self) -> u64 {
+        u64::from(self.0.blksize())
     }
 }
 
-/// Reads metadata at path
+/// Reads metadata at path, following symlinks
 /// # Errors
 /// Os errors relating to file access
 #[inline]
 pub fn metadata(path: &UnixStr) -> Result<Metadata> {
-    let res = rusl::unistd::stat(path)?;
-    Ok(Metadata(res))
+    Metadata::statx(None, path, StatxFlags::empty())
+}
+
+/// Reads metadata at path without following symlinks, if `path` is a symlink
+/// the metadata is for the link itself
+/// # Errors
+/// Os errors relating to file access
+#[inline]
+pub fn symlink_metadata(path: &UnixStr) -> Result<Metadata> {
+    Metadata::statx(None, path, StatxFlags::AT_SYMLINK_NOFOLLOW)
+}
+
+/// Permission bits of a file, the lower 12 bits of its [`Mode`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Permissions(Mode);
+
+impl Permissions {
+    const MASK: u32 = 0o7777;
+    const WRITE: u32 = 0o222;
+
+    #[inline]
+    #[must_use]
+    pub fn from_mode(mode: u32) -> Self {
+        Self(Mode::from(mode & Self::MASK))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> u32 {
+        self.0.bits()
+    }
+
+    /// No one has write permissions
+    #[inline]
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        self.0.bits() & Self::WRITE == 0
+    }
+
+    /// Removes write permissions for everyone if `readonly`, otherwise
+    /// adds write permissions for everyone
+    #[inline]
+    pub fn set_readonly(&mut self, readonly: bool) {
+        let bits = if readonly {
+            self.0.bits() & !Self::WRITE
+        } else {
+            self.0.bits() | Self::WRITE
+        };
+        self.0 = Mode::from(bits);
+    }
+}
+
+impl From<Mode> for Permissions {
+    #[inline]
+    fn from(value: Mode) -> Self {
+        Self::from_mode(value.bits())
+    }
+}
+
+/// Set the permissions of the file at `path`, following symlinks
+/// # Errors
+/// Os errors relating to file access, such as not owning the file
+#[inline]
+pub fn set_permissions(path: &UnixStr, perm: Permissions) -> Result<()> {
+    rusl::unistd::fchmodat(None, path, perm.0)?;
+    Ok(())
+}
+
+/// Change the owner and/or group of the file at `path`, following symlinks.
+/// `None` leaves that id unchanged.
+/// # Errors
+/// Os errors relating to file access, or lacking the privileges to change ownership
+#[inline]
+pub fn chown(path: &UnixStr, uid: Option<u32>, gid: Option<u32>) -> Result<()> {
+    rusl::unistd::fchownat(None, path, uid, gid, DirFlags::empty())?;
+    Ok(())
+}
+
+/// Timestamps to set with [`File::set_times`] or [`set_times`],
+/// timestamps that aren't set are left unchanged
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
+impl FileTimes {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn set_accessed(mut self, t: SystemTime) -> Self {
+        self.accessed = Some(t);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn set_modified(mut self, t: SystemTime) -> Self {
+        self.modified = Some(t);
+        self
+    }
+
+    fn as_timespecs(self) -> [TimeSpec; 2] {
+        [
+            self.accessed.map_or(TimeSpec::UTIME_OMIT, TimeSpec::from),
+            self.modified.map_or(TimeSpec::UTIME_OMIT, TimeSpec::from),
+        ]
+    }
+}
+
+/// Set the access and/or modification times of the file at `path`, following symlinks
+/// # Errors
+/// Os errors relating to file access, such as not owning the file
+#[inline]
+pub fn set_times(path: &UnixStr, times: FileTimes) -> Result<()> {
+    rusl::unistd::utimensat(
+        None,
+        Some(path),
+        Some(&times.as_timespecs()),
+        DirFlags::empty(),
+    )?;
+    Ok(())
 }
 
 /// Renames `src` to `dest`, can be used to move files or directories.
@@ -232,6 +533,136 @@ pub fn copy_file(src: &UnixStr, dest: &UnixStr) -> Result<File> {
     src_file.copy(dest)
 }
 
+/// Creates a symlink at `link` pointing to `target`, `target` is stored as is
+/// and doesn't have to exist
+/// # Errors
+/// Os errors relating to file access, or something already existing at `link`
+#[inline]
+pub fn symlink(target: &UnixStr, link: &UnixStr) -> Result<()> {
+    rusl::unistd::symlinkat(target, None, link)?;
+    Ok(())
+}
+
+/// Creates a hard link at `link` to the file at `original`, if `original` is a symlink
+/// the link points to the symlink itself
+/// # Errors
+/// Os errors relating to file access, or something already existing at `link`
+#[inline]
+pub fn hard_link(original: &UnixStr, link: &UnixStr) -> Result<()> {
+    rusl::unistd::linkat(None, original, None, link, LinkFlags::empty())?;
+    Ok(())
+}
+
+/// Reads the target of the symlink at `path`
+/// # Errors
+/// Os errors relating to file access, or `path` not being a symlink
+#[cfg(feature = "alloc")]
+pub fn read_link(path: &UnixStr) -> Result<UnixString> {
+    let target = read_link_bytes(path)?;
+    Ok(UnixString::try_from_vec(target)?)
+}
+
+#[cfg(feature = "alloc")]
+fn read_link_bytes(path: &UnixStr) -> Result<Vec<u8>> {
+    let mut buf = alloc::vec![0u8; 256];
+    loop {
+        let len = rusl::unistd::readlinkat(None, path, &mut buf)?;
+        // A full buffer may have been truncated
+        if len < buf.len() {
+            buf.truncate(len);
+            return Ok(buf);
+        }
+        buf.resize(buf.len() * 2, 0);
+    }
+}
+
+/// Returns the absolute form of `path` with all `.` and `..` components and symlinks
+/// resolved. Relative paths are resolved from the current directory.
+/// # Errors
+/// Os errors relating to file access, some part of `path` not existing,
+/// or too many levels of symlinks
+#[cfg(feature = "alloc")]
+pub fn canonicalize(path: &UnixStr) -> Result<UnixString> {
+    // Same limit as the kernel's `MAXSYMLINKS`
+    const MAX_LINKS: usize = 40;
+    let path = &path.as_slice()[..path.len() - 1];
+    if path.is_empty() {
+        return Err(Error::os("Can't canonicalize an empty path", Errno::ENOENT));
+    }
+    // Absolute and without a trailing slash, empty meaning `/`
+    let mut resolved = Vec::new();
+    if path[0] != b'/' {
+        resolved = current_dir_bytes()?;
+        if resolved == b"/" {
+            resolved.clear();
+        }
+    }
+    // Components left to resolve, in reverse order
+    let mut pending: Vec<Vec<u8>> = path
+        .split(|b| *b == b'/')
+        .rev()
+        .map(<[u8]>::to_vec)
+        .collect();
+    let mut links_followed = 0;
+    while let Some(component) = pending.pop() {
+        match component.as_slice() {
+            b"" | b"." => {}
+            b".." => {
+                let parent_len = resolved.iter().rposition(|b| *b == b'/').unwrap_or(0);
+                resolved.truncate(parent_len);
+            }
+            name => {
+                let parent_len = resolved.len();
+                resolved.push(b'/');
+                resolved.extend_from_slice(name);
+                resolved.push(NULL_BYTE);
+                let link = read_link_bytes(unsafe { UnixStr::from_bytes_unchecked(&resolved) });
+                resolved.pop();
+                match link {
+                    Ok(target) => {
+                        links_followed += 1;
+                        if links_followed > MAX_LINKS {
+                            return Err(Error::os(
+                                "Too many levels of symlinks while canonicalizing",
+                                Errno::ELOOP,
+                            ));
+                        }
+                        if target.first() == Some(&b'/') {
+                            resolved.clear();
+                        } else {
+                            resolved.truncate(parent_len);
+                        }
+                        pending.extend(target.split(|b| *b == b'/').rev().map(<[u8]>::to_vec));
+                    }
+                    // Not a symlink
+                    Err(e) if e.matches_errno(Errno::EINVAL) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+    }
+    if resolved.is_empty() {
+        resolved.push(b'/');
+    }
+    Ok(UnixString::try_from_vec(resolved)?)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn current_dir_bytes() -> Result<Vec<u8>> {
+    let mut buf = alloc::vec![0u8; 256];
+    loop {
+        match rusl::unistd::getcwd(&mut buf) {
+            Ok(len) => {
+                // Drop the null byte
+                buf.truncate(len - 1);
+                return Ok(buf);
+            }
+            Err(e) if e.code == Some(Errno::ERANGE) => buf.resize(buf.len() * 2, 0),
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 /// Checks if anything exists at the provided path.
 /// Will false-negative if the path is empty.
 /// # Errors
@@ -0,0 +1,191 @@
+use core::num::NonZeroUsize;
+use core::ops::Deref;
+
+use rusl::platform::{
+    MapAdditionalFlags, MapRequiredFlag, MemfdFlags, MemoryProtection, OffT, SealFlags,
+};
+use rusl::string::unix_str::UnixStr;
+
+use crate::error::{Error, Result};
+use crate::io::{Read, Write};
+use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
+
+/// An anonymous file that lives in memory, backed by `memfd_create`.
+/// It can be sized, mapped, sealed and sent to other processes over a
+/// [`UnixStream`](crate::net::UnixStream), making it suitable for shared buffers.
+#[derive(Debug)]
+pub struct MemFile(OwnedFd);
+
+impl MemFile {
+    /// Create a new empty `MemFile`, `name` only shows up in `/proc/self/fd/` and has no
+    /// other meaning.
+    /// If `allow_sealing` is false the file is created with `F_SEAL_SEAL` set, and
+    /// can't be sealed later.
+    /// # Errors
+    /// Os errors creating the file
+    pub fn create(name: &UnixStr, allow_sealing: bool) -> Result<Self> {
+        let mut flags = MemfdFlags::MFD_CLOEXEC;
+        if allow_sealing {
+            flags |= MemfdFlags::MFD_ALLOW_SEALING;
+        }
+        let fd = rusl::unistd::memfd_create(name, flags)?;
+        Ok(Self(OwnedFd(fd)))
+    }
+
+    /// Create a `MemFile` from a raw `fd`, for example one received over a
+    /// [`UnixStream`](crate::net::UnixStream)
+    /// # Safety
+    /// The fd is valid, refers to a memfd, and is not duplicated, see `File::from_raw_fd`
+    #[must_use]
+    pub const unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Self(OwnedFd::from_raw(fd))
+    }
+
+    /// Consume this `MemFile` returning the `fd` without closing it
+    #[must_use]
+    pub fn into_raw_fd(self) -> RawFd {
+        let fd = self.0 .0;
+        core::mem::forget(self);
+        fd
+    }
+
+    /// Get the current size of this `MemFile` in bytes
+    /// # Errors
+    /// Os errors making the stat-syscall
+    pub fn len(&self) -> Result<u64> {
+        let stat = rusl::unistd::stat_fd(self.0 .0)?;
+        // Sizes are never negative
+        #[expect(clippy::cast_sign_loss)]
+        Ok(stat.st_size as u64)
+    }
+
+    /// Check if this `MemFile` is empty
+    /// # Errors
+    /// Os errors making the stat-syscall
+    #[inline]
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Truncate or extend this `MemFile` to `size` bytes, extended parts read as zeroes
+    /// # Errors
+    /// `size` doesn't fit in an `i64`, or the file is sealed against growing or shrinking
+    pub fn set_len(&self, size: u64) -> Result<()> {
+        let size =
+            OffT::try_from(size).map_err(|_| Error::no_code("MemFile size exceeds i64::MAX"))?;
+        rusl::unistd::ftruncate(self.0 .0, size)?;
+        Ok(())
+    }
+
+    /// Add seals to this `MemFile`, seals can't be removed once added
+    /// # Errors
+    /// The file was created without `allow_sealing` or is sealed with `F_SEAL_SEAL`,
+    /// or `F_SEAL_WRITE` was requested while a writable mapping exists
+    #[inline]
+    pub fn add_seals(&self, seals: SealFlags) -> Result<()> {
+        rusl::unistd::fcntl_add_seals(self.0 .0, seals)?;
+        Ok(())
+    }
+
+    /// Get the seals currently placed on this `MemFile`
+    /// # Errors
+    /// Os errors making the fcntl-syscall
+    #[inline]
+    pub fn seals(&self) -> Result<SealFlags> {
+        Ok(rusl::unistd::fcntl_get_seals(self.0 .0)?)
+    }
+
+    /// Map the whole `MemFile` into memory as a shared mapping, writes through a writable
+    /// mapping are visible to every process that has the file mapped.
+    /// # Errors
+    /// The file is empty, or `writable` is requested on a file sealed with `F_SEAL_WRITE`
+    /// # Safety
+    /// The file must not be shrunk while the mapping is alive, and the returned slices
+    /// may change under the caller if other writers exist. Sealing with `F_SEAL_SHRINK`
+    /// and `F_SEAL_WRITE` rules out both.
+    pub unsafe fn map(&self, writable: bool) -> Result<MemMap> {
+        let len = usize::try_from(self.len()?)
+            .ok()
+            .and_then(NonZeroUsize::new)
+            .ok_or_else(|| Error::no_code("Can't map an empty MemFile"))?;
+        let prot = if writable {
+            MemoryProtection::PROT_READ | MemoryProtection::PROT_WRITE
+        } else {
+            MemoryProtection::PROT_READ
+        };
+        let addr = rusl::unistd::mmap(
+            None,
+            len,
+            prot,
+            MapRequiredFlag::MapShared,
+            MapAdditionalFlags::empty(),
+            Some(self.0 .0),
+            0,
+        )?;
+        Ok(MemMap {
+            addr,
+            len,
+            writable,
+        })
+    }
+}
+
+impl AsRawFd for MemFile {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0 .0
+    }
+}
+
+impl Read for MemFile {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        Ok(rusl::unistd::read(self.0 .0, buf)?)
+    }
+}
+
+impl Write for MemFile {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        Ok(rusl::unistd::write(self.0 .0, buf)?)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A shared mapping of a [`MemFile`], unmapped on drop
+#[derive(Debug)]
+pub struct MemMap {
+    addr: usize,
+    len: NonZeroUsize,
+    writable: bool,
+}
+
+impl MemMap {
+    /// Get the mapped memory mutably, `None` if the mapping was created read-only
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> Option<&mut [u8]> {
+        self.writable.then(|| unsafe {
+            core::slice::from_raw_parts_mut(self.addr as *mut u8, self.len.get())
+        })
+    }
+}
+
+impl Deref for MemMap {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::slice::from_raw_parts(self.addr as *const u8, self.len.get()) }
+    }
+}
+
+impl Drop for MemMap {
+    fn drop(&mut self) {
+        // Best attempt, the mapping is owned by this struct and nothing borrows it anymore
+        let _ = unsafe { rusl::unistd::munmap(self.addr, self.len) };
+    }
+}
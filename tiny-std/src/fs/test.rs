@@ -5,6 +5,8 @@ use alloc::vec::Vec;
 
 use rusl::error::Errno;
 use rusl::string::unix_str::UnixStr;
+#[cfg(feature = "alloc")]
+use rusl::string::unix_str::UnixString;
 
 use crate::fs::{metadata, File, FileType, OpenOptions};
 use crate::io::{Read, Write};
@@ -364,3 +366,276 @@ fn rw_copy_exact(src: &UnixStr, buf: &mut [u8], dst: &UnixStr) -> File {
     file.write_all(buf).unwrap();
     file
 }
+
+#[test]
+fn mem_file_read_write_map() {
+    use crate::fs::MemFile;
+    use crate::unix::fd::AsRawFd;
+    let mut mf =
+        MemFile::create(UnixStr::try_from_str("tiny-std-memfile\0").unwrap(), false).unwrap();
+    assert!(mf.is_empty().unwrap());
+    assert!(unsafe { mf.map(false) }.is_err());
+    mf.write_all(b"Hello").unwrap();
+    assert_eq!(5, mf.len().unwrap());
+    mf.set_len(4096).unwrap();
+    assert_eq!(4096, mf.len().unwrap());
+    let mut map = unsafe { mf.map(true) }.unwrap();
+    assert_eq!(4096, map.len());
+    assert_eq!(b"Hello", &map[..5]);
+    map.as_mut_slice().unwrap()[..5].copy_from_slice(b"World");
+    drop(map);
+    rusl::unistd::lseek(mf.as_raw_fd(), 0, rusl::unistd::Whence::SET).unwrap();
+    let mut buf = [0u8; 5];
+    mf.read_exact(&mut buf).unwrap();
+    assert_eq!(b"World", &buf);
+    let mut read_only = unsafe { mf.map(false) }.unwrap();
+    assert!(read_only.as_mut_slice().is_none());
+    // Created without sealing, so it's sealed against further seals
+    assert!(mf
+        .seals()
+        .unwrap()
+        .contains(rusl::platform::SealFlags::F_SEAL_SEAL));
+}
+
+#[test]
+fn mem_file_seal() {
+    use crate::fs::MemFile;
+    use rusl::platform::SealFlags;
+    let mut mf = MemFile::create(
+        UnixStr::try_from_str("tiny-std-memfile-seal\0").unwrap(),
+        true,
+    )
+    .unwrap();
+    mf.set_len(16).unwrap();
+    let map = unsafe { mf.map(true) }.unwrap();
+    // Can't write-seal while there's a writable mapping
+    assert!(mf
+        .add_seals(SealFlags::F_SEAL_WRITE)
+        .unwrap_err()
+        .matches_errno(Errno::EBUSY));
+    drop(map);
+    mf.add_seals(SealFlags::F_SEAL_WRITE | SealFlags::F_SEAL_SHRINK | SealFlags::F_SEAL_GROW)
+        .unwrap();
+    assert!(mf.write(b"Hi").unwrap_err().matches_errno(Errno::EPERM));
+    assert!(mf.set_len(32).unwrap_err().matches_errno(Errno::EPERM));
+    assert!(unsafe { mf.map(true) }.is_err());
+    assert_eq!(&[0u8; 16], &*unsafe { mf.map(false) }.unwrap());
+}
+
+#[test]
+fn metadata_identity_and_times() {
+    let path = UnixStr::try_from_str("test-files/fs/test_metadata_times.txt\0").unwrap();
+    let _ = crate::fs::remove_file(path);
+    let before = crate::time::SystemTime::now();
+    crate::fs::write(path, b"Hello").unwrap();
+    let md = metadata(path).unwrap();
+    assert!(md.is_file());
+    assert_eq!(5, md.len());
+    assert_eq!(1, md.nlink());
+    assert_eq!(rusl::unistd::get_uid().unwrap(), md.uid());
+    // Coarse filesystem timestamps can lag a little behind the realtime clock
+    let slack = core::time::Duration::from_secs(1);
+    assert!((md.modified() + slack)
+        .unwrap()
+        .duration_since(before)
+        .is_some());
+    assert!(md.modified().elapsed().is_some());
+    assert!(md
+        .changed()
+        .duration_since((before - slack).unwrap())
+        .is_some());
+    if let Some(created) = md.created() {
+        assert!(created.duration_since((before - slack).unwrap()).is_some());
+    }
+    let file = File::open(path).unwrap();
+    let fd_md = file.metadata().unwrap();
+    assert_eq!(md.ino(), fd_md.ino());
+    assert_eq!(md.dev(), fd_md.dev());
+    let other = metadata(UnixStr::try_from_str("test-files/fs/test1.txt\0").unwrap()).unwrap();
+    assert_ne!(md.ino(), other.ino());
+    crate::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn symlink_metadata_does_not_follow() {
+    let path = UnixStr::try_from_str("/proc/self\0").unwrap();
+    let link = crate::fs::symlink_metadata(path).unwrap();
+    assert!(link.is_symlink());
+    let target = metadata(path).unwrap();
+    assert!(target.is_dir());
+    assert_ne!(link.ino(), target.ino());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn file_sync_and_resize() {
+    let path = UnixStr::try_from_str("test-files/fs/test_sync_resize.txt\0").unwrap();
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .unwrap();
+    file.write_all(b"Hello world").unwrap();
+    file.sync_data().unwrap();
+    file.sync_all().unwrap();
+    file.set_len(5).unwrap();
+    assert_eq!(5, file.metadata().unwrap().len());
+    file.set_len(4096).unwrap();
+    assert_eq!(4096, file.metadata().unwrap().len());
+    let content = crate::fs::read(path).unwrap();
+    assert_eq!(b"Hello", &content[..5]);
+    assert!(content[5..].iter().all(|b| *b == 0));
+    file.allocate(0, 16384).unwrap();
+    let md = file.metadata().unwrap();
+    assert_eq!(16384, md.len());
+    assert!(md.blocks() * 512 >= 16384);
+    let read_only = File::open(path).unwrap();
+    assert!(read_only.set_len(0).is_err());
+    assert!(file.set_len(u64::MAX).is_err());
+    crate::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn can_create_and_read_links() {
+    let dir = UnixStr::try_from_str("test-files/fs/links_basic\0").unwrap();
+    let _ = crate::fs::remove_dir_all(dir);
+    crate::fs::create_dir_all(UnixStr::try_from_str("test-files/fs/links_basic/\0").unwrap())
+        .unwrap();
+    let orig = UnixStr::try_from_str("test-files/fs/links_basic/orig.txt\0").unwrap();
+    crate::fs::write(orig, b"linked").unwrap();
+    let soft = UnixStr::try_from_str("test-files/fs/links_basic/soft\0").unwrap();
+    let hard = UnixStr::try_from_str("test-files/fs/links_basic/hard\0").unwrap();
+    crate::fs::symlink(UnixStr::try_from_str("orig.txt\0").unwrap(), soft).unwrap();
+    crate::fs::hard_link(orig, hard).unwrap();
+    assert!(crate::fs::symlink_metadata(soft).unwrap().is_symlink());
+    assert!(metadata(soft).unwrap().is_file());
+    assert_eq!(metadata(orig).unwrap().ino(), metadata(hard).unwrap().ino());
+    assert_eq!(2, metadata(orig).unwrap().nlink());
+    #[cfg(feature = "alloc")]
+    {
+        let target = crate::fs::read_link(soft).unwrap();
+        assert_eq!("orig.txt", target.as_str().unwrap());
+        assert!(crate::fs::read_link(orig)
+            .unwrap_err()
+            .matches_errno(Errno::EINVAL));
+        assert_eq!(b"linked".as_slice(), crate::fs::read(soft).unwrap());
+    }
+    assert!(crate::fs::symlink(orig, soft)
+        .unwrap_err()
+        .matches_errno(Errno::EEXIST));
+    crate::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn can_canonicalize() {
+    let dir = UnixStr::try_from_str("test-files/fs/links_canon\0").unwrap();
+    let _ = crate::fs::remove_dir_all(dir);
+    crate::fs::create_dir_all(UnixStr::try_from_str("test-files/fs/links_canon/a/b/\0").unwrap())
+        .unwrap();
+    crate::fs::write(
+        UnixStr::try_from_str("test-files/fs/links_canon/a/b/file\0").unwrap(),
+        b"",
+    )
+    .unwrap();
+    // Relative link to a directory, and a chain of links ending in it
+    crate::fs::symlink(
+        UnixStr::try_from_str("a/b\0").unwrap(),
+        UnixStr::try_from_str("test-files/fs/links_canon/to_b\0").unwrap(),
+    )
+    .unwrap();
+    crate::fs::symlink(
+        UnixStr::try_from_str("to_b/../b/file\0").unwrap(),
+        UnixStr::try_from_str("test-files/fs/links_canon/chain\0").unwrap(),
+    )
+    .unwrap();
+    crate::fs::symlink(
+        UnixStr::try_from_str("loop\0").unwrap(),
+        UnixStr::try_from_str("test-files/fs/links_canon/loop\0").unwrap(),
+    )
+    .unwrap();
+    let cwd = std::env::current_dir().unwrap();
+    let expect = std::format!("{}/test-files/fs/links_canon/a/b/file", cwd.display());
+    let canon = crate::fs::canonicalize(
+        UnixStr::try_from_str("test-files/fs/links_canon/./a/../to_b/file\0").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(expect, canon.as_str().unwrap());
+    // `..` after a link to a directory applies to where the link points
+    let canon = crate::fs::canonicalize(
+        UnixStr::try_from_str("test-files/fs/links_canon/chain\0").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(expect, canon.as_str().unwrap());
+    let abs = UnixString::try_from_str(&std::format!("{expect}\0")).unwrap();
+    assert_eq!(
+        expect,
+        crate::fs::canonicalize(&abs).unwrap().as_str().unwrap()
+    );
+    assert_eq!(
+        "/",
+        crate::fs::canonicalize(UnixStr::try_from_str("/../..\0").unwrap())
+            .unwrap()
+            .as_str()
+            .unwrap()
+    );
+    assert!(crate::fs::canonicalize(
+        UnixStr::try_from_str("test-files/fs/links_canon/missing\0").unwrap()
+    )
+    .unwrap_err()
+    .matches_errno(Errno::ENOENT));
+    assert!(crate::fs::canonicalize(
+        UnixStr::try_from_str("test-files/fs/links_canon/loop\0").unwrap()
+    )
+    .unwrap_err()
+    .matches_errno(Errno::ELOOP));
+    crate::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn set_permissions_and_times() {
+    crate::fs::create_dir_all(UnixStr::try_from_str("test-files/fs/perms/\0").unwrap()).unwrap();
+    let src = UnixStr::try_from_str("test-files/fs/perms/bin\0").unwrap();
+    let dest = UnixStr::try_from_str("test-files/fs/perms/bin_copy\0").unwrap();
+    let _ = crate::fs::remove_file(src);
+    let _ = crate::fs::remove_file(dest);
+    crate::fs::write(src, b"#!/bin/sh\n").unwrap();
+    let mut perms = crate::fs::Permissions::from_mode(0o755);
+    crate::fs::set_permissions(src, perms).unwrap();
+    assert_eq!(0o755, metadata(src).unwrap().permissions().mode());
+    perms.set_readonly(true);
+    assert!(perms.readonly());
+    assert_eq!(0o555, perms.mode());
+    let file = File::open(src).unwrap();
+    file.set_permissions(perms).unwrap();
+    assert!(file.metadata().unwrap().permissions().readonly());
+    file.set_permissions(crate::fs::Permissions::from_mode(0o755))
+        .unwrap();
+
+    // Copy and keep the original mtime, leaving atime untouched
+    let mtime = crate::time::SystemTime::from(rusl::platform::TimeSpec::new(1_000_000, 42));
+    crate::fs::set_times(src, crate::fs::FileTimes::new().set_modified(mtime)).unwrap();
+    let src_meta = metadata(src).unwrap();
+    assert_eq!(mtime, src_meta.modified());
+    let copy = crate::fs::copy_file(src, dest).unwrap();
+    copy.set_times(crate::fs::FileTimes::new().set_modified(src_meta.modified()))
+        .unwrap();
+    let dest_meta = copy.metadata().unwrap();
+    assert_eq!(mtime, dest_meta.modified());
+    assert_eq!(0o755, dest_meta.permissions().mode());
+
+    // Chown to ourselves is always permitted
+    let uid = rusl::unistd::get_uid().unwrap();
+    crate::fs::chown(dest, Some(uid), None).unwrap();
+    crate::fs::chown(dest, None, Some(dest_meta.gid())).unwrap();
+    assert_eq!(uid, metadata(dest).unwrap().uid());
+    assert!(crate::fs::set_permissions(
+        UnixStr::try_from_str("test-files/fs/perms/missing\0").unwrap(),
+        perms
+    )
+    .unwrap_err()
+    .matches_errno(Errno::ENOENT));
+    crate::fs::remove_dir_all(UnixStr::try_from_str("test-files/fs/perms\0").unwrap()).unwrap();
+}
@@ -0,0 +1,646 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use rusl::error::Errno;
+use rusl::platform::{InotifyEvent, InotifyFlags, InotifyMask};
+use rusl::string::unix_str::{UnixStr, UnixString};
+
+use crate::error::{Error, Result};
+use crate::fs::{metadata, Directory, FileType};
+use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
+
+/// Fits the largest possible event a good number of times over, move pairs are only
+/// matched within a single read, so a bigger buffer makes splitting them less likely.
+const EVENT_BUF_SIZE: usize = InotifyEvent::MIN_BUF_SIZE * 16;
+
+/// A change to a watched path
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WatchEvent {
+    Created {
+        path: UnixString,
+        is_dir: bool,
+    },
+    Modified {
+        path: UnixString,
+    },
+    Deleted {
+        path: UnixString,
+        is_dir: bool,
+    },
+    /// Something was moved, `from` is `None` if it was moved in from outside the watched paths,
+    /// `to` is `None` if it was moved out of them.
+    Moved {
+        from: Option<UnixString>,
+        to: Option<UnixString>,
+        is_dir: bool,
+    },
+    /// The kernel's event queue overflowed and events were dropped
+    Overflow,
+}
+
+struct Watch {
+    path: UnixString,
+    // `Some(recursive)` if added by the user, rather than by recursing into a directory
+    root: Option<bool>,
+    // The roots this watch was added for, including itself if it's a root.
+    // A directory can be below a recursive root and be a root of its own at the same time.
+    owners: Vec<i32>,
+    // `IN_DELETE_SELF` doesn't carry `IN_ISDIR`, and a deleted path can't be inspected
+    is_dir: bool,
+}
+
+/// Watches files and directories for changes, backed by inotify.
+/// Readable when there are events, so it can be registered with an `EpollDriver`.
+pub struct Watcher {
+    fd: OwnedFd,
+    watches: BTreeMap<i32, Watch>,
+    buf: Vec<u8>,
+    // Events that were read before an error, returned on the next read
+    pending: Vec<WatchEvent>,
+}
+
+impl Watcher {
+    /// Create a new `Watcher` without any watches.
+    /// If `nonblocking`, reading events when there are none produces an `EAGAIN` error
+    /// instead of waiting for one.
+    /// # Errors
+    /// Os errors creating the inotify instance
+    pub fn new(nonblocking: bool) -> Result<Self> {
+        let flags = if nonblocking {
+            InotifyFlags::IN_CLOEXEC | InotifyFlags::IN_NONBLOCK
+        } else {
+            InotifyFlags::IN_CLOEXEC
+        };
+        let fd = rusl::unistd::inotify_init1(flags)?;
+        Ok(Self {
+            fd: OwnedFd(fd),
+            watches: BTreeMap::new(),
+            buf: vec![0; EVENT_BUF_SIZE],
+            pending: Vec::new(),
+        })
+    }
+
+    /// Watch `path` for changes. If `path` is a directory, changes to its entries are reported,
+    /// if `recursive`, changes anywhere in the directory tree below it are reported as well,
+    /// including in directories created after the watch was added.
+    /// Entries created inside a new directory before its watch was added are not reported.
+    /// # Errors
+    /// Os errors adding the watch, or reading the directory tree if `recursive`
+    pub fn watch(&mut self, path: &UnixStr, recursive: bool) -> Result<()> {
+        let (wd, is_dir) = self.add_watch(path)?;
+        if let Some(watch) = self.watches.get_mut(&wd) {
+            watch.root = Some(recursive);
+        }
+        self.add_owners(wd, &[wd]);
+        if recursive && is_dir {
+            self.add_sub_dir_watches(path, &[wd])?;
+        }
+        Ok(())
+    }
+
+    /// Stop watching `path`, and everything below it if it was watched recursively.
+    /// Paths below it that are watched on their own, or through another recursive watch,
+    /// stay watched.
+    /// # Errors
+    /// `path` isn't watched, or os errors removing the watch
+    pub fn unwatch(&mut self, path: &UnixStr) -> Result<()> {
+        let Some((&root_wd, _)) = self
+            .watches
+            .iter()
+            .find(|(_, watch)| watch.root.is_some() && watch.path.as_slice() == path.as_slice())
+        else {
+            return Err(Error::no_code("Tried to unwatch a path that isn't watched"));
+        };
+        if let Some(root) = self.watches.get_mut(&root_wd) {
+            root.root = None;
+        }
+        let fd = self.fd.0;
+        let mut res = Ok(());
+        self.watches.retain(|&wd, watch| {
+            watch.owners.retain(|&owner| owner != root_wd);
+            if !watch.owners.is_empty() {
+                return true;
+            }
+            let removed = rusl::unistd::inotify_rm_watch(fd, wd);
+            // Best effort below the root, it's gone if the directory has been deleted
+            if wd == root_wd {
+                res = removed.map_err(Error::from);
+            }
+            false
+        });
+        res
+    }
+
+    /// Read available events, waits for at least one if blocking.
+    /// If an error occurs after events have been read, the error is returned
+    /// and the events are returned by the next call.
+    /// # Errors
+    /// Os errors reading, if nonblocking `EAGAIN` if there are no events,
+    /// or os errors adding watches to new directories if watching recursively
+    pub fn read_events(&mut self) -> Result<Vec<WatchEvent>> {
+        if !self.pending.is_empty() {
+            return Ok(core::mem::take(&mut self.pending));
+        }
+        // Taken out so that `self` can be modified while events are parsed from it
+        let mut buf = core::mem::take(&mut self.buf);
+        let mut events = Vec::new();
+        let mut res = self.read_events_into(&mut buf, &mut events);
+        // Reads can consist of only events that aren't reported, such as `IN_IGNORED`
+        while res.is_ok() && events.is_empty() {
+            res = self.read_events_into(&mut buf, &mut events);
+        }
+        self.buf = buf;
+        match res {
+            Ok(()) => Ok(events),
+            Err(e) => {
+                self.pending = events;
+                Err(e)
+            }
+        }
+    }
+
+    fn read_events_into(&mut self, buf: &mut [u8], events: &mut Vec<WatchEvent>) -> Result<()> {
+        let read = rusl::unistd::read(self.fd.0, buf)?;
+        let mut pending_move: Option<(u32, UnixString, bool)> = None;
+        let mut offset = 0;
+        // Events that have been read can't be read again, so the rest are handled
+        // even if one fails, and the first error is returned afterwards
+        let mut res = Ok(());
+        while let Some((event, used)) = InotifyEvent::try_from_bytes(&buf[offset..read]) {
+            offset += used;
+            let handled = self.handle_event(&event, events, &mut pending_move);
+            if res.is_ok() {
+                res = handled;
+            }
+        }
+        if let Some((_, from, is_dir)) = pending_move {
+            self.moved_out(&from, is_dir);
+            events.push(WatchEvent::Moved {
+                from: Some(from),
+                to: None,
+                is_dir,
+            });
+        }
+        res
+    }
+
+    fn handle_event(
+        &mut self,
+        event: &InotifyEvent,
+        events: &mut Vec<WatchEvent>,
+        pending_move: &mut Option<(u32, UnixString, bool)>,
+    ) -> Result<()> {
+        let mask = event.mask;
+        // The two halves of a move are queued back to back
+        let completes_move = matches!(pending_move, Some((cookie, _, _))
+            if mask.contains(InotifyMask::IN_MOVED_TO) && event.cookie == *cookie);
+        if !completes_move {
+            if let Some((_, from, is_dir)) = pending_move.take() {
+                self.moved_out(&from, is_dir);
+                events.push(WatchEvent::Moved {
+                    from: Some(from),
+                    to: None,
+                    is_dir,
+                });
+            }
+        }
+        if mask.contains(InotifyMask::IN_Q_OVERFLOW) {
+            events.push(WatchEvent::Overflow);
+            return Ok(());
+        }
+        if mask.contains(InotifyMask::IN_IGNORED) {
+            self.watches.remove(&event.wd);
+            return Ok(());
+        }
+        let Some(watch) = self.watches.get(&event.wd) else {
+            // Event for a watch that's been removed
+            return Ok(());
+        };
+        let path = match event.name {
+            Some(name) => watch.path.path_join(name),
+            None => watch.path.clone(),
+        };
+        let is_dir = mask.contains(InotifyMask::IN_ISDIR);
+        let root = watch.root.is_some();
+        let watch_is_dir = watch.is_dir;
+        let recursive_owners = self.recursive_owners(event.wd);
+        if mask.contains(InotifyMask::IN_CREATE) {
+            events.push(WatchEvent::Created {
+                path: path.clone(),
+                is_dir,
+            });
+            if is_dir && !recursive_owners.is_empty() {
+                self.add_nested_watch_ignore_missing(&path, &recursive_owners)?;
+            }
+        } else if mask.contains(InotifyMask::IN_MODIFY) {
+            events.push(WatchEvent::Modified { path });
+        } else if mask.contains(InotifyMask::IN_DELETE) {
+            events.push(WatchEvent::Deleted { path, is_dir });
+        } else if mask.contains(InotifyMask::IN_DELETE_SELF) {
+            // A directory below a recursive watch is already reported through its parent
+            if root {
+                events.push(WatchEvent::Deleted {
+                    path,
+                    is_dir: watch_is_dir,
+                });
+            }
+        } else if mask.contains(InotifyMask::IN_MOVED_FROM) {
+            *pending_move = Some((event.cookie, path, is_dir));
+        } else if mask.contains(InotifyMask::IN_MOVED_TO) {
+            if let Some((_, from, _)) = pending_move.take() {
+                if is_dir {
+                    self.rename_watches(&from, &path);
+                }
+                events.push(WatchEvent::Moved {
+                    from: Some(from),
+                    to: Some(path),
+                    is_dir,
+                });
+            } else {
+                events.push(WatchEvent::Moved {
+                    from: None,
+                    to: Some(path.clone()),
+                    is_dir,
+                });
+                if is_dir && !recursive_owners.is_empty() {
+                    self.add_nested_watch_ignore_missing(&path, &recursive_owners)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a watch without owners, the kernel hands out the same descriptor if the path
+    /// is already watched, in which case the existing watch is kept
+    fn add_watch(&mut self, path: &UnixStr) -> Result<(i32, bool)> {
+        let wd = rusl::unistd::inotify_add_watch(self.fd.0, path, watch_mask())?;
+        let is_dir = metadata(path)?.is_dir();
+        self.watches.entry(wd).or_insert_with(|| Watch {
+            path: UnixString::from(path),
+            root: None,
+            owners: Vec::new(),
+            is_dir,
+        });
+        Ok((wd, is_dir))
+    }
+
+    fn add_owners(&mut self, wd: i32, owners: &[i32]) {
+        if let Some(watch) = self.watches.get_mut(&wd) {
+            for owner in owners {
+                if !watch.owners.contains(owner) {
+                    watch.owners.push(*owner);
+                }
+            }
+        }
+    }
+
+    /// The roots that directories created below the watch should be watched for
+    fn recursive_owners(&self, wd: i32) -> Vec<i32> {
+        self.watches.get(&wd).map_or_else(Vec::new, |watch| {
+            watch
+                .owners
+                .iter()
+                .copied()
+                .filter(|owner| {
+                    self.watches
+                        .get(owner)
+                        .is_some_and(|root| root.root == Some(true))
+                })
+                .collect()
+        })
+    }
+
+    fn add_sub_dir_watches(&mut self, path: &UnixStr, owners: &[i32]) -> Result<()> {
+        for sub_dir in sub_dirs(path)? {
+            self.add_nested_watch_ignore_missing(&sub_dir, owners)?;
+        }
+        Ok(())
+    }
+
+    /// Directories can disappear between being found and being watched
+    fn add_nested_watch_ignore_missing(&mut self, path: &UnixStr, owners: &[i32]) -> Result<()> {
+        match self.add_nested_watch(path, owners) {
+            Err(e) if e.matches_errno(Errno::ENOENT) => Ok(()),
+            res => res,
+        }
+    }
+
+    fn add_nested_watch(&mut self, path: &UnixStr, owners: &[i32]) -> Result<()> {
+        let (wd, is_dir) = self.add_watch(path)?;
+        self.add_owners(wd, owners);
+        if is_dir {
+            self.add_sub_dir_watches(path, owners)?;
+        }
+        Ok(())
+    }
+
+    fn moved_out(&mut self, from: &UnixStr, is_dir: bool) {
+        if is_dir {
+            self.remove_watches_under(from);
+        }
+    }
+
+    fn remove_watches_under(&mut self, root: &UnixStr) {
+        let fd = self.fd.0;
+        self.watches.retain(|wd, watch| {
+            if is_same_or_under(&watch.path, root) {
+                // Best effort, it's gone if the directory has been deleted
+                let _ = rusl::unistd::inotify_rm_watch(fd, *wd);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    fn rename_watches(&mut self, from: &UnixStr, to: &UnixStr) {
+        let from_bytes = without_null(from);
+        let to_bytes = without_null(to);
+        for watch in self.watches.values_mut() {
+            if is_same_or_under(&watch.path, from) {
+                let mut new_path = to_bytes.to_vec();
+                new_path.extend_from_slice(&watch.path.as_slice()[from_bytes.len()..]);
+                // Both parts are null free apart from the trailing null that is carried over
+                if let Ok(new_path) = UnixString::try_from_vec(new_path) {
+                    watch.path = new_path;
+                }
+            }
+        }
+    }
+}
+
+impl AsRawFd for Watcher {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+#[inline]
+fn watch_mask() -> InotifyMask {
+    InotifyMask::IN_CREATE
+        | InotifyMask::IN_MODIFY
+        | InotifyMask::IN_DELETE
+        | InotifyMask::IN_DELETE_SELF
+        | InotifyMask::IN_MOVED_FROM
+        | InotifyMask::IN_MOVED_TO
+}
+
+fn sub_dirs(path: &UnixStr) -> Result<Vec<UnixString>> {
+    let dir = Directory::open(path)?;
+    let mut found = Vec::new();
+    for entry in dir.read() {
+        let entry = entry?;
+        if entry.is_relative_reference() {
+            continue;
+        }
+        let full = path.path_join(entry.file_unix_name()?);
+        match entry.file_type() {
+            FileType::Directory => found.push(full),
+            // Not all filesystems fill in the type
+            FileType::Unknown if metadata(&full).is_ok_and(|md| md.is_dir()) => found.push(full),
+            _ => {}
+        }
+    }
+    Ok(found)
+}
+
+#[inline]
+fn without_null(path: &UnixStr) -> &[u8] {
+    let bytes = path.as_slice();
+    &bytes[..bytes.len() - 1]
+}
+
+fn is_same_or_under(path: &UnixStr, root: &UnixStr) -> bool {
+    let path = without_null(path);
+    let root = without_null(root);
+    if !path.starts_with(root) {
+        return false;
+    }
+    path.len() == root.len() || root.ends_with(b"/") || path[root.len()] == b'/'
+}
+
+#[cfg(test)]
+mod tests {
+    use rusl::unix_lit;
+
+    use super::*;
+    use crate::fs::{create_dir, remove_dir_all, rename, write};
+    use crate::linux::epoll::{EpollDriver, EpollEvent, EpollEventMask, EpollTimeout};
+
+    fn fresh_dir(path: &UnixStr) {
+        let _ = remove_dir_all(path);
+        create_dir(path).unwrap();
+    }
+
+    fn owned(path: &UnixStr) -> UnixString {
+        UnixString::from(path)
+    }
+
+    #[test]
+    fn watch_create_modify_move_delete() {
+        let dir = unix_lit!("test-files/fs/watcher_flat");
+        let file = unix_lit!("test-files/fs/watcher_flat/a.txt");
+        let moved = unix_lit!("test-files/fs/watcher_flat/b.txt");
+        fresh_dir(dir);
+        let mut watcher = Watcher::new(false).unwrap();
+        watcher.watch(dir, false).unwrap();
+        write(file, b"hello").unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(file),
+                is_dir: false
+            },
+            events[0]
+        );
+        assert!(events.contains(&WatchEvent::Modified { path: owned(file) }));
+        rename(file, moved).unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            vec![WatchEvent::Moved {
+                from: Some(owned(file)),
+                to: Some(owned(moved)),
+                is_dir: false
+            }],
+            events
+        );
+        crate::fs::remove_file(moved).unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            vec![WatchEvent::Deleted {
+                path: owned(moved),
+                is_dir: false
+            }],
+            events
+        );
+        watcher.unwatch(dir).unwrap();
+        assert!(watcher.unwatch(dir).is_err());
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn watch_recursive() {
+        let dir = unix_lit!("test-files/fs/watcher_recursive");
+        let existing = unix_lit!("test-files/fs/watcher_recursive/existing");
+        let existing_file = unix_lit!("test-files/fs/watcher_recursive/existing/f.txt");
+        let new_dir = unix_lit!("test-files/fs/watcher_recursive/new");
+        let new_file = unix_lit!("test-files/fs/watcher_recursive/new/f.txt");
+        let renamed_dir = unix_lit!("test-files/fs/watcher_recursive/renamed");
+        let renamed_file = unix_lit!("test-files/fs/watcher_recursive/renamed/g.txt");
+        fresh_dir(dir);
+        create_dir(existing).unwrap();
+        let mut watcher = Watcher::new(false).unwrap();
+        watcher.watch(dir, true).unwrap();
+        write(existing_file, b"").unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(existing_file),
+                is_dir: false
+            },
+            events[0]
+        );
+        create_dir(new_dir).unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            vec![WatchEvent::Created {
+                path: owned(new_dir),
+                is_dir: true
+            }],
+            events
+        );
+        write(new_file, b"").unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(new_file),
+                is_dir: false
+            },
+            events[0]
+        );
+        rename(new_dir, renamed_dir).unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Moved {
+                from: Some(owned(new_dir)),
+                to: Some(owned(renamed_dir)),
+                is_dir: true
+            },
+            events[0]
+        );
+        // The watch for the moved directory should follow it
+        write(renamed_file, b"").unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(renamed_file),
+                is_dir: false
+            },
+            events[0]
+        );
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unwatch_keeps_nested_roots() {
+        let dir = unix_lit!("test-files/fs/watcher_nested");
+        let nested = unix_lit!("test-files/fs/watcher_nested/nested");
+        let new_dir = unix_lit!("test-files/fs/watcher_nested/nested/new");
+        let new_file = unix_lit!("test-files/fs/watcher_nested/nested/new/f.txt");
+        let outer_file = unix_lit!("test-files/fs/watcher_nested/f.txt");
+        fresh_dir(dir);
+        create_dir(nested).unwrap();
+        let mut watcher = Watcher::new(true).unwrap();
+        watcher.watch(dir, true).unwrap();
+        watcher.watch(nested, true).unwrap();
+        create_dir(new_dir).unwrap();
+        assert_eq!(
+            vec![WatchEvent::Created {
+                path: owned(new_dir),
+                is_dir: true
+            }],
+            watcher.read_events().unwrap()
+        );
+        // The directory created below both roots stays watched for the nested one
+        watcher.unwatch(dir).unwrap();
+        write(outer_file, b"").unwrap();
+        write(new_file, b"").unwrap();
+        let events = watcher.read_events().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(new_file),
+                is_dir: false
+            },
+            events[0]
+        );
+        assert!(!events.iter().any(|event| matches!(
+            event,
+            WatchEvent::Created { path, .. } if path.as_slice() == outer_file.as_slice()
+        )));
+        assert!(watcher.unwatch(dir).is_err());
+        watcher.unwatch(nested).unwrap();
+        write(new_file, b"more").unwrap();
+        // Only the `IN_IGNORED` events of the removed watches are left, which aren't reported
+        assert!(watcher
+            .read_events()
+            .unwrap_err()
+            .matches_errno(Errno::EAGAIN));
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn blocking_read_skips_unreported_events() {
+        let dir = unix_lit!("test-files/fs/watcher_blocking");
+        let unwatched = unix_lit!("test-files/fs/watcher_blocking/unwatched");
+        let file = unix_lit!("test-files/fs/watcher_blocking/a.txt");
+        fresh_dir(dir);
+        create_dir(unwatched).unwrap();
+        let mut watcher = Watcher::new(false).unwrap();
+        watcher.watch(dir, false).unwrap();
+        watcher.watch(unwatched, false).unwrap();
+        // Queues an `IN_IGNORED` event, the first read only gets that one
+        watcher.unwatch(unwatched).unwrap();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(core::time::Duration::from_millis(20));
+            write(file, b"").unwrap();
+        });
+        let events = watcher.read_events().unwrap();
+        writer.join().unwrap();
+        assert_eq!(
+            WatchEvent::Created {
+                path: owned(file),
+                is_dir: false
+            },
+            events[0]
+        );
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn watcher_pollable() {
+        let dir = unix_lit!("test-files/fs/watcher_poll");
+        let file = unix_lit!("test-files/fs/watcher_poll/a.txt");
+        fresh_dir(dir);
+        let mut watcher = Watcher::new(true).unwrap();
+        watcher.watch(dir, false).unwrap();
+        assert!(watcher
+            .read_events()
+            .unwrap_err()
+            .matches_errno(Errno::EAGAIN));
+        let driver = EpollDriver::create(true).unwrap();
+        driver
+            .register(watcher.as_raw_fd(), 5, EpollEventMask::EPOLLIN)
+            .unwrap();
+        write(file, b"").unwrap();
+        let mut events = [EpollEvent::new(0, EpollEventMask::empty())];
+        let ready = driver
+            .wait(&mut events, EpollTimeout::WaitMillis(1_000))
+            .unwrap();
+        assert_eq!(1, ready);
+        assert_eq!(5, events[0].get_data());
+        assert!(!watcher.read_events().unwrap().is_empty());
+        remove_dir_all(dir).unwrap();
+    }
+}
@@ -5,11 +5,15 @@ use alloc::vec::Vec;
 use core::{fmt, str};
 
 use rusl::error::Errno;
+use rusl::platform::{Mode, PollEvents, PollFd, SpliceFlags};
 
 use crate::error::{Error, Result};
+use crate::unix::fd::{AsRawFd, RawFd};
 
 #[cfg(feature = "alloc")]
 pub(crate) mod read_buf;
+#[cfg(all(test, feature = "alloc"))]
+mod test;
 
 pub trait Read {
     /// Read into to provided buffer
@@ -289,3 +293,113 @@ pub trait Write {
         self
     }
 }
+
+/// Copies everything from `reader` to `writer` until `reader` reaches EOF,
+/// returning the number of bytes copied.
+/// Both ends are read from and written to at, and advance, their current offsets.
+/// The copy stays in-kernel where possible, `splice` is used if either end is a pipe,
+/// and `sendfile` if `reader` is a regular file. If the kernel refuses the pair,
+/// it falls back to a plain read/write loop.
+/// Non-blocking ends are polled until ready.
+/// # Errors
+/// Os errors reading or writing
+pub fn copy<R: AsRawFd + ?Sized, W: AsRawFd + ?Sized>(reader: &R, writer: &W) -> Result<u64> {
+    let (in_fd, out_fd) = (reader.as_raw_fd(), writer.as_raw_fd());
+    let mut method = CopyMethod::for_pair(in_fd, out_fd)?;
+    let mut copied = 0;
+    loop {
+        let res = match method {
+            CopyMethod::Splice => rusl::unistd::splice(
+                in_fd,
+                None,
+                out_fd,
+                None,
+                MAX_IN_KERNEL_COPY,
+                SpliceFlags::SPLICE_F_MOVE,
+            ),
+            CopyMethod::Sendfile => rusl::unistd::sendfile(out_fd, in_fd, None, MAX_IN_KERNEL_COPY),
+            CopyMethod::ReadWrite => return Ok(copied + read_write_copy(in_fd, out_fd)?),
+        };
+        match res {
+            Ok(0) => return Ok(copied),
+            Ok(n) => copied += n as u64,
+            Err(e)
+                if e.code == Some(Errno::EINVAL)
+                    || e.code == Some(Errno::EXDEV)
+                    || e.code == Some(Errno::ENOSYS) =>
+            {
+                method = CopyMethod::ReadWrite;
+            }
+            Err(e) if e.code == Some(Errno::EAGAIN) => {
+                wait_ready(in_fd, PollEvents::POLLIN)?;
+                wait_ready(out_fd, PollEvents::POLLOUT)?;
+            }
+            Err(e) if e.code == Some(Errno::EINTR) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// The most the kernel will transfer in one call, see `MAX_RW_COUNT` in `include/linux/fs.h`
+const MAX_IN_KERNEL_COPY: usize = 0x7fff_f000;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum CopyMethod {
+    Splice,
+    Sendfile,
+    ReadWrite,
+}
+
+impl CopyMethod {
+    fn for_pair(in_fd: RawFd, out_fd: RawFd) -> Result<Self> {
+        let in_type = Mode::from(rusl::unistd::stat_fd(in_fd)?.st_mode) & Mode::S_IFMT;
+        let out_type = Mode::from(rusl::unistd::stat_fd(out_fd)?.st_mode) & Mode::S_IFMT;
+        Ok(if in_type == Mode::S_IFIFO || out_type == Mode::S_IFIFO {
+            Self::Splice
+        } else if in_type == Mode::S_IFREG || in_type == Mode::S_IFBLK {
+            Self::Sendfile
+        } else {
+            Self::ReadWrite
+        })
+    }
+}
+
+fn read_write_copy(in_fd: RawFd, out_fd: RawFd) -> Result<u64> {
+    let mut buf = [0u8; 8192];
+    let mut copied = 0;
+    loop {
+        let read = match rusl::unistd::read(in_fd, &mut buf) {
+            Ok(0) => return Ok(copied),
+            Ok(n) => n,
+            Err(e) if e.code == Some(Errno::EAGAIN) => {
+                wait_ready(in_fd, PollEvents::POLLIN)?;
+                continue;
+            }
+            Err(e) if e.code == Some(Errno::EINTR) => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let mut remaining = &buf[..read];
+        while !remaining.is_empty() {
+            match rusl::unistd::write(out_fd, remaining) {
+                Ok(0) => return Err(Error::no_code("failed to write whole buffer")),
+                Ok(n) => remaining = &remaining[n..],
+                Err(e) if e.code == Some(Errno::EAGAIN) => {
+                    wait_ready(out_fd, PollEvents::POLLOUT)?;
+                }
+                Err(e) if e.code == Some(Errno::EINTR) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        copied += read as u64;
+    }
+}
+
+fn wait_ready(fd: RawFd, events: PollEvents) -> Result<()> {
+    loop {
+        match rusl::select::ppoll(&mut [PollFd::new(fd, events)], None, None) {
+            Ok(_) => return Ok(()),
+            Err(e) if e.code == Some(Errno::EINTR) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
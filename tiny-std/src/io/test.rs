@@ -0,0 +1,124 @@
+use rusl::platform::OpenFlags;
+use rusl::string::unix_str::UnixStr;
+
+use crate::fs::{File, OpenOptions};
+use crate::io::{Read, Write};
+use crate::net::{UnixListener, UnixStream};
+
+fn content(len: usize) -> std::vec::Vec<u8> {
+    (0..len).map(|i| u8::try_from(i % 251).unwrap()).collect()
+}
+
+fn source_file(path: &UnixStr, len: usize) -> (File, std::vec::Vec<u8>) {
+    crate::fs::create_dir_all(UnixStr::try_from_str("test-files/io/\0").unwrap()).unwrap();
+    let data = content(len);
+    crate::fs::write(path, &data).unwrap();
+    (File::open(path).unwrap(), data)
+}
+
+fn unix_pair(path: &UnixStr) -> (UnixStream, UnixStream) {
+    crate::fs::create_dir_all(UnixStr::try_from_str("/tmp/test-sock/\0").unwrap()).unwrap();
+    let _ = crate::fs::remove_file(path);
+    let mut listener = UnixListener::bind(path).unwrap();
+    let client = UnixStream::connect(path).unwrap();
+    let server = listener.accept().unwrap();
+    (client, server)
+}
+
+#[test]
+fn copy_file_to_file() {
+    let src_path = UnixStr::try_from_str("test-files/io/copy_src1\0").unwrap();
+    let (src, data) = source_file(src_path, 100_000);
+    let dest_path = UnixStr::try_from_str("test-files/io/copy_dest1\0").unwrap();
+    let dest = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(dest_path)
+        .unwrap();
+    assert_eq!(100_000, super::copy(&src, &dest).unwrap());
+    // The source offset was advanced, so there's nothing left
+    assert_eq!(0, super::copy(&src, &dest).unwrap());
+    assert_eq!(data, crate::fs::read(dest_path).unwrap());
+    crate::fs::remove_file(src_path).unwrap();
+    crate::fs::remove_file(dest_path).unwrap();
+}
+
+#[test]
+fn copy_falls_back_when_kernel_refuses() {
+    let src_path = UnixStr::try_from_str("test-files/io/copy_src2\0").unwrap();
+    let (src, data) = source_file(src_path, 20_000);
+    let dest_path = UnixStr::try_from_str("test-files/io/copy_dest2\0").unwrap();
+    crate::fs::write(dest_path, b"head").unwrap();
+    // `sendfile` refuses `O_APPEND` destinations with `EINVAL`
+    let dest = OpenOptions::new().append(true).open(dest_path).unwrap();
+    assert_eq!(20_000, super::copy(&src, &dest).unwrap());
+    let written = crate::fs::read(dest_path).unwrap();
+    assert_eq!(b"head", &written[..4]);
+    assert_eq!(data, &written[4..]);
+    crate::fs::remove_file(src_path).unwrap();
+    crate::fs::remove_file(dest_path).unwrap();
+}
+
+#[test]
+fn copy_file_to_pipe() {
+    let src_path = UnixStr::try_from_str("test-files/io/copy_src3\0").unwrap();
+    let (src, data) = source_file(src_path, 200_000);
+    let pipe = rusl::unistd::pipe2(OpenFlags::O_CLOEXEC).unwrap();
+    let mut read_end = unsafe { File::from_raw_fd(pipe.in_pipe) };
+    let write_end = unsafe { File::from_raw_fd(pipe.out_pipe) };
+    // More than fits in a pipe buffer, so the reader has to run concurrently
+    let reader = std::thread::spawn(move || {
+        let mut received = std::vec::Vec::new();
+        read_end.read_to_end(&mut received).unwrap();
+        received
+    });
+    assert_eq!(200_000, super::copy(&src, &write_end).unwrap());
+    drop(write_end);
+    assert_eq!(data, reader.join().unwrap());
+    crate::fs::remove_file(src_path).unwrap();
+}
+
+#[test]
+fn copy_file_to_nonblocking_socket() {
+    let src_path = UnixStr::try_from_str("test-files/io/copy_src4\0").unwrap();
+    let (src, data) = source_file(src_path, 1_000_000);
+    let (client, mut server) =
+        unix_pair(UnixStr::try_from_str("/tmp/test-sock/io-copy1\0").unwrap());
+    let reader = std::thread::spawn(move || {
+        let mut received = std::vec::Vec::new();
+        let mut buf = [0u8; 4096];
+        while received.len() < 1_000_000 {
+            let read = server.read(&mut buf).unwrap();
+            received.extend_from_slice(&buf[..read]);
+        }
+        received
+    });
+    assert_eq!(1_000_000, super::copy(&src, &client).unwrap());
+    assert_eq!(data, reader.join().unwrap());
+    crate::fs::remove_file(src_path).unwrap();
+}
+
+#[test]
+fn copy_socket_to_file() {
+    let (mut client, server) =
+        unix_pair(UnixStr::try_from_str("/tmp/test-sock/io-copy2\0").unwrap());
+    let dest_path = UnixStr::try_from_str("test-files/io/copy_dest5\0").unwrap();
+    crate::fs::create_dir_all(UnixStr::try_from_str("test-files/io/\0").unwrap()).unwrap();
+    let dest = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(dest_path)
+        .unwrap();
+    let data = content(50_000);
+    let send = data.clone();
+    let writer = std::thread::spawn(move || {
+        client.write_all(&send).unwrap();
+    });
+    let copier = std::thread::spawn(move || super::copy(&server, &dest).unwrap());
+    writer.join().unwrap();
+    assert_eq!(50_000, copier.join().unwrap());
+    assert_eq!(data, crate::fs::read(dest_path).unwrap());
+    crate::fs::remove_file(dest_path).unwrap();
+}
@@ -1,2 +1,7 @@
 pub mod epoll;
+pub mod eventfd;
 pub mod get_pass;
+pub mod landlock;
+#[cfg(feature = "alloc")]
+pub mod seccomp;
+pub mod signalfd;
@@ -0,0 +1,148 @@
+use rusl::error::Errno;
+use rusl::platform::EventFdFlags;
+
+use crate::error::{Error, Result};
+use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
+
+/// A kernel-side counter that becomes readable when it's non-zero.
+/// It's `Send + Sync`, so it can be shared between threads, which makes it a good fit
+/// for waking up an `EpollDriver::wait` from another thread, see [`EventFd::waker`].
+pub struct EventFd(OwnedFd);
+
+impl EventFd {
+    /// Create a new `EventFd` with its counter starting at `initial`.
+    /// If `semaphore`, each read decrements the counter by one instead of resetting it.
+    /// If `nonblocking`, reading a zero counter returns an `EAGAIN` error instead of waiting.
+    /// # Errors
+    /// Os errors creating the fd
+    pub fn new(initial: u32, semaphore: bool, nonblocking: bool) -> Result<Self> {
+        let mut flags = EventFdFlags::EFD_CLOEXEC;
+        if semaphore {
+            flags |= EventFdFlags::EFD_SEMAPHORE;
+        }
+        if nonblocking {
+            flags |= EventFdFlags::EFD_NONBLOCK;
+        }
+        let fd = rusl::unistd::eventfd(initial, flags)?;
+        Ok(Self(OwnedFd(fd)))
+    }
+
+    /// Create a nonblocking `EventFd` meant for waking up a thread waiting on it in an
+    /// `EpollDriver`, `notify` from any thread, and `drain` when woken.
+    /// # Errors
+    /// Os errors creating the fd
+    #[inline]
+    pub fn waker() -> Result<Self> {
+        Self::new(0, false, true)
+    }
+
+    /// Add `value` to the counter.
+    /// # Errors
+    /// Os errors writing, if nonblocking, `EAGAIN` if the counter would overflow
+    pub fn add(&self, value: u64) -> Result<()> {
+        let written = rusl::unistd::write(self.0 .0, &value.to_ne_bytes())?;
+        if written != core::mem::size_of::<u64>() {
+            return Err(Error::no_code("Partial write to an eventfd"));
+        }
+        Ok(())
+    }
+
+    /// Increment the counter, making the fd readable.
+    /// A counter that is already saturated is readable already, so that's not
+    /// considered an error.
+    /// # Errors
+    /// Os errors writing
+    pub fn notify(&self) -> Result<()> {
+        match self.add(1) {
+            Err(e) if e.matches_errno(Errno::EAGAIN) => Ok(()),
+            res => res,
+        }
+    }
+
+    /// Read the counter, resetting it, or decrementing it by one if it's a semaphore.
+    /// Waits for the counter to become non-zero if blocking.
+    /// # Errors
+    /// Os errors reading, if nonblocking, `EAGAIN` if the counter is zero.
+    pub fn read(&self) -> Result<u64> {
+        let mut buf = [0u8; 8];
+        let read = rusl::unistd::read(self.0 .0, &mut buf)?;
+        if read != buf.len() {
+            return Err(Error::no_code("Partial read from an eventfd"));
+        }
+        Ok(u64::from_ne_bytes(buf))
+    }
+
+    /// Read the counter if it's non-zero, returning zero instead of an error if it isn't
+    /// and the `EventFd` is nonblocking.
+    /// # Errors
+    /// Os errors reading
+    pub fn drain(&self) -> Result<u64> {
+        match self.read() {
+            Err(e) if e.matches_errno(Errno::EAGAIN) => Ok(0),
+            res => res,
+        }
+    }
+}
+
+impl AsRawFd for EventFd {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::linux::epoll::{EpollDriver, EpollEvent, EpollEventMask, EpollTimeout};
+
+    #[test]
+    fn notify_and_drain() {
+        let efd = EventFd::waker().unwrap();
+        assert_eq!(0, efd.drain().unwrap());
+        efd.notify().unwrap();
+        efd.notify().unwrap();
+        assert_eq!(2, efd.drain().unwrap());
+        assert_eq!(0, efd.drain().unwrap());
+    }
+
+    #[test]
+    fn semaphore_reads_one() {
+        let efd = EventFd::new(2, true, true).unwrap();
+        assert_eq!(1, efd.read().unwrap());
+        assert_eq!(1, efd.read().unwrap());
+        assert!(efd.read().unwrap_err().matches_errno(Errno::EAGAIN));
+    }
+
+    #[test]
+    fn notify_saturated_is_ok() {
+        let efd = EventFd::waker().unwrap();
+        efd.add(u64::MAX - 1).unwrap();
+        assert!(efd.add(1).unwrap_err().matches_errno(Errno::EAGAIN));
+        efd.notify().unwrap();
+        assert_eq!(u64::MAX - 1, efd.drain().unwrap());
+    }
+
+    #[test]
+    fn wakes_epoll_from_other_thread() {
+        let efd = Arc::new(EventFd::waker().unwrap());
+        let driver = EpollDriver::create(true).unwrap();
+        driver
+            .register(efd.as_raw_fd(), 11, EpollEventMask::EPOLLIN)
+            .unwrap();
+        let efd_c = efd.clone();
+        let handle = std::thread::spawn(move || {
+            efd_c.notify().unwrap();
+        });
+        let mut events = [EpollEvent::new(0, EpollEventMask::empty())];
+        let ready = driver
+            .wait(&mut events, EpollTimeout::WaitMillis(5_000))
+            .unwrap();
+        assert_eq!(1, ready);
+        assert_eq!(11, events[0].get_data());
+        assert_eq!(1, efd.drain().unwrap());
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+use rusl::error::Errno;
+pub use rusl::platform::LandlockAccessFs;
+use rusl::platform::{
+    LandlockPathBeneathAttr, LandlockRulesetAttr, Mode, OpenFlags, StatxFlags, StatxMask,
+};
+use rusl::process::{LandlockRule, PrctlOption};
+use rusl::string::unix_str::UnixStr;
+
+use crate::error::Result;
+use crate::unix::fd::OwnedFd;
+
+/// How much of the requested restrictions ended up being enforced
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LandlockStatus {
+    /// Every requested right is handled
+    FullyEnforced,
+    /// Some requested rights aren't supported by the kernel's landlock ABI, and are allowed
+    PartiallyEnforced,
+    /// Landlock isn't supported or is disabled, nothing is restricted
+    NotEnforced,
+}
+
+/// A landlock ruleset, restricting filesystem access to paths that are explicitly allowed.
+/// Rights that the running kernel doesn't support are dropped instead of causing errors,
+/// and if landlock isn't available at all every operation is a no-op, check the
+/// [`LandlockStatus`] returned from `restrict_self` if that matters.
+/// Rules are added when calling `allow_path`, so restricting only takes a couple of
+/// syscalls, which makes it suitable to move into a `Command::pre_exec` closure.
+#[derive(Debug)]
+pub struct Landlock {
+    ruleset: Option<OwnedFd>,
+    abi: u32,
+    requested: LandlockAccessFs,
+    handled: LandlockAccessFs,
+}
+
+impl Landlock {
+    /// Create a ruleset that denies the `handled` rights everywhere except beneath allowed paths
+    /// # Errors
+    /// Os errors creating the ruleset
+    pub fn new(handled: LandlockAccessFs) -> Result<Self> {
+        let abi = match rusl::process::landlock_abi_version() {
+            Ok(abi) => abi,
+            Err(e) if matches!(e.code, Some(Errno::ENOSYS | Errno::EOPNOTSUPP)) => 0,
+            Err(e) => return Err(e.into()),
+        };
+        let supported = handled & LandlockAccessFs::supported_by_abi(abi);
+        if supported == LandlockAccessFs::empty() {
+            return Ok(Self {
+                ruleset: None,
+                abi,
+                requested: handled,
+                handled: supported,
+            });
+        }
+        let attr = LandlockRulesetAttr {
+            handled_access_fs: supported,
+            ..LandlockRulesetAttr::default()
+        };
+        let ruleset = OwnedFd(rusl::process::landlock_create_ruleset(&attr)?);
+        Ok(Self {
+            ruleset: Some(ruleset),
+            abi,
+            requested: handled,
+            handled: supported,
+        })
+    }
+
+    /// The kernel's landlock ABI version, 0 if unsupported
+    #[inline]
+    #[must_use]
+    pub fn abi_version(&self) -> u32 {
+        self.abi
+    }
+
+    /// The rights that are actually restricted
+    #[inline]
+    #[must_use]
+    pub fn handled(&self) -> LandlockAccessFs {
+        self.handled
+    }
+
+    /// Allow `access` on the file at `path`, or everything beneath it if it's a directory.
+    /// Unsupported rights, and rights that only apply to directories if `path` is a file,
+    /// are dropped.
+    /// # Errors
+    /// Os errors opening `path` or adding the rule
+    pub fn allow_path(&mut self, path: &UnixStr, access: LandlockAccessFs) -> Result<&mut Self> {
+        let Some(ruleset) = &self.ruleset else {
+            return Ok(self);
+        };
+        let fd = OwnedFd(rusl::unistd::open(
+            path,
+            OpenFlags::O_PATH | OpenFlags::O_CLOEXEC,
+        )?);
+        let stat = rusl::unistd::statx(
+            Some(fd.0),
+            UnixStr::EMPTY,
+            StatxFlags::AT_EMPTY_PATH,
+            StatxMask::STATX_TYPE,
+        )?;
+        let mut allowed = access & self.handled;
+        if stat.mode() & Mode::S_IFMT != Mode::S_IFDIR {
+            allowed &= LandlockAccessFs::FILE_ACCESS;
+        }
+        if allowed == LandlockAccessFs::empty() {
+            return Ok(self);
+        }
+        let attr = LandlockPathBeneathAttr {
+            allowed_access: allowed,
+            parent_fd: fd.0.value(),
+        };
+        rusl::process::landlock_add_rule(ruleset.0, LandlockRule::PathBeneath(&attr))?;
+        Ok(self)
+    }
+
+    /// Set `no_new_privs` and restrict the calling thread, restrictions are inherited
+    /// by threads and processes spawned from it afterward and can't be lifted.
+    /// # Errors
+    /// Os errors setting `no_new_privs` or enforcing the ruleset
+    pub fn restrict_self(&self) -> Result<LandlockStatus> {
+        let Some(ruleset) = &self.ruleset else {
+            return Ok(LandlockStatus::NotEnforced);
+        };
+        rusl::process::prctl(PrctlOption::SetNoNewPrivs)?;
+        rusl::process::landlock_restrict_self(ruleset.0)?;
+        if self.handled == self.requested {
+            Ok(LandlockStatus::FullyEnforced)
+        } else {
+            Ok(LandlockStatus::PartiallyEnforced)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusl::platform::WaitPidFlags;
+    use rusl::unix_lit;
+
+    use super::*;
+
+    #[test]
+    fn restrict_reads_in_child() {
+        let mut landlock = Landlock::new(
+            LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_FILE
+                | LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_DIR,
+        )
+        .unwrap();
+        // Directory rights are dropped for files
+        landlock
+            .allow_path(
+                unix_lit!("test-files/fs/test1.txt"),
+                LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_FILE
+                    | LandlockAccessFs::LANDLOCK_ACCESS_FS_READ_DIR,
+            )
+            .unwrap();
+        let enforced = landlock.abi_version() > 0;
+        let child = unsafe { rusl::process::fork().unwrap() };
+        if child == 0 {
+            let mut code = 0;
+            let expect = if enforced {
+                LandlockStatus::FullyEnforced
+            } else {
+                LandlockStatus::NotEnforced
+            };
+            if landlock.restrict_self().ok() != Some(expect) {
+                code |= 1;
+            }
+            if crate::fs::File::open(unix_lit!("test-files/fs/test1.txt")).is_err() {
+                code |= 2;
+            }
+            let other = crate::fs::File::open(unix_lit!("Cargo.toml"));
+            if enforced != other.is_err() {
+                code |= 4;
+            }
+            rusl::process::exit(code);
+        }
+        let res = rusl::process::wait_pid(child, WaitPidFlags::empty()).unwrap();
+        assert_eq!(0, res.status);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn restrict_writes_before_exec() {
+        let path = unix_lit!("test-files/fs/landlock_out.txt");
+        let landlock = Landlock::new(
+            LandlockAccessFs::LANDLOCK_ACCESS_FS_WRITE_FILE
+                | LandlockAccessFs::LANDLOCK_ACCESS_FS_MAKE_REG,
+        )
+        .unwrap();
+        if landlock.abi_version() == 0 {
+            return;
+        }
+        let mut cmd = crate::process::Command::new(unix_lit!("/bin/sh")).unwrap();
+        cmd.arg(unix_lit!("-c"))
+            .arg(unix_lit!("echo out > test-files/fs/landlock_out.txt"))
+            .stderr(crate::process::Stdio::Null);
+        unsafe {
+            cmd.pre_exec(move || landlock.restrict_self().map(|_| ()));
+        }
+        assert_ne!(0, cmd.spawn().unwrap().wait().unwrap());
+        assert!(!crate::fs::exists(path).unwrap());
+    }
+}
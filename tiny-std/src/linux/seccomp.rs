@@ -0,0 +1,489 @@
+use alloc::vec::Vec;
+
+use rusl::error::Errno;
+use rusl::platform::{
+    SeccompFlags, SockFilter, SockFprog, AUDIT_ARCH_NATIVE, BPF_ABS, BPF_ALU, BPF_AND, BPF_JEQ,
+    BPF_JGE, BPF_JGT, BPF_JMP, BPF_K, BPF_LD, BPF_MAXINSNS, BPF_RET, BPF_W,
+    SECCOMP_DATA_ARCH_OFFSET, SECCOMP_DATA_ARGS_OFFSET, SECCOMP_DATA_NR_OFFSET, SECCOMP_RET_ALLOW,
+    SECCOMP_RET_DATA, SECCOMP_RET_ERRNO, SECCOMP_RET_KILL_PROCESS, SECCOMP_RET_KILL_THREAD,
+    SECCOMP_RET_LOG, SECCOMP_RET_TRAP,
+};
+use rusl::process::PrctlOption;
+
+use crate::error::{Error, Result};
+
+/// What happens when a syscall matches a rule
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SeccompAction {
+    /// Let the syscall through
+    Allow,
+    /// Let the syscall through, but log it
+    Log,
+    /// Fail the syscall with the provided error, without executing it
+    Errno(Errno),
+    /// Send `SIGSYS` to the thread
+    Trap,
+    /// Kill the calling thread
+    KillThread,
+    /// Kill the whole process
+    KillProcess,
+}
+
+impl SeccompAction {
+    #[expect(clippy::cast_sign_loss)]
+    fn into_raw(self) -> u32 {
+        match self {
+            SeccompAction::Allow => SECCOMP_RET_ALLOW,
+            SeccompAction::Log => SECCOMP_RET_LOG,
+            SeccompAction::Errno(errno) => {
+                SECCOMP_RET_ERRNO | (errno.raw() as u32 & SECCOMP_RET_DATA)
+            }
+            SeccompAction::Trap => SECCOMP_RET_TRAP,
+            SeccompAction::KillThread => SECCOMP_RET_KILL_THREAD,
+            SeccompAction::KillProcess => SECCOMP_RET_KILL_PROCESS,
+        }
+    }
+}
+
+/// A comparison of a full 64-bit syscall argument, comparisons are unsigned
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ArgCmp {
+    Eq(u64),
+    Ne(u64),
+    /// `arg & mask == value`, useful for flag arguments
+    MaskedEq {
+        mask: u64,
+        value: u64,
+    },
+    Gt(u64),
+    Ge(u64),
+    Lt(u64),
+    Le(u64),
+}
+
+/// A comparison of the syscall argument at `index`, where `0` is the first argument
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ArgCondition {
+    index: u8,
+    cmp: ArgCmp,
+}
+
+impl ArgCondition {
+    #[inline]
+    #[must_use]
+    pub const fn new(index: u8, cmp: ArgCmp) -> Self {
+        Self { index, cmp }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SeccompRule {
+    nr: usize,
+    conditions: Vec<ArgCondition>,
+    action: SeccompAction,
+}
+
+/// Builds a seccomp filter from a list of rules keyed on syscall numbers,
+/// as found in `sc::nr`.
+/// Rules are checked in the order they were added, and the first one that matches decides
+/// the action, if none match the default action is taken.
+/// Syscalls made with a different architecture than the one compiled for, such as the
+/// `x32` ABI on `x86_64`, always kill the process, since their syscall numbers differ.
+#[derive(Debug, Clone)]
+pub struct SeccompFilterBuilder {
+    default: SeccompAction,
+    rules: Vec<SeccompRule>,
+}
+
+impl SeccompFilterBuilder {
+    #[must_use]
+    pub fn new(default: SeccompAction) -> Self {
+        Self {
+            default,
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn allow(&mut self, nr: usize) -> &mut Self {
+        self.rule(nr, &[], SeccompAction::Allow)
+    }
+
+    /// Kill the process if `nr` is called
+    pub fn deny(&mut self, nr: usize) -> &mut Self {
+        self.rule(nr, &[], SeccompAction::KillProcess)
+    }
+
+    pub fn errno(&mut self, nr: usize, errno: Errno) -> &mut Self {
+        self.rule(nr, &[], SeccompAction::Errno(errno))
+    }
+
+    pub fn trap(&mut self, nr: usize) -> &mut Self {
+        self.rule(nr, &[], SeccompAction::Trap)
+    }
+
+    /// Take `action` if `nr` is called and all `conditions` hold
+    pub fn rule(
+        &mut self,
+        nr: usize,
+        conditions: &[ArgCondition],
+        action: SeccompAction,
+    ) -> &mut Self {
+        self.rules.push(SeccompRule {
+            nr,
+            conditions: conditions.to_vec(),
+            action,
+        });
+        self
+    }
+
+    /// Compile the rules into a BPF program
+    /// # Errors
+    /// An argument index above 5, a syscall number that doesn't fit a `u32`,
+    /// or more rules than fit into a program
+    pub fn build(&self) -> Result<SeccompFilter> {
+        let mut prog = Vec::new();
+        prog.push(load(SECCOMP_DATA_ARCH_OFFSET));
+        prog.push(SockFilter::jump(
+            BPF_JMP | BPF_JEQ | BPF_K,
+            AUDIT_ARCH_NATIVE,
+            1,
+            0,
+        ));
+        prog.push(ret(SECCOMP_RET_KILL_PROCESS));
+        #[cfg(target_arch = "x86_64")]
+        {
+            prog.push(load(SECCOMP_DATA_NR_OFFSET));
+            prog.push(SockFilter::jump(
+                BPF_JMP | BPF_JGE | BPF_K,
+                rusl::platform::X32_SYSCALL_BIT,
+                0,
+                1,
+            ));
+            prog.push(ret(SECCOMP_RET_KILL_PROCESS));
+        }
+        for rule in &self.rules {
+            compile_rule(rule, &mut prog)?;
+        }
+        prog.push(ret(self.default.into_raw()));
+        if prog.len() > BPF_MAXINSNS {
+            return Err(Error::no_code("Seccomp filter has too many instructions"));
+        }
+        Ok(SeccompFilter(prog))
+    }
+}
+
+/// A jump target in a rule block, either relative or to the end of the block,
+/// which is where the next rule starts
+#[derive(Copy, Clone)]
+enum Target {
+    Skip(u8),
+    NextRule,
+}
+
+struct BlockInsn {
+    code: u16,
+    k: u32,
+    jt: Target,
+    jf: Target,
+}
+
+impl BlockInsn {
+    const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            k,
+            jt: Target::Skip(0),
+            jf: Target::Skip(0),
+        }
+    }
+
+    const fn jump(code: u16, k: u32, jt: Target, jf: Target) -> Self {
+        Self { code, k, jt, jf }
+    }
+}
+
+fn compile_rule(rule: &SeccompRule, prog: &mut Vec<SockFilter>) -> Result<()> {
+    use Target::{NextRule, Skip};
+    let nr =
+        u32::try_from(rule.nr).map_err(|_| Error::no_code("Syscall number doesn't fit a u32"))?;
+    let mut block = Vec::new();
+    block.push(BlockInsn::stmt(
+        BPF_LD | BPF_W | BPF_ABS,
+        SECCOMP_DATA_NR_OFFSET,
+    ));
+    block.push(BlockInsn::jump(
+        BPF_JMP | BPF_JEQ | BPF_K,
+        nr,
+        Skip(0),
+        NextRule,
+    ));
+    for cond in &rule.conditions {
+        if cond.index > 5 {
+            return Err(Error::no_code(
+                "Seccomp argument index out of range, syscalls take at most 6 arguments",
+            ));
+        }
+        // Arguments are 64 bits but BPF loads 32, both supported arches are little endian
+        let lo_offset = SECCOMP_DATA_ARGS_OFFSET + u32::from(cond.index) * 8;
+        let hi_offset = lo_offset + 4;
+        let ld_lo = BlockInsn::stmt(BPF_LD | BPF_W | BPF_ABS, lo_offset);
+        let ld_hi = BlockInsn::stmt(BPF_LD | BPF_W | BPF_ABS, hi_offset);
+        let jeq = |k, jt, jf| BlockInsn::jump(BPF_JMP | BPF_JEQ | BPF_K, k, jt, jf);
+        let jgt = |k, jt, jf| BlockInsn::jump(BPF_JMP | BPF_JGT | BPF_K, k, jt, jf);
+        let jge = |k, jt, jf| BlockInsn::jump(BPF_JMP | BPF_JGE | BPF_K, k, jt, jf);
+        match cond.cmp {
+            ArgCmp::Eq(v) => block.extend([
+                ld_hi,
+                jeq(hi(v), Skip(0), NextRule),
+                ld_lo,
+                jeq(lo(v), Skip(0), NextRule),
+            ]),
+            ArgCmp::Ne(v) => block.extend([
+                ld_hi,
+                // High differs, passes
+                jeq(hi(v), Skip(0), Skip(2)),
+                ld_lo,
+                jeq(lo(v), NextRule, Skip(0)),
+            ]),
+            ArgCmp::MaskedEq { mask, value } => block.extend([
+                ld_hi,
+                BlockInsn::stmt(BPF_ALU | BPF_AND | BPF_K, hi(mask)),
+                jeq(hi(value & mask), Skip(0), NextRule),
+                ld_lo,
+                BlockInsn::stmt(BPF_ALU | BPF_AND | BPF_K, lo(mask)),
+                jeq(lo(value & mask), Skip(0), NextRule),
+            ]),
+            ArgCmp::Gt(v) => block.extend([
+                ld_hi,
+                // High greater, passes
+                jgt(hi(v), Skip(3), Skip(0)),
+                jeq(hi(v), Skip(0), NextRule),
+                ld_lo,
+                jgt(lo(v), Skip(0), NextRule),
+            ]),
+            ArgCmp::Ge(v) => block.extend([
+                ld_hi,
+                jgt(hi(v), Skip(3), Skip(0)),
+                jeq(hi(v), Skip(0), NextRule),
+                ld_lo,
+                jge(lo(v), Skip(0), NextRule),
+            ]),
+            ArgCmp::Lt(v) => block.extend([
+                ld_hi,
+                jgt(hi(v), NextRule, Skip(0)),
+                // High less, passes
+                jeq(hi(v), Skip(0), Skip(2)),
+                ld_lo,
+                jge(lo(v), NextRule, Skip(0)),
+            ]),
+            ArgCmp::Le(v) => block.extend([
+                ld_hi,
+                jgt(hi(v), NextRule, Skip(0)),
+                jeq(hi(v), Skip(0), Skip(2)),
+                ld_lo,
+                jgt(lo(v), NextRule, Skip(0)),
+            ]),
+        }
+    }
+    block.push(BlockInsn::stmt(BPF_RET | BPF_K, rule.action.into_raw()));
+    let len = block.len();
+    for (ind, insn) in block.into_iter().enumerate() {
+        let resolve = |target| match target {
+            Skip(n) => Ok(n),
+            NextRule => u8::try_from(len - ind - 1)
+                .map_err(|_| Error::no_code("Seccomp rule has too many conditions")),
+        };
+        prog.push(SockFilter::jump(
+            insn.code,
+            insn.k,
+            resolve(insn.jt)?,
+            resolve(insn.jf)?,
+        ));
+    }
+    Ok(())
+}
+
+#[inline]
+const fn load(offset: u32) -> SockFilter {
+    SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, offset)
+}
+
+#[inline]
+const fn ret(action: u32) -> SockFilter {
+    SockFilter::stmt(BPF_RET | BPF_K, action)
+}
+
+#[inline]
+#[expect(clippy::cast_possible_truncation)]
+const fn lo(v: u64) -> u32 {
+    v as u32
+}
+
+#[inline]
+const fn hi(v: u64) -> u32 {
+    lo(v >> 32)
+}
+
+/// A compiled seccomp BPF program.
+/// To sandbox a child, clone it into a `Command::pre_exec` closure that calls `install`.
+#[derive(Debug, Clone)]
+pub struct SeccompFilter(Vec<SockFilter>);
+
+impl SeccompFilter {
+    #[inline]
+    #[must_use]
+    pub fn instructions(&self) -> &[SockFilter] {
+        &self.0
+    }
+
+    /// Set `no_new_privs` and install this filter on the calling thread, it's inherited by
+    /// threads and processes spawned from it afterward and can't be removed.
+    /// # Errors
+    /// Os errors setting `no_new_privs` or installing the filter
+    pub fn install(&self) -> Result<()> {
+        self.install_with_flags(SeccompFlags::empty())
+    }
+
+    /// Like `install`, but also applies the filter to all other threads of the process
+    /// # Errors
+    /// Os errors setting `no_new_privs` or installing the filter, such as another thread
+    /// already having a diverging filter
+    pub fn install_all_threads(&self) -> Result<()> {
+        self.install_with_flags(
+            SeccompFlags::SECCOMP_FILTER_FLAG_TSYNC | SeccompFlags::SECCOMP_FILTER_FLAG_TSYNC_ESRCH,
+        )
+    }
+
+    fn install_with_flags(&self, flags: SeccompFlags) -> Result<()> {
+        // Checked on build
+        let prog = SockFprog::new(&self.0)
+            .ok_or_else(|| Error::no_code("Seccomp filter has too many instructions"))?;
+        rusl::process::prctl(PrctlOption::SetNoNewPrivs)?;
+        rusl::process::seccomp_set_mode_filter(&prog, flags)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusl::error::Errno;
+    use rusl::platform::{OpenFlags, WaitPidFlags};
+    use rusl::unistd::Whence;
+    use rusl::unix_lit;
+
+    use super::*;
+    use crate::process::Command;
+
+    #[test]
+    fn build_rejects_bad_args() {
+        let mut builder = SeccompFilterBuilder::new(SeccompAction::Allow);
+        builder.rule(
+            sc::nr::READ,
+            &[ArgCondition::new(6, ArgCmp::Eq(0))],
+            SeccompAction::KillProcess,
+        );
+        assert!(builder.build().is_err());
+        let filter = SeccompFilterBuilder::new(SeccompAction::Allow)
+            .allow(sc::nr::READ)
+            .build()
+            .unwrap();
+        assert_eq!(
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW),
+            *filter.instructions().last().unwrap()
+        );
+    }
+
+    #[test]
+    fn filter_applies_arg_conditions() {
+        let filter = SeccompFilterBuilder::new(SeccompAction::Allow)
+            .errno(sc::nr::GETUID, Errno::EPERM)
+            .rule(
+                sc::nr::LSEEK,
+                &[
+                    ArgCondition::new(1, ArgCmp::Gt(u64::from(u32::MAX))),
+                    ArgCondition::new(2, ArgCmp::Eq(0)),
+                ],
+                SeccompAction::Errno(Errno::ENOSPC),
+            )
+            .rule(
+                sc::nr::LSEEK,
+                &[ArgCondition::new(1, ArgCmp::Lt(3))],
+                SeccompAction::Errno(Errno::EDOM),
+            )
+            .rule(
+                sc::nr::FCNTL,
+                &[ArgCondition::new(
+                    1,
+                    ArgCmp::MaskedEq {
+                        mask: 0xff,
+                        value: 3,
+                    },
+                )],
+                SeccompAction::Errno(Errno::EACCES),
+            )
+            .build()
+            .unwrap();
+        let fd = rusl::unistd::open(
+            unix_lit!("test-files/fs/test1.txt"),
+            OpenFlags::O_RDONLY | OpenFlags::O_CLOEXEC,
+        )
+        .unwrap();
+        // Filters can't be removed, install in a child
+        let child = unsafe { rusl::process::fork().unwrap() };
+        if child == 0 {
+            let mut code = 0;
+            if filter.install().is_err() {
+                rusl::process::exit(100);
+            }
+            let expect = |res: core::result::Result<(), rusl::Error>, errno| {
+                res.err().and_then(|e| e.code) == errno
+            };
+            if !expect(rusl::unistd::get_uid().map(|_| ()), Some(Errno::EPERM)) {
+                code |= 1;
+            }
+            if !expect(
+                rusl::unistd::lseek(fd, 1 << 33, Whence::SET).map(|_| ()),
+                Some(Errno::ENOSPC),
+            ) {
+                code |= 2;
+            }
+            if !expect(
+                rusl::unistd::lseek(fd, 1, Whence::SET).map(|_| ()),
+                Some(Errno::EDOM),
+            ) {
+                code |= 4;
+            }
+            // Not matching any lseek rule
+            if !expect(rusl::unistd::lseek(fd, 4, Whence::SET).map(|_| ()), None) {
+                code |= 8;
+            }
+            // Uses F_GETFL, which is 3
+            if !expect(
+                rusl::unistd::fcntl_get_file_status(fd).map(|_| ()),
+                Some(Errno::EACCES),
+            ) {
+                code |= 16;
+            }
+            rusl::process::exit(code);
+        }
+        let res = rusl::process::wait_pid(child, WaitPidFlags::empty()).unwrap();
+        rusl::unistd::close(fd).unwrap();
+        assert_eq!(0, res.status);
+    }
+
+    #[test]
+    fn pre_exec_filter_blocks_exec() {
+        let filter = SeccompFilterBuilder::new(SeccompAction::Allow)
+            .errno(sc::nr::EXECVE, Errno::EACCES)
+            .build()
+            .unwrap();
+        let mut cmd = Command::new(unix_lit!("/bin/true")).unwrap();
+        unsafe {
+            cmd.pre_exec(move || filter.install());
+        }
+        let Err(e) = cmd.spawn() else {
+            panic!("Expected exec to be blocked");
+        };
+        assert!(e.matches_errno(Errno::EACCES));
+    }
+}
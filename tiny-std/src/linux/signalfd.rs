@@ -0,0 +1,141 @@
+pub use rusl::platform::{SigSetT, SignalFdSigInfo, SignalKind};
+use rusl::platform::{SignalFdFlags, SigprocmaskHow};
+
+use crate::error::{Error, Result};
+use crate::io::Read;
+use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
+
+/// A file descriptor that signals can be read from, instead of handling them with a
+/// signal handler.
+/// The signals in the mask are blocked for the calling thread on creation, signals are
+/// process-wide, so to make sure that they arrive here they need to be blocked in every thread,
+/// the easiest way to accomplish that is to create this before spawning any threads,
+/// since new threads inherit the signal mask.
+/// Dropping it doesn't unblock the signals.
+pub struct SignalFd {
+    fd: OwnedFd,
+    mask: SigSetT,
+}
+
+impl SignalFd {
+    /// Blocks the signals in `mask` and creates a `SignalFd` that reads them.
+    /// If `nonblocking`, reads return an `EAGAIN` error instead of waiting when no
+    /// signal is pending, which is what's wanted when registering it with an `EpollDriver`.
+    /// # Errors
+    /// Os errors blocking the signals or creating the fd
+    pub fn new(mask: &SigSetT, nonblocking: bool) -> Result<Self> {
+        rusl::process::sigprocmask(SigprocmaskHow::Block, Some(mask))?;
+        let flags = if nonblocking {
+            SignalFdFlags::SFD_CLOEXEC | SignalFdFlags::SFD_NONBLOCK
+        } else {
+            SignalFdFlags::SFD_CLOEXEC
+        };
+        let fd = rusl::process::signalfd(None, mask, flags)?;
+        Ok(Self {
+            fd: OwnedFd(fd),
+            mask: *mask,
+        })
+    }
+
+    /// The signals that this `SignalFd` reads
+    #[inline]
+    #[must_use]
+    pub fn mask(&self) -> &SigSetT {
+        &self.mask
+    }
+
+    /// Replaces the signals that this `SignalFd` reads, the new signals are blocked,
+    /// previously blocked signals are left as is.
+    /// # Errors
+    /// Os errors blocking the signals or updating the fd
+    pub fn set_mask(&mut self, mask: &SigSetT) -> Result<()> {
+        rusl::process::sigprocmask(SigprocmaskHow::Block, Some(mask))?;
+        rusl::process::signalfd(Some(self.fd.0), mask, SignalFdFlags::empty())?;
+        self.mask = *mask;
+        Ok(())
+    }
+
+    /// Read a single signal.
+    /// # Errors
+    /// Os errors reading, if nonblocking, `EAGAIN` if there's no pending signal
+    pub fn read_signal(&mut self) -> Result<SignalFdSigInfo> {
+        let mut info = [SignalFdSigInfo::new_zeroed()];
+        self.read_signals(&mut info)?;
+        Ok(info[0])
+    }
+
+    /// Read as many pending signals as fits into `buf`, returning the number read.
+    /// # Errors
+    /// Os errors reading, if nonblocking, `EAGAIN` if there's no pending signal
+    pub fn read_signals(&mut self, buf: &mut [SignalFdSigInfo]) -> Result<usize> {
+        // Safety: `SignalFdSigInfo` is plain old data, any bytes the kernel writes are valid
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(
+                buf.as_mut_ptr().cast::<u8>(),
+                core::mem::size_of_val(buf),
+            )
+        };
+        let read = self.read(bytes)?;
+        if read % SignalFdSigInfo::SIZE != 0 {
+            return Err(Error::no_code(
+                "Got a partial `signalfd_siginfo` from a signalfd",
+            ));
+        }
+        Ok(read / SignalFdSigInfo::SIZE)
+    }
+}
+
+impl AsRawFd for SignalFd {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+/// Reads raw `signalfd_siginfo` structs, the buffer needs to fit at least one of them.
+impl Read for SignalFd {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        Ok(rusl::unistd::read(self.fd.0, buf)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusl::error::Errno;
+
+    use super::*;
+    use crate::linux::epoll::{EpollDriver, EpollEvent, EpollEventMask, EpollTimeout};
+
+    fn raise_on_current_thread(signal: SignalKind) {
+        unsafe {
+            let tid = sc::syscall!(GETTID);
+            let res = sc::syscall!(TGKILL, rusl::process::get_pid(), tid, signal.value());
+            assert_eq!(0, res);
+        }
+    }
+
+    #[test]
+    fn signalfd_readable_through_epoll() {
+        let mut mask = SigSetT::new();
+        mask.add(SignalKind::SIGUSR1);
+        let old = rusl::process::sigprocmask(SigprocmaskHow::Block, None).unwrap();
+        let mut sfd = SignalFd::new(&mask, true).unwrap();
+        let err = sfd.read_signal().unwrap_err();
+        assert!(err.matches_errno(Errno::EAGAIN));
+        let driver = EpollDriver::create(true).unwrap();
+        driver
+            .register(sfd.as_raw_fd(), 7, EpollEventMask::EPOLLIN)
+            .unwrap();
+        raise_on_current_thread(SignalKind::SIGUSR1);
+        let mut events = [EpollEvent::new(0, EpollEventMask::empty())];
+        let ready = driver
+            .wait(&mut events, EpollTimeout::WaitMillis(1_000))
+            .unwrap();
+        assert_eq!(1, ready);
+        assert_eq!(7, events[0].get_data());
+        let info = sfd.read_signal().unwrap();
+        assert_eq!(SignalKind::SIGUSR1.value(), info.signal().unwrap().value());
+        rusl::process::sigprocmask(SigprocmaskHow::SetMask, Some(&old)).unwrap();
+    }
+}
@@ -1,23 +1,30 @@
 use core::time::Duration;
 
 use rusl::error::Errno;
-use rusl::network::get_inet_sock_name;
+use rusl::network::get_ip_sock_name;
 use rusl::platform::{
-    AddressFamily, NonNegativeI32, PollEvents, SocketAddressInet, SocketAddressUnix, SocketFlags,
-    SocketOptions, SocketType,
+    AddressFamily, MsgFlags, NonNegativeI32, PollEvents, SocketAddressInet, SocketAddressInet6,
+    SocketAddressIp, SocketAddressUnix, SocketFlags, SocketOptions, SocketType,
 };
+#[cfg(feature = "alloc")]
+use rusl::platform::{ControlMessageSend, MsgHdrBorrow, MSG_CMSG_CLOEXEC};
+pub use rusl::platform::{Shutdown, UCred};
 use rusl::string::unix_str::UnixStr;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::io::{Read, Write};
 use crate::sock::{
     blocking_read_nonblock_sock, blocking_write_nonblock_sock, sock_nonblock_op_poll_if_not_ready,
+    sock_nonblock_op_poll_until_ready,
 };
 use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
 
+mod addr;
 #[cfg(test)]
 mod test;
 
+pub use addr::{Ip, SocketAddress, UnixSocketAddress};
+
 #[derive(Debug)]
 pub struct UnixStream(OwnedFd);
 
@@ -81,6 +88,149 @@ impl UnixStream {
         }
         Ok(Some(Self(OwnedFd(fd))))
     }
+
+    /// Write `buf` to the stream, passing `fds` along with it to the receiving end.
+    /// The fds stay open and owned by the caller, the receiver gets duplicates.
+    /// # Errors
+    /// Os errors relating to sending on the socket
+    #[cfg(feature = "alloc")]
+    pub fn send_with_fds(&mut self, buf: &[u8], fds: &[RawFd]) -> Result<usize> {
+        let io = [rusl::platform::IoSlice::new(buf)];
+        let control = (!fds.is_empty()).then_some(ControlMessageSend::ScmRights(fds));
+        let msg = MsgHdrBorrow::create_send(None, &io, control);
+        sock_nonblock_op_poll_if_not_ready(
+            self.0 .0,
+            Errno::EAGAIN,
+            PollEvents::POLLOUT,
+            None,
+            |sock| rusl::network::sendmsg(sock, &msg, 0),
+        )
+    }
+
+    /// Read from the stream into `buf`, also accepting up to `max_fds` fds passed
+    /// along with the data, any excess fds are closed by the kernel.
+    /// The received fds are owned by the caller and have `O_CLOEXEC` set.
+    /// # Errors
+    /// Os errors relating to reading from the socket
+    #[cfg(feature = "alloc")]
+    pub fn recv_with_fds(
+        &mut self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> Result<(usize, alloc::vec::Vec<RawFd>)> {
+        // Header plus the fds aligned to 8 bytes, same as `CMSG_SPACE`
+        let ctrl_len = 16 + ((max_fds * core::mem::size_of::<RawFd>() + 7) & !7);
+        sock_nonblock_op_poll_if_not_ready(
+            self.0 .0,
+            Errno::EAGAIN,
+            PollEvents::POLLIN,
+            None,
+            |sock| {
+                let mut io = [rusl::platform::IoSliceMut::new(buf)];
+                let mut ctrl = alloc::vec![0u8; ctrl_len];
+                let mut msg =
+                    MsgHdrBorrow::create_recv(&mut io, (max_fds > 0).then_some(&mut ctrl));
+                let read = rusl::network::recvmsg(sock, &mut msg, MSG_CMSG_CLOEXEC)?;
+                let mut fds = alloc::vec::Vec::new();
+                for cmsg in msg.control_messages() {
+                    match cmsg {
+                        ControlMessageSend::ScmRights(received) => fds.extend_from_slice(received),
+                    }
+                }
+                Ok((read, fds))
+            },
+        )
+    }
+
+    /// Get this socket's local address, unnamed unless it was bound
+    /// # Errors
+    /// Various OS errors, most likely os out of resources
+    #[inline]
+    pub fn local_addr(&self) -> Result<UnixSocketAddress> {
+        Ok(UnixSocketAddress(rusl::network::get_unix_sock_name(
+            self.0 .0,
+        )?))
+    }
+
+    /// Get the address of the peer this socket is connected to
+    /// # Errors
+    /// Os errors, such as the socket not being connected
+    #[inline]
+    pub fn peer_addr(&self) -> Result<UnixSocketAddress> {
+        Ok(UnixSocketAddress(rusl::network::get_unix_peer_name(
+            self.0 .0,
+        )?))
+    }
+
+    /// Shut down the read, write, or both halves of this connection. Shutting down writes
+    /// makes the peer read EOF, while this end can keep reading what the peer sends.
+    /// This affects every clone of this stream.
+    /// # Errors
+    /// Os errors, such as the socket not being connected
+    #[inline]
+    pub fn shutdown(&self, how: Shutdown) -> Result<()> {
+        rusl::network::shutdown(self.0 .0, how)?;
+        Ok(())
+    }
+
+    /// Create a new handle to the same socket, so that reads and writes can happen
+    /// from different places. The new fd is close-on-exec
+    /// # Errors
+    /// Os errors, such as running out of fds
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(Self(OwnedFd(rusl::unistd::dup(self.0 .0, true)?)))
+    }
+
+    /// Get the credentials of the process on the other end, as they were when it connected
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn peer_cred(&self) -> Result<UCred> {
+        Ok(rusl::network::get_peer_credentials(self.0 .0)?)
+    }
+
+    /// Set the size of the receive buffer, the kernel doubles it to make room for bookkeeping
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_recv_buffer_size(&self, size: usize) -> Result<()> {
+        rusl::network::set_recv_buffer_size(self.0 .0, size)?;
+        Ok(())
+    }
+
+    /// Get the size of the receive buffer
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn recv_buffer_size(&self) -> Result<usize> {
+        Ok(rusl::network::get_recv_buffer_size(self.0 .0)?)
+    }
+
+    /// Set the size of the send buffer, the kernel doubles it to make room for bookkeeping
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_send_buffer_size(&self, size: usize) -> Result<()> {
+        rusl::network::set_send_buffer_size(self.0 .0, size)?;
+        Ok(())
+    }
+
+    /// Get the size of the send buffer
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn send_buffer_size(&self) -> Result<usize> {
+        Ok(rusl::network::get_send_buffer_size(self.0 .0)?)
+    }
+
+    /// Get and clear the pending error on the socket, if any
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        take_error(self.0 .0)
+    }
 }
 
 impl AsRawFd for UnixStream {
@@ -96,6 +246,13 @@ impl Read for UnixStream {
     }
 }
 
+impl Read for &UnixStream {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        blocking_read_nonblock_sock(self.0 .0, buf, None)
+    }
+}
+
 impl Write for UnixStream {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
@@ -108,6 +265,18 @@ impl Write for UnixStream {
     }
 }
 
+impl Write for &UnixStream {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        blocking_write_nonblock_sock(self.0 .0, buf, None)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
 pub struct UnixListener(OwnedFd);
 
 impl UnixListener {
@@ -186,26 +355,21 @@ impl UnixListener {
 
         Ok(UnixStream(OwnedFd(fd)))
     }
-}
 
-#[derive(Debug, Clone, Copy)]
-pub struct SocketAddress {
-    ip: Ip,
-    port: u16,
-}
-
-impl SocketAddress {
-    #[must_use]
-    pub fn new(ip: Ip, port: u16) -> Self {
-        Self { ip, port }
+    /// Get and clear the pending error on the socket, if any
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        take_error(self.0 .0)
     }
 }
 
-#[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
-pub enum Ip {
-    /// 4 bytes representing the address, i.e. `127.0.0.1`
-    V4([u8; 4]),
+impl AsRawFd for UnixListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0 .0
+    }
 }
 
 #[derive(Debug)]
@@ -218,14 +382,14 @@ pub enum TcpTryConnect {
 }
 
 #[derive(Debug)]
-pub struct TcpStreamInProgress(OwnedFd, SocketAddressInet);
+pub struct TcpStreamInProgress(OwnedFd, SocketAddressIp);
 
 impl TcpStreamInProgress {
     /// Try to continue establishing the connection started on this stream
     /// # Errors
     /// Connection failures
     pub fn try_connect(self) -> Result<TcpTryConnect> {
-        match rusl::network::connect_inet(self.0 .0, &self.1) {
+        match connect_ip(self.0 .0, &self.1) {
             Ok(()) => {}
             Err(e) if matches!(e.code, Some(Errno::EINPROGRESS)) => {
                 return Ok(TcpTryConnect::InProgress(self));
@@ -247,7 +411,7 @@ impl TcpStreamInProgress {
             Errno::EINPROGRESS,
             PollEvents::POLLOUT,
             None,
-            |sock| rusl::network::connect_inet(sock, &self.1),
+            |sock| connect_ip(sock, &self.1),
         )?;
         let Self(o, _addr) = self;
         Ok(TcpStream(o))
@@ -276,18 +440,16 @@ impl TcpStream {
     /// # Errors
     /// Various OS errors relating to permissions, and networking issues
     pub fn try_connect(addr: &SocketAddress) -> Result<TcpTryConnect> {
+        let addr = ip_addr(*addr);
         let fd = rusl::network::socket(
-            AddressFamily::AF_INET,
+            addr.family(),
             SocketOptions::new(
                 SocketType::SOCK_STREAM,
                 SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
             ),
             6,
         )?;
-        let addr = match addr.ip {
-            Ip::V4(bytes) => SocketAddressInet::new(bytes, addr.port),
-        };
-        match rusl::network::connect_inet(fd, &addr) {
+        match connect_ip(fd, &addr) {
             Ok(()) => {}
             Err(e) if matches!(e.code, Some(Errno::EINPROGRESS)) => {
                 return Ok(TcpTryConnect::InProgress(TcpStreamInProgress(
@@ -303,25 +465,22 @@ impl TcpStream {
         Ok(TcpTryConnect::Connected(Self(OwnedFd(fd))))
     }
 
-    #[expect(clippy::trivially_copy_pass_by_ref)]
     fn do_connect(addr: &SocketAddress, timeout: Option<Duration>) -> Result<Self> {
+        let addr = ip_addr(*addr);
         let fd = rusl::network::socket(
-            AddressFamily::AF_INET,
+            addr.family(),
             SocketOptions::new(
                 SocketType::SOCK_STREAM,
                 SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
             ),
             6,
         )?;
-        let addr = match addr.ip {
-            Ip::V4(bytes) => SocketAddressInet::new(bytes, addr.port),
-        };
         if let Err(e) = sock_nonblock_op_poll_if_not_ready(
             fd,
             Errno::EINPROGRESS,
             PollEvents::POLLOUT,
             timeout,
-            |sock| rusl::network::connect_inet(sock, &addr),
+            |sock| connect_ip(sock, &addr),
         ) {
             let _ = rusl::unistd::close(fd);
             return Err(e);
@@ -329,6 +488,42 @@ impl TcpStream {
         Ok(Self(OwnedFd(fd)))
     }
 
+    /// Get this socket's local address
+    /// # Errors
+    /// Various OS errors, most likely os out of resources
+    #[inline]
+    pub fn local_addr(&self) -> Result<SocketAddress> {
+        Ok(socket_address(get_ip_sock_name(self.0 .0)?))
+    }
+
+    /// Get the address of the peer this socket is connected to
+    /// # Errors
+    /// Os errors, such as the socket not being connected
+    #[inline]
+    pub fn peer_addr(&self) -> Result<SocketAddress> {
+        Ok(socket_address(rusl::network::get_ip_peer_name(self.0 .0)?))
+    }
+
+    /// Shut down the read, write, or both halves of this connection. Shutting down writes
+    /// sends a FIN, the peer reads EOF while this end can keep reading what the peer sends.
+    /// This affects every clone of this stream.
+    /// # Errors
+    /// Os errors, such as the socket not being connected
+    #[inline]
+    pub fn shutdown(&self, how: Shutdown) -> Result<()> {
+        rusl::network::shutdown(self.0 .0, how)?;
+        Ok(())
+    }
+
+    /// Create a new handle to the same socket, so that reads and writes can happen
+    /// from different threads. The new fd is close-on-exec
+    /// # Errors
+    /// Os errors, such as running out of fds
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(Self(OwnedFd(rusl::unistd::dup(self.0 .0, true)?)))
+    }
+
     /// Reads from this socket, into the provided buffer, with the specified timeout
     /// # Errors
     /// Os-errors related to reads, or a timeout
@@ -336,6 +531,106 @@ impl TcpStream {
     pub fn read_with_timeout(&mut self, buf: &mut [u8], timeout: Duration) -> Result<usize> {
         blocking_read_nonblock_sock(self.0 .0, buf, Some(timeout))
     }
+
+    /// Disable Nagle's algorithm, sending small writes immediately instead of
+    /// waiting to coalesce them with later ones
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_nodelay(&self, nodelay: bool) -> Result<()> {
+        rusl::network::set_tcp_nodelay(self.0 .0, nodelay)?;
+        Ok(())
+    }
+
+    /// Check if Nagle's algorithm is disabled
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn nodelay(&self) -> Result<bool> {
+        Ok(rusl::network::get_tcp_nodelay(self.0 .0)?)
+    }
+
+    /// Send keepalive probes when the connection is idle, after `idle` if provided,
+    /// otherwise after the system default of 2 hours
+    /// # Errors
+    /// Os errors relating to setting the socket option, `idle` has to be within `1..=32767` seconds
+    pub fn set_keepalive(&self, keepalive: bool, idle: Option<Duration>) -> Result<()> {
+        if let Some(idle) = idle {
+            rusl::network::set_tcp_keepidle(self.0 .0, idle)?;
+        }
+        rusl::network::set_keepalive(self.0 .0, keepalive)?;
+        Ok(())
+    }
+
+    /// Get the idle time before keepalive probes are sent, `None` if keepalive is off
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    pub fn keepalive(&self) -> Result<Option<Duration>> {
+        if !rusl::network::get_keepalive(self.0 .0)? {
+            return Ok(None);
+        }
+        Ok(Some(rusl::network::get_tcp_keepidle(self.0 .0)?))
+    }
+
+    /// With `Some`, dropping the stream blocks until unsent data is sent or the timeout passes,
+    /// a timeout of zero resets the connection instead of shutting it down gracefully
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_linger(&self, linger: Option<Duration>) -> Result<()> {
+        rusl::network::set_linger(self.0 .0, linger)?;
+        Ok(())
+    }
+
+    /// Get the linger timeout, `None` if off
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn linger(&self) -> Result<Option<Duration>> {
+        Ok(rusl::network::get_linger(self.0 .0)?)
+    }
+
+    /// Set the size of the receive buffer, the kernel doubles it to make room for bookkeeping
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_recv_buffer_size(&self, size: usize) -> Result<()> {
+        rusl::network::set_recv_buffer_size(self.0 .0, size)?;
+        Ok(())
+    }
+
+    /// Get the size of the receive buffer
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn recv_buffer_size(&self) -> Result<usize> {
+        Ok(rusl::network::get_recv_buffer_size(self.0 .0)?)
+    }
+
+    /// Set the size of the send buffer, the kernel doubles it to make room for bookkeeping
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_send_buffer_size(&self, size: usize) -> Result<()> {
+        rusl::network::set_send_buffer_size(self.0 .0, size)?;
+        Ok(())
+    }
+
+    /// Get the size of the send buffer
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn send_buffer_size(&self) -> Result<usize> {
+        Ok(rusl::network::get_send_buffer_size(self.0 .0)?)
+    }
+
+    /// Get and clear the pending error on the socket, if any
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        take_error(self.0 .0)
+    }
 }
 
 impl AsRawFd for TcpStream {
@@ -352,6 +647,13 @@ impl Read for TcpStream {
     }
 }
 
+impl Read for &TcpStream {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        blocking_read_nonblock_sock(self.0 .0, buf, None)
+    }
+}
+
 impl Write for TcpStream {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
@@ -364,6 +666,18 @@ impl Write for TcpStream {
     }
 }
 
+impl Write for &TcpStream {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        blocking_write_nonblock_sock(self.0 .0, buf, None)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct TcpListener(OwnedFd);
 
@@ -371,32 +685,16 @@ impl TcpListener {
     /// Create a socket, bind to it, and listen on it, creating a `TcpListener` at the provided address
     /// # Errors
     /// Various OS errors relating to permissions, and missing paths
+    #[inline]
     pub fn bind(addr: &SocketAddress) -> Result<Self> {
-        let fd = rusl::network::socket(
-            AddressFamily::AF_INET,
-            SocketOptions::new(
-                SocketType::SOCK_STREAM,
-                SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
-            ),
-            6,
-        )?;
-        let addr = match addr.ip {
-            Ip::V4(bytes) => SocketAddressInet::new(bytes, addr.port),
-        };
-        if let Err(e) = rusl::network::bind_inet(fd, &addr) {
-            let _ = rusl::unistd::close(fd);
-            return Err(e.into());
-        }
-        rusl::network::listen(fd, NonNegativeI32::MAX)?;
-        Ok(Self(OwnedFd(fd)))
+        TcpListenOptions::new().bind(addr)
     }
+
     /// Get this socket's local bind address
     /// # Errors
     /// Various OS errors, most likely os out of resources
     pub fn local_addr(&self) -> Result<SocketAddress> {
-        let name = get_inet_sock_name(self.0 .0)?;
-        let (ip, port) = name.ipv4_addr();
-        Ok(SocketAddress::new(Ip::V4(ip), port))
+        Ok(socket_address(get_ip_sock_name(self.0 .0)?))
     }
 
     /// Attempt to accept a client connection on the socket
@@ -418,7 +716,7 @@ impl TcpListener {
     /// # Errors
     /// Various OS-errors such as out of memory
     pub fn try_accept(&mut self) -> Result<Option<TcpStream>> {
-        let fd = match rusl::network::accept_inet(
+        let fd = match rusl::network::accept_ip(
             self.0 .0,
             SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
         ) {
@@ -436,7 +734,7 @@ impl TcpListener {
             PollEvents::POLLIN,
             timeout,
             |sock| {
-                rusl::network::accept_inet(
+                rusl::network::accept_ip(
                     sock,
                     SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
                 )
@@ -445,4 +743,431 @@ impl TcpListener {
 
         Ok(TcpStream(OwnedFd(fd)))
     }
+
+    /// Check if `SO_REUSEADDR` is set, see [`TcpListenOptions::reuse_addr`]
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn reuse_addr(&self) -> Result<bool> {
+        Ok(rusl::network::get_reuse_addr(self.0 .0)?)
+    }
+
+    /// Check if `SO_REUSEPORT` is set, see [`TcpListenOptions::reuse_port`]
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn reuse_port(&self) -> Result<bool> {
+        Ok(rusl::network::get_reuse_port(self.0 .0)?)
+    }
+
+    /// Check if `IPV6_V6ONLY` is set, see [`TcpListenOptions::only_v6`]
+    /// # Errors
+    /// Os errors relating to getting the socket option, such as this being an IPv4 listener
+    #[inline]
+    pub fn only_v6(&self) -> Result<bool> {
+        Ok(rusl::network::get_ipv6_only(self.0 .0)?)
+    }
+
+    /// Get and clear the pending error on the socket, if any
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        take_error(self.0 .0)
+    }
+}
+
+impl AsRawFd for TcpListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0 .0
+    }
+}
+
+/// Options that have to be set on the socket before it's bound to create a [`TcpListener`]
+#[derive(Debug, Clone, Default)]
+pub struct TcpListenOptions {
+    reuse_addr: bool,
+    reuse_port: bool,
+    nodelay: bool,
+    only_v6: Option<bool>,
+}
+
+impl TcpListenOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            reuse_addr: false,
+            reuse_port: false,
+            nodelay: false,
+            only_v6: None,
+        }
+    }
+
+    /// Allow binding to an address that's still in `TIME_WAIT` from a previous
+    /// listener, so that a server can restart immediately
+    pub fn reuse_addr(&mut self, reuse_addr: bool) -> &mut Self {
+        self.reuse_addr = reuse_addr;
+        self
+    }
+
+    /// Allow several listeners to bind the same address, the kernel load balances
+    /// incoming connections between them. Every listener needs to set this
+    pub fn reuse_port(&mut self, reuse_port: bool) -> &mut Self {
+        self.reuse_port = reuse_port;
+        self
+    }
+
+    /// Set `TCP_NODELAY` on the listener, accepted streams inherit it
+    pub fn nodelay(&mut self, nodelay: bool) -> &mut Self {
+        self.nodelay = nodelay;
+        self
+    }
+
+    /// Only accept IPv6 connections on an IPv6 listener. When `false`, a listener bound
+    /// to `::` is dual-stack and accepts IPv4 clients as v4-mapped addresses (`::ffff:a.b.c.d`).
+    /// Left unset, the system default from `net.ipv6.bindv6only` applies.
+    /// Has no effect on IPv4 listeners
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut Self {
+        self.only_v6 = Some(only_v6);
+        self
+    }
+
+    /// Create a socket with these options, bind to it, and listen on it
+    /// # Errors
+    /// Various OS errors relating to permissions, and the address being in use
+    pub fn bind(&self, addr: &SocketAddress) -> Result<TcpListener> {
+        let addr = ip_addr(*addr);
+        let fd = rusl::network::socket(
+            addr.family(),
+            SocketOptions::new(
+                SocketType::SOCK_STREAM,
+                SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
+            ),
+            6,
+        )?;
+        // Closes the socket on error
+        let owned = OwnedFd(fd);
+        if self.reuse_addr {
+            rusl::network::set_reuse_addr(fd, true)?;
+        }
+        if self.reuse_port {
+            rusl::network::set_reuse_port(fd, true)?;
+        }
+        if self.nodelay {
+            rusl::network::set_tcp_nodelay(fd, true)?;
+        }
+        if let (Some(only_v6), SocketAddressIp::V6(_)) = (self.only_v6, &addr) {
+            rusl::network::set_ipv6_only(fd, only_v6)?;
+        }
+        bind_ip(fd, &addr)?;
+        rusl::network::listen(fd, NonNegativeI32::MAX)?;
+        Ok(TcpListener(owned))
+    }
+}
+
+/// A UDP socket, non-blocking under the hood, blocking operations poll until ready
+#[derive(Debug)]
+pub struct UdpSocket(OwnedFd);
+
+impl UdpSocket {
+    /// Create a socket and bind it to the provided address, port `0` picks a free port
+    /// # Errors
+    /// Various OS errors relating to permissions, and the address being in use
+    pub fn bind(addr: &SocketAddress) -> Result<Self> {
+        let addr = ip_addr(*addr);
+        let fd = rusl::network::socket(
+            addr.family(),
+            SocketOptions::new(
+                SocketType::SOCK_DGRAM,
+                SocketFlags::SOCK_NONBLOCK | SocketFlags::SOCK_CLOEXEC,
+            ),
+            0,
+        )?;
+        let sock = Self(OwnedFd(fd));
+        bind_ip(fd, &addr)?;
+        Ok(sock)
+    }
+
+    /// Get this socket's local bind address
+    /// # Errors
+    /// Various OS errors, most likely os out of resources
+    pub fn local_addr(&self) -> Result<SocketAddress> {
+        Ok(socket_address(get_ip_sock_name(self.0 .0)?))
+    }
+
+    /// Set the default destination for [`UdpSocket::send`], and only receive datagrams from it
+    /// # Errors
+    /// Os errors, such as no route to the address
+    pub fn connect(&self, addr: &SocketAddress) -> Result<()> {
+        connect_ip(self.0 .0, &ip_addr(*addr))?;
+        Ok(())
+    }
+
+    /// Send a datagram to `addr`, blocking until there's room in the send buffer
+    /// # Errors
+    /// Os errors relating to sending on the socket
+    #[inline]
+    pub fn send_to(&self, buf: &[u8], addr: &SocketAddress) -> Result<usize> {
+        self.do_send(buf, Some(&ip_addr(*addr)), None)
+    }
+
+    /// Send a datagram to `addr`, blocking until there's room in the send buffer or the `timeout` passes
+    /// # Errors
+    /// Os errors relating to sending on the socket, or a timeout
+    #[inline]
+    pub fn send_to_with_timeout(
+        &self,
+        buf: &[u8],
+        addr: &SocketAddress,
+        timeout: Duration,
+    ) -> Result<usize> {
+        self.do_send(buf, Some(&ip_addr(*addr)), Some(timeout))
+    }
+
+    /// Attempt to send a datagram to `addr`, returns `None` if the send buffer is full
+    /// # Errors
+    /// Os errors relating to sending on the socket
+    pub fn try_send_to(&self, buf: &[u8], addr: &SocketAddress) -> Result<Option<usize>> {
+        match sendto_ip(self.0 .0, buf, Some(&ip_addr(*addr))) {
+            Ok(sent) => Ok(Some(sent)),
+            Err(e) if e.code == Some(Errno::EAGAIN) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Send a datagram to the address this socket is connected to
+    /// # Errors
+    /// Os errors relating to sending on the socket, or not being connected
+    #[inline]
+    pub fn send(&self, buf: &[u8]) -> Result<usize> {
+        self.do_send(buf, None, None)
+    }
+
+    fn do_send(
+        &self,
+        buf: &[u8],
+        addr: Option<&SocketAddressIp>,
+        timeout: Option<Duration>,
+    ) -> Result<usize> {
+        sock_nonblock_op_poll_until_ready(
+            self.0 .0,
+            Errno::EAGAIN,
+            PollEvents::POLLOUT,
+            timeout,
+            |sock| sendto_ip(sock, buf, addr),
+        )
+    }
+
+    /// Receive a datagram, blocking until one arrives. If it doesn't fit into `buf`
+    /// the rest of it is discarded.
+    /// # Errors
+    /// Os errors relating to reading from the socket
+    #[inline]
+    pub fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddress)> {
+        self.do_recv(buf, MsgFlags::empty(), None)
+    }
+
+    /// Receive a datagram, blocking until one arrives or the `timeout` passes
+    /// # Errors
+    /// Os errors relating to reading from the socket, or a timeout
+    #[inline]
+    pub fn recv_from_with_timeout(
+        &self,
+        buf: &mut [u8],
+        timeout: Duration,
+    ) -> Result<(usize, SocketAddress)> {
+        self.do_recv(buf, MsgFlags::empty(), Some(timeout))
+    }
+
+    /// Attempt to receive a datagram, returns `None` if none is queued
+    /// # Errors
+    /// Os errors relating to reading from the socket
+    pub fn try_recv_from(&self, buf: &mut [u8]) -> Result<Option<(usize, SocketAddress)>> {
+        match rusl::network::recvfrom_ip(self.0 .0, buf, MsgFlags::empty()) {
+            Ok((read, addr)) => Ok(Some((read, socket_address(addr)))),
+            Err(e) if e.code == Some(Errno::EAGAIN) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Receive a datagram without removing it from the queue, blocking until one arrives
+    /// # Errors
+    /// Os errors relating to reading from the socket
+    #[inline]
+    pub fn peek_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddress)> {
+        self.do_recv(buf, MsgFlags::MSG_PEEK, None)
+    }
+
+    /// Receive a datagram from the address this socket is connected to
+    /// # Errors
+    /// Os errors relating to reading from the socket
+    #[inline]
+    pub fn recv(&self, buf: &mut [u8]) -> Result<usize> {
+        Ok(self.do_recv(buf, MsgFlags::empty(), None)?.0)
+    }
+
+    /// Receive a datagram from the address this socket is connected to, with a timeout
+    /// # Errors
+    /// Os errors relating to reading from the socket, or a timeout
+    #[inline]
+    pub fn recv_with_timeout(&self, buf: &mut [u8], timeout: Duration) -> Result<usize> {
+        Ok(self.do_recv(buf, MsgFlags::empty(), Some(timeout))?.0)
+    }
+
+    fn do_recv(
+        &self,
+        buf: &mut [u8],
+        flags: MsgFlags,
+        timeout: Option<Duration>,
+    ) -> Result<(usize, SocketAddress)> {
+        let (read, addr) = sock_nonblock_op_poll_until_ready(
+            self.0 .0,
+            Errno::EAGAIN,
+            PollEvents::POLLIN,
+            timeout,
+            |sock| rusl::network::recvfrom_ip(sock, buf, flags),
+        )?;
+        Ok((read, socket_address(addr)))
+    }
+
+    /// Allow sending to broadcast addresses
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_broadcast(&self, broadcast: bool) -> Result<()> {
+        rusl::network::set_broadcast(self.0 .0, broadcast)?;
+        Ok(())
+    }
+
+    /// Check if sending to broadcast addresses is allowed
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn broadcast(&self) -> Result<bool> {
+        Ok(rusl::network::get_broadcast(self.0 .0)?)
+    }
+
+    /// Join a multicast `group` on the interface with the address `interface`,
+    /// `[0, 0, 0, 0]` lets the OS pick one
+    /// # Errors
+    /// Os errors, such as `group` not being a multicast address
+    #[inline]
+    pub fn join_multicast_v4(&self, group: [u8; 4], interface: [u8; 4]) -> Result<()> {
+        rusl::network::join_multicast_v4(self.0 .0, group, interface)?;
+        Ok(())
+    }
+
+    /// Leave a multicast `group` joined with [`UdpSocket::join_multicast_v4`]
+    /// # Errors
+    /// Os errors, such as not being a member of the group
+    #[inline]
+    pub fn leave_multicast_v4(&self, group: [u8; 4], interface: [u8; 4]) -> Result<()> {
+        rusl::network::leave_multicast_v4(self.0 .0, group, interface)?;
+        Ok(())
+    }
+
+    /// Set whether sent multicast datagrams are looped back to local members of the group
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_multicast_loop_v4(&self, multicast_loop: bool) -> Result<()> {
+        rusl::network::set_multicast_loop_v4(self.0 .0, multicast_loop)?;
+        Ok(())
+    }
+
+    /// Check if sent multicast datagrams are looped back
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn multicast_loop_v4(&self) -> Result<bool> {
+        Ok(rusl::network::get_multicast_loop_v4(self.0 .0)?)
+    }
+
+    /// Set the time to live of sent multicast datagrams
+    /// # Errors
+    /// Os errors relating to setting the socket option
+    #[inline]
+    pub fn set_multicast_ttl_v4(&self, ttl: u8) -> Result<()> {
+        rusl::network::set_multicast_ttl_v4(self.0 .0, ttl)?;
+        Ok(())
+    }
+
+    /// Get the time to live of sent multicast datagrams
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn multicast_ttl_v4(&self) -> Result<u8> {
+        Ok(rusl::network::get_multicast_ttl_v4(self.0 .0)?)
+    }
+
+    /// Get and clear the pending error on the socket, such as an ICMP port unreachable
+    /// from an earlier send
+    /// # Errors
+    /// Os errors relating to getting the socket option
+    #[inline]
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        take_error(self.0 .0)
+    }
+}
+
+impl AsRawFd for UdpSocket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0 .0
+    }
+}
+
+#[inline]
+fn ip_addr(addr: SocketAddress) -> SocketAddressIp {
+    match addr.ip() {
+        Ip::V4(bytes) => SocketAddressIp::V4(SocketAddressInet::new(bytes, addr.port())),
+        Ip::V6(bytes) => SocketAddressIp::V6(
+            SocketAddressInet6::new(bytes, addr.port()).with_scope_id(addr.scope_id()),
+        ),
+    }
+}
+
+#[inline]
+fn socket_address(addr: SocketAddressIp) -> SocketAddress {
+    match addr {
+        SocketAddressIp::V4(addr) => {
+            let (ip, port) = addr.ipv4_addr();
+            SocketAddress::new(Ip::V4(ip), port)
+        }
+        SocketAddressIp::V6(addr) => {
+            let (ip, port) = addr.ipv6_addr();
+            SocketAddress::new(Ip::V6(ip), port).with_scope_id(addr.scope_id())
+        }
+    }
+}
+
+fn bind_ip(fd: RawFd, addr: &SocketAddressIp) -> rusl::Result<()> {
+    match addr {
+        SocketAddressIp::V4(addr) => rusl::network::bind_inet(fd, addr),
+        SocketAddressIp::V6(addr) => rusl::network::bind_inet6(fd, addr),
+    }
+}
+
+fn connect_ip(fd: RawFd, addr: &SocketAddressIp) -> rusl::Result<()> {
+    match addr {
+        SocketAddressIp::V4(addr) => rusl::network::connect_inet(fd, addr),
+        SocketAddressIp::V6(addr) => rusl::network::connect_inet6(fd, addr),
+    }
+}
+
+fn sendto_ip(fd: RawFd, buf: &[u8], addr: Option<&SocketAddressIp>) -> rusl::Result<usize> {
+    match addr {
+        None => rusl::network::sendto_inet(fd, buf, MsgFlags::empty(), None),
+        Some(SocketAddressIp::V4(addr)) => {
+            rusl::network::sendto_inet(fd, buf, MsgFlags::empty(), Some(addr))
+        }
+        Some(SocketAddressIp::V6(addr)) => {
+            rusl::network::sendto_inet6(fd, buf, MsgFlags::empty(), Some(addr))
+        }
+    }
+}
+
+fn take_error(fd: RawFd) -> Result<Option<Error>> {
+    Ok(rusl::network::get_socket_error(fd)?.map(|code| Error::os("Socket error", code)))
 }
@@ -0,0 +1,311 @@
+use core::fmt::{Display, Formatter, Write};
+use core::str::FromStr;
+
+use rusl::platform::SocketArgUnix;
+use rusl::string::unix_str::UnixStr;
+
+use crate::error::Error;
+
+/// An IP address and a port, and for IPv6 a scope id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SocketAddress {
+    ip: Ip,
+    port: u16,
+    scope_id: u32,
+}
+
+impl SocketAddress {
+    #[must_use]
+    pub const fn new(ip: Ip, port: u16) -> Self {
+        Self {
+            ip,
+            port,
+            scope_id: 0,
+        }
+    }
+
+    /// Set the scope id, the index of the interface that a link-local IPv6 address, such as
+    /// `fe80::1`, is reached through. Link-local addresses can't be used without one.
+    /// Unused for IPv4.
+    #[inline]
+    #[must_use]
+    pub const fn with_scope_id(mut self, scope_id: u32) -> Self {
+        self.scope_id = scope_id;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn ip(&self) -> Ip {
+        self.ip
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn scope_id(&self) -> u32 {
+        self.scope_id
+    }
+}
+
+impl FromStr for SocketAddress {
+    type Err = Error;
+
+    /// Parses `127.0.0.1:8080` or, with the IPv6 address in brackets, `[::1]:8080`.
+    /// A numeric scope id can follow an IPv6 address, `[fe80::1%2]:8080`, interface names aren't resolved.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_socket_address(s).ok_or(Error::no_code("Invalid socket address syntax"))
+    }
+}
+
+impl Display for SocketAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.ip {
+            Ip::V4(_) => f.write_fmt(format_args!("{}:{}", self.ip, self.port)),
+            Ip::V6(_) if self.scope_id != 0 => f.write_fmt(format_args!(
+                "[{}%{}]:{}",
+                self.ip, self.scope_id, self.port
+            )),
+            Ip::V6(_) => f.write_fmt(format_args!("[{}]:{}", self.ip, self.port)),
+        }
+    }
+}
+
+/// The address of a Unix socket
+#[derive(Debug, Clone, Copy)]
+pub struct UnixSocketAddress(pub(crate) SocketArgUnix);
+
+impl UnixSocketAddress {
+    /// Get the path the socket is bound to, `None` if it's unnamed or abstract
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&UnixStr> {
+        self.0.path()
+    }
+
+    /// Check if the socket is unnamed, which the client end of a connection
+    /// usually is since it never bound to a path
+    #[inline]
+    #[must_use]
+    pub const fn is_unnamed(&self) -> bool {
+        self.0.is_unnamed()
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ip {
+    /// 4 bytes representing the address, i.e. `127.0.0.1`
+    V4([u8; 4]),
+    /// 16 bytes representing the address, i.e. `::1`
+    V6([u8; 16]),
+}
+
+impl Ip {
+    pub const LOCALHOST_V4: Self = Self::V4([127, 0, 0, 1]);
+    pub const LOCALHOST_V6: Self = Self::V6([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    pub const UNSPECIFIED_V4: Self = Self::V4([0; 4]);
+    pub const UNSPECIFIED_V6: Self = Self::V6([0; 16]);
+
+    /// `127.0.0.0/8` or `::1`
+    #[inline]
+    #[must_use]
+    pub const fn is_loopback(&self) -> bool {
+        matches!(self, Self::V4([127, ..])) || matches!(*self, Self::LOCALHOST_V6)
+    }
+
+    /// `0.0.0.0` or `::`, binding to it means binding to all interfaces
+    #[inline]
+    #[must_use]
+    pub const fn is_unspecified(&self) -> bool {
+        matches!(*self, Self::UNSPECIFIED_V4 | Self::UNSPECIFIED_V6)
+    }
+
+    /// The private IPv4 ranges from RFC 1918, `10.0.0.0/8`, `172.16.0.0/12` and `192.168.0.0/16`,
+    /// or the IPv6 unique local range `fc00::/7`
+    #[inline]
+    #[must_use]
+    pub const fn is_private(&self) -> bool {
+        match self {
+            Self::V4(bytes) => matches!(bytes, [10, ..] | [172, 16..=31, ..] | [192, 168, ..]),
+            Self::V6(bytes) => bytes[0] & 0xfe == 0xfc,
+        }
+    }
+
+    /// `224.0.0.0/4` or `ff00::/8`
+    #[inline]
+    #[must_use]
+    pub const fn is_multicast(&self) -> bool {
+        matches!(self, Self::V4([224..=239, ..]) | Self::V6([0xff, ..]))
+    }
+
+    /// Turn a v4-mapped IPv6 address (`::ffff:a.b.c.d`), which is what a dual-stack socket sees
+    /// for IPv4 peers, into the IPv4 address it maps. The classification functions don't look
+    /// through the mapping, so this should be done first if that's desired.
+    #[inline]
+    #[must_use]
+    pub const fn to_canonical(&self) -> Self {
+        match self {
+            Self::V6([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, a, b, c, d]) => {
+                Self::V4([*a, *b, *c, *d])
+            }
+            _ => *self,
+        }
+    }
+}
+
+impl FromStr for Ip {
+    type Err = Error;
+
+    /// Parses dotted decimal IPv4, or full and compressed IPv6 including
+    /// an embedded IPv4 tail such as `::ffff:127.0.0.1`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ip = if s.contains(':') {
+            parse_v6(s).map(Ip::V6)
+        } else {
+            parse_v4(s).map(Ip::V4)
+        };
+        ip.ok_or(Error::no_code("Invalid IP address syntax"))
+    }
+}
+
+impl Display for Ip {
+    /// Formats IPv6 addresses in the canonical form of RFC 5952
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ip::V4(octets) => write_v4(f, *octets),
+            Ip::V6([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, mapped @ ..]) => {
+                f.write_str("::ffff:")?;
+                write_v4(f, *mapped)
+            }
+            Ip::V6(bytes) => {
+                let groups: [u16; 8] =
+                    core::array::from_fn(|i| u16::from_be_bytes([bytes[i * 2], bytes[i * 2 + 1]]));
+                // The longest run of at least two zero groups is compressed, the first one on ties
+                let (mut zeroes_start, mut zeroes_len) = (0, 0);
+                let mut i = 0;
+                while i < groups.len() {
+                    let start = i;
+                    while i < groups.len() && groups[i] == 0 {
+                        i += 1;
+                    }
+                    if i - start > zeroes_len {
+                        zeroes_start = start;
+                        zeroes_len = i - start;
+                    }
+                    i += 1;
+                }
+                if zeroes_len < 2 {
+                    write_v6_groups(f, &groups)
+                } else {
+                    write_v6_groups(f, &groups[..zeroes_start])?;
+                    f.write_str("::")?;
+                    write_v6_groups(f, &groups[zeroes_start + zeroes_len..])
+                }
+            }
+        }
+    }
+}
+
+fn write_v4(f: &mut Formatter<'_>, octets: [u8; 4]) -> core::fmt::Result {
+    let [first, second, third, fourth] = octets;
+    f.write_fmt(format_args!("{first}.{second}.{third}.{fourth}"))
+}
+
+fn write_v6_groups(f: &mut Formatter<'_>, groups: &[u16]) -> core::fmt::Result {
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            f.write_char(':')?;
+        }
+        f.write_fmt(format_args!("{group:x}"))?;
+    }
+    Ok(())
+}
+
+fn parse_socket_address(s: &str) -> Option<SocketAddress> {
+    let (ip, scope_id, port) = if let Some(bracketed) = s.strip_prefix('[') {
+        let (ip, port) = bracketed.split_once("]:")?;
+        let (ip, scope_id) = match ip.split_once('%') {
+            Some((ip, scope_id)) => (ip, parse_decimal(scope_id, 10)?),
+            None => (ip, 0),
+        };
+        (Ip::V6(parse_v6(ip)?), scope_id, port)
+    } else {
+        let (ip, port) = s.rsplit_once(':')?;
+        (Ip::V4(parse_v4(ip)?), 0, port)
+    };
+    let port = u16::try_from(parse_decimal(port, 5)?).ok()?;
+    Some(SocketAddress::new(ip, port).with_scope_id(scope_id))
+}
+
+fn parse_v4(s: &str) -> Option<[u8; 4]> {
+    let mut octets = [0u8; 4];
+    let mut parts = s.split('.');
+    for octet in &mut octets {
+        let part = parts.next()?;
+        // Leading zeroes are ambiguous, some parsers read them as octal
+        if part.len() > 1 && part.starts_with('0') {
+            return None;
+        }
+        *octet = u8::try_from(parse_decimal(part, 3)?).ok()?;
+    }
+    parts.next().is_none().then_some(octets)
+}
+
+fn parse_v6(s: &str) -> Option<[u8; 16]> {
+    let mut groups = [0u16; 8];
+    if let Some((head, tail)) = s.split_once("::") {
+        let head_len = parse_v6_groups(head, &mut groups, false)?;
+        let mut tail_groups = [0u16; 8];
+        let tail_len = parse_v6_groups(tail, &mut tail_groups, true)?;
+        // `::` stands for at least one group
+        if head_len + tail_len > 7 {
+            return None;
+        }
+        groups[8 - tail_len..].copy_from_slice(&tail_groups[..tail_len]);
+    } else if parse_v6_groups(s, &mut groups, true)? != 8 {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    for (chunk, group) in bytes.chunks_exact_mut(2).zip(groups) {
+        chunk.copy_from_slice(&group.to_be_bytes());
+    }
+    Some(bytes)
+}
+
+/// Parses colon-separated hex groups into `groups` and returns how many there were,
+/// if `allow_v4_tail` is set the last two groups may be written as an IPv4 address
+fn parse_v6_groups(s: &str, groups: &mut [u16; 8], allow_v4_tail: bool) -> Option<usize> {
+    if s.is_empty() {
+        return Some(0);
+    }
+    let mut len = 0;
+    let mut parts = s.split(':').peekable();
+    while let Some(part) = parts.next() {
+        if allow_v4_tail && parts.peek().is_none() && part.contains('.') {
+            let octets = parse_v4(part)?;
+            *groups.get_mut(len)? = u16::from_be_bytes([octets[0], octets[1]]);
+            *groups.get_mut(len + 1)? = u16::from_be_bytes([octets[2], octets[3]]);
+            return Some(len + 2);
+        }
+        if part.is_empty() || part.len() > 4 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        *groups.get_mut(len)? = u16::from_str_radix(part, 16).ok()?;
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Parses only ascii digits, unlike `u32::from_str` which accepts a leading `+`
+fn parse_decimal(s: &str, max_digits: usize) -> Option<u32> {
+    if s.is_empty() || s.len() > max_digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
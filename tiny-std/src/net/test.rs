@@ -2,7 +2,8 @@ use core::time::Duration;
 
 use crate::io::{Read, Write};
 use crate::net::{
-    Ip, SocketAddress, TcpListener, TcpStream, TcpTryConnect, UnixListener, UnixStream,
+    Ip, Shutdown, SocketAddress, TcpListenOptions, TcpListener, TcpStream, TcpTryConnect,
+    UdpSocket, UnixListener, UnixStream,
 };
 use crate::time::MonotonicInstant;
 use crate::unix::fd::AsRawFd;
@@ -175,3 +176,578 @@ fn test_tcp_try_connect_then_block() {
     let client = jh.join().unwrap();
     verify_communication(client, client_handle);
 }
+
+#[test]
+fn test_tcp_restart_with_reuse_addr() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let mut listener = TcpListenOptions::new()
+        .reuse_addr(true)
+        .bind(&SocketAddress::new(ip, 0))
+        .unwrap();
+    assert!(listener.reuse_addr().unwrap());
+    assert!(!listener.reuse_port().unwrap());
+    let addr = listener.local_addr().unwrap();
+    let client = TcpStream::connect(&addr).unwrap();
+    let client_handle = listener.accept().unwrap();
+    // Closing the server end first leaves it in `TIME_WAIT`
+    drop(client_handle);
+    drop(client);
+    drop(listener);
+    let Err(e) = TcpListener::bind(&addr) else {
+        panic!("Expected to fail binding an address in `TIME_WAIT` without `SO_REUSEADDR`");
+    };
+    assert!(e.matches_errno(Errno::EADDRINUSE));
+    let listener = TcpListenOptions::new()
+        .reuse_addr(true)
+        .bind(&addr)
+        .unwrap();
+    assert!(listener.take_error().unwrap().is_none());
+}
+
+#[test]
+fn test_tcp_reuse_port() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let mut opts = TcpListenOptions::new();
+    opts.reuse_port(true);
+    let first = opts.bind(&SocketAddress::new(ip, 0)).unwrap();
+    let addr = first.local_addr().unwrap();
+    let second = opts.bind(&addr).unwrap();
+    assert!(second.reuse_port().unwrap());
+}
+
+#[test]
+fn test_tcp_stream_options() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let mut listener = TcpListenOptions::new()
+        .nodelay(true)
+        .bind(&SocketAddress::new(ip, 0))
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let client = TcpStream::connect(&addr).unwrap();
+    let client_handle = listener.accept().unwrap();
+    // Inherited from the listener
+    assert!(client_handle.nodelay().unwrap());
+    assert!(!client.nodelay().unwrap());
+    client.set_nodelay(true).unwrap();
+    assert!(client.nodelay().unwrap());
+    assert_eq!(None, client.keepalive().unwrap());
+    client
+        .set_keepalive(true, Some(Duration::from_secs(45)))
+        .unwrap();
+    assert_eq!(Some(Duration::from_secs(45)), client.keepalive().unwrap());
+    client.set_keepalive(false, None).unwrap();
+    assert_eq!(None, client.keepalive().unwrap());
+    client.set_linger(Some(Duration::from_secs(1))).unwrap();
+    assert_eq!(Some(Duration::from_secs(1)), client.linger().unwrap());
+    client.set_recv_buffer_size(4096).unwrap();
+    assert_eq!(8192, client.recv_buffer_size().unwrap());
+    client.set_send_buffer_size(4096).unwrap();
+    assert_eq!(8192, client.send_buffer_size().unwrap());
+    assert!(client.take_error().unwrap().is_none());
+    verify_communication(client, client_handle);
+}
+
+#[test]
+fn test_unix_stream_options() {
+    let sock_path = UnixStr::try_from_str("/tmp/test-sock/sock-options\0").unwrap();
+    let _ = crate::fs::remove_file(sock_path);
+    crate::fs::create_dir_all(UnixStr::try_from_str("/tmp/test-sock/\0").unwrap()).unwrap();
+    let mut listener = UnixListener::bind(sock_path).unwrap();
+    let client = UnixStream::connect(sock_path).unwrap();
+    let server = listener.accept().unwrap();
+    for stream in [&client, &server] {
+        let cred = stream.peer_cred().unwrap();
+        assert_eq!(crate::process::id(), cred.pid.unsigned_abs());
+        assert_eq!(rusl::unistd::get_uid().unwrap(), cred.uid);
+        assert_eq!(rusl::unistd::get_gid().unwrap(), cred.gid);
+    }
+    client.set_send_buffer_size(8192).unwrap();
+    assert_eq!(16384, client.send_buffer_size().unwrap());
+    server.set_recv_buffer_size(8192).unwrap();
+    assert_eq!(16384, server.recv_buffer_size().unwrap());
+    assert!(client.take_error().unwrap().is_none());
+    assert!(listener.take_error().unwrap().is_none());
+}
+
+#[test]
+fn test_udp_send_recv() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let a = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let b = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let a_addr = a.local_addr().unwrap();
+    let b_addr = b.local_addr().unwrap();
+    let mut buf = [0u8; 16];
+    assert!(b.try_recv_from(&mut buf).unwrap().is_none());
+    assert!(matches!(
+        b.recv_from_with_timeout(&mut buf, Duration::from_millis(15)),
+        Err(crate::Error::Timeout)
+    ));
+    assert_eq!(6, a.send_to(b"metric", &b_addr).unwrap());
+    let (peeked, from) = b.peek_from(&mut buf).unwrap();
+    assert_eq!(6, peeked);
+    assert_eq!(a_addr.port(), from.port());
+    let (read, from) = b.recv_from(&mut buf).unwrap();
+    assert_eq!(b"metric", &buf[..read]);
+    assert_eq!(a_addr.port(), from.port());
+    assert_eq!(Some(2), b.try_send_to(b"ok", &a_addr).unwrap());
+    let (read, _from) = a
+        .recv_from_with_timeout(&mut buf, Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(b"ok", &buf[..read]);
+    // A datagram larger than the buffer is truncated
+    a.send_to(b"a longer datagram", &b_addr).unwrap();
+    let (read, _from) = b.recv_from(&mut buf[..4]).unwrap();
+    assert_eq!(b"a lo", &buf[..read]);
+    assert!(b.try_recv_from(&mut buf).unwrap().is_none());
+}
+
+#[test]
+fn test_udp_shared_blocking_recv() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let a = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let b = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let b_addr = b.local_addr().unwrap();
+    // Both receivers can be woken by the first datagram, the one that loses the race
+    // has to go back to waiting instead of failing with `EAGAIN`
+    std::thread::scope(|s| {
+        let receivers = [(); 2].map(|()| {
+            s.spawn(|| {
+                let mut buf = [0u8; 16];
+                b.recv_from_with_timeout(&mut buf, Duration::from_secs(5))
+                    .unwrap()
+                    .0
+            })
+        });
+        std::thread::sleep(Duration::from_millis(20));
+        a.send_to(b"first", &b_addr).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        a.send_to(b"second", &b_addr).unwrap();
+        let mut read = receivers.map(|r| r.join().unwrap());
+        read.sort_unstable();
+        assert_eq!([5, 6], read);
+    });
+}
+
+#[test]
+fn test_udp_connected_blocking_recv() {
+    let ip = Ip::V4([127, 0, 0, 1]);
+    let a = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let b = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let other = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let a_addr = a.local_addr().unwrap();
+    let b_addr = b.local_addr().unwrap();
+    b.connect(&a_addr).unwrap();
+    // Datagrams from others than the connected peer are dropped
+    other.send_to(b"noise", &b_addr).unwrap();
+    let jh = std::thread::spawn(move || {
+        let mut buf = [0u8; 8];
+        let read = b.recv(&mut buf).unwrap();
+        assert_eq!(b"ping", &buf[..read]);
+        b.send(b"pong").unwrap();
+    });
+    a.send_to(b"ping", &b_addr).unwrap();
+    let mut buf = [0u8; 8];
+    let (read, from) = a
+        .recv_from_with_timeout(&mut buf, Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(b"pong", &buf[..read]);
+    assert_eq!(b_addr.port(), from.port());
+    jh.join().unwrap();
+}
+
+#[test]
+fn test_udp_options() {
+    let sock = UdpSocket::bind(&SocketAddress::new(Ip::V4([0, 0, 0, 0]), 0)).unwrap();
+    assert!(!sock.broadcast().unwrap());
+    sock.set_broadcast(true).unwrap();
+    assert!(sock.broadcast().unwrap());
+    sock.set_multicast_loop_v4(false).unwrap();
+    assert!(!sock.multicast_loop_v4().unwrap());
+    sock.set_multicast_ttl_v4(4).unwrap();
+    assert_eq!(4, sock.multicast_ttl_v4().unwrap());
+    assert!(sock
+        .leave_multicast_v4([239, 0, 0, 1], [0, 0, 0, 0])
+        .unwrap_err()
+        .matches_errno(Errno::EADDRNOTAVAIL));
+    assert!(sock.take_error().unwrap().is_none());
+}
+
+#[test]
+fn test_link_local_scope_id() {
+    let addr = SocketAddress::new("fe80::1".parse().unwrap(), 9);
+    // The kernel refuses link-local addresses without an interface
+    assert!(TcpStream::connect(&addr)
+        .unwrap_err()
+        .matches_errno(Errno::EINVAL));
+    // The loopback interface always has index 1, it can't reach the address but accepts the scope
+    let res = TcpStream::connect_with_timeout(&addr.with_scope_id(1), Duration::from_millis(100));
+    assert!(!res.unwrap_err().matches_errno(Errno::EINVAL));
+}
+
+#[test]
+fn test_tcp6_ping_pong() {
+    let ip = Ip::LOCALHOST_V6;
+    let mut listener = TcpListener::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let addr = listener.local_addr().unwrap();
+    assert_eq!(Ip::LOCALHOST_V6, addr.ip());
+    let client = TcpStream::connect(&addr).unwrap();
+    let client_handle = listener.accept().unwrap();
+    verify_communication(client, client_handle);
+    let client = match TcpStream::try_connect(&addr).unwrap() {
+        TcpTryConnect::Connected(client) => client,
+        TcpTryConnect::InProgress(in_progress) => in_progress.connect_blocking().unwrap(),
+    };
+    let client_handle = listener.try_accept().unwrap().unwrap();
+    verify_communication(client, client_handle);
+}
+
+#[test]
+fn test_tcp_dual_stack_listener() {
+    let mut listener = TcpListenOptions::new()
+        .only_v6(false)
+        .bind(&SocketAddress::new(Ip::V6([0; 16]), 0))
+        .unwrap();
+    assert!(!listener.only_v6().unwrap());
+    let port = listener.local_addr().unwrap().port();
+    let client = TcpStream::connect(&SocketAddress::new(Ip::V4([127, 0, 0, 1]), port)).unwrap();
+    let client_handle = listener.accept().unwrap();
+    verify_communication(client, client_handle);
+    let client = TcpStream::connect(&SocketAddress::new(Ip::LOCALHOST_V6, port)).unwrap();
+    let client_handle = listener.accept().unwrap();
+    verify_communication(client, client_handle);
+    // A v6-only listener refuses v4 clients
+    let v6_only = TcpListenOptions::new()
+        .only_v6(true)
+        .bind(&SocketAddress::new(Ip::V6([0; 16]), 0))
+        .unwrap();
+    assert!(v6_only.only_v6().unwrap());
+    let port = v6_only.local_addr().unwrap().port();
+    assert!(
+        TcpStream::connect(&SocketAddress::new(Ip::V4([127, 0, 0, 1]), port))
+            .unwrap_err()
+            .matches_errno(Errno::ECONNREFUSED)
+    );
+}
+
+#[test]
+fn test_udp6_send_recv() {
+    let ip = Ip::LOCALHOST_V6;
+    let a = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let b = UdpSocket::bind(&SocketAddress::new(ip, 0)).unwrap();
+    let a_addr = a.local_addr().unwrap();
+    let b_addr = b.local_addr().unwrap();
+    assert_eq!(6, a.send_to(b"metric", &b_addr).unwrap());
+    let mut buf = [0u8; 16];
+    let (read, from) = b
+        .recv_from_with_timeout(&mut buf, Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(b"metric", &buf[..read]);
+    assert_eq!(Ip::LOCALHOST_V6, from.ip());
+    assert_eq!(a_addr.port(), from.port());
+    b.connect(&a_addr).unwrap();
+    b.send(b"ok").unwrap();
+    let read = a
+        .recv_with_timeout(&mut buf, Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(b"ok", &buf[..read]);
+}
+
+#[test]
+fn test_tcp_peer_addr() {
+    for ip in [Ip::LOCALHOST_V4, Ip::LOCALHOST_V6] {
+        let mut listener = TcpListener::bind(&SocketAddress::new(ip, 0)).unwrap();
+        let listen_addr = listener.local_addr().unwrap();
+        let client = TcpStream::connect(&listen_addr).unwrap();
+        let client_handle = listener.accept().unwrap();
+        assert_eq!(listen_addr, client.peer_addr().unwrap());
+        assert_eq!(
+            client.local_addr().unwrap(),
+            client_handle.peer_addr().unwrap()
+        );
+        assert_eq!(ip, client.local_addr().unwrap().ip());
+    }
+}
+
+#[test]
+fn test_tcp_half_close() {
+    let mut listener = TcpListener::bind(&SocketAddress::new(Ip::LOCALHOST_V4, 0)).unwrap();
+    let mut client = TcpStream::connect(&listener.local_addr().unwrap()).unwrap();
+    let mut server = listener.accept().unwrap();
+    client.write_all(b"request").unwrap();
+    client.shutdown(Shutdown::Write).unwrap();
+    // The server reads to EOF, then answers on the still open half
+    let mut buf = [0u8; 16];
+    let mut read = 0;
+    loop {
+        let n = server.read(&mut buf[read..]).unwrap();
+        if n == 0 {
+            break;
+        }
+        read += n;
+    }
+    assert_eq!(b"request", &buf[..read]);
+    server.write_all(b"response").unwrap();
+    server.shutdown(Shutdown::Both).unwrap();
+    client.read_exact(&mut buf[..8]).unwrap();
+    assert_eq!(b"response", &buf[..8]);
+    assert_eq!(0, client.read(&mut buf).unwrap());
+}
+
+#[test]
+fn test_tcp_try_clone_split() {
+    let mut listener = TcpListener::bind(&SocketAddress::new(Ip::LOCALHOST_V4, 0)).unwrap();
+    let client = TcpStream::connect(&listener.local_addr().unwrap()).unwrap();
+    let mut server = listener.accept().unwrap();
+    let writer = client.try_clone().unwrap();
+    assert_ne!(client.as_raw_fd(), writer.as_raw_fd());
+    assert_eq!(client.local_addr().unwrap(), writer.local_addr().unwrap());
+    // One thread writes through the clone while this one reads through a shared reference
+    let jh = std::thread::spawn(move || {
+        (&writer).write_all(b"from clone").unwrap();
+        writer.shutdown(Shutdown::Write).unwrap();
+    });
+    let mut buf = [0u8; 10];
+    server.read_exact(&mut buf).unwrap();
+    assert_eq!(b"from clone", &buf);
+    jh.join().unwrap();
+    // The shutdown through the clone applies to the original too
+    assert_eq!(0, server.read(&mut buf).unwrap());
+    server.write_all(b"back").unwrap();
+    (&client).read_exact(&mut buf[..4]).unwrap();
+    assert_eq!(b"back", &buf[..4]);
+}
+
+#[test]
+fn test_unix_stream_lifecycle() {
+    let sock_path = UnixStr::try_from_str("/tmp/test-sock/sock-lifecycle\0").unwrap();
+    let _ = crate::fs::remove_file(sock_path);
+    crate::fs::create_dir_all(UnixStr::try_from_str("/tmp/test-sock/\0").unwrap()).unwrap();
+    let mut listener = UnixListener::bind(sock_path).unwrap();
+    let client = UnixStream::connect(sock_path).unwrap();
+    let server = listener.accept().unwrap();
+    assert_eq!(Some(sock_path), client.peer_addr().unwrap().path());
+    assert!(client.local_addr().unwrap().is_unnamed());
+    assert!(server.peer_addr().unwrap().is_unnamed());
+    assert_eq!(Some(sock_path), server.local_addr().unwrap().path());
+    let server_clone = server.try_clone().unwrap();
+    (&client).write_all(b"ping").unwrap();
+    client.shutdown(Shutdown::Write).unwrap();
+    let mut buf = [0u8; 4];
+    (&server_clone).read_exact(&mut buf).unwrap();
+    assert_eq!(b"ping", &buf);
+    assert_eq!(0, (&server).read(&mut buf).unwrap());
+    (&server).write_all(b"pong").unwrap();
+    (&client).read_exact(&mut buf).unwrap();
+    assert_eq!(b"pong", &buf);
+}
+
+#[test]
+fn test_parse_ip() {
+    let parse = |s: &str| s.parse::<Ip>().ok();
+    assert_eq!(Some(Ip::LOCALHOST_V4), parse("127.0.0.1"));
+    assert_eq!(Some(Ip::V4([255, 0, 10, 1])), parse("255.0.10.1"));
+    for bad in [
+        "",
+        "1.2.3",
+        "1.2.3.4.",
+        "1.2.3.4.5",
+        "256.0.0.1",
+        "01.2.3.4",
+        "1.2.3.+4",
+        "1..3.4",
+        "a.b.c.d",
+        " 1.2.3.4",
+    ] {
+        assert_eq!(None, parse(bad), "parsed {bad:?}");
+    }
+    assert_eq!(Some(Ip::LOCALHOST_V6), parse("::1"));
+    assert_eq!(Some(Ip::UNSPECIFIED_V6), parse("::"));
+    assert_eq!(
+        Some(Ip::V6([
+            0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1
+        ])),
+        parse("2001:DB8::1")
+    );
+    assert_eq!(
+        parse("2001:db8::1"),
+        parse("2001:0db8:0000:0000:0000:0000:0000:0001")
+    );
+    assert_eq!(parse("1::"), parse("1:0:0:0:0:0:0:0"));
+    assert_eq!(parse("1:2:3:4:5:6:7::"), parse("1:2:3:4:5:6:7:0"));
+    assert_eq!(
+        Some(Ip::V6([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 127, 0, 0, 1
+        ])),
+        parse("::ffff:127.0.0.1")
+    );
+    assert_eq!(parse("::ffff:7f00:1"), parse("0:0:0:0:0:ffff:127.0.0.1"));
+    for bad in [
+        ":",
+        ":::",
+        "1:::2",
+        "1::2::3",
+        ":1::",
+        "1:2:3:4:5:6:7",
+        "1:2:3:4:5:6:7:8:9",
+        "1:2:3:4:5:6:7::8",
+        "12345::",
+        "g::",
+        "::+1",
+        "1.2.3.4::",
+        "::1.2.3.4:1",
+        "1:2:3:4:5:6:7:1.2.3.4",
+    ] {
+        assert_eq!(None, parse(bad), "parsed {bad:?}");
+    }
+}
+
+#[test]
+fn test_parse_socket_address() {
+    let parse = |s: &str| s.parse::<SocketAddress>().ok();
+    assert_eq!(
+        Some(SocketAddress::new(Ip::LOCALHOST_V4, 8080)),
+        parse("127.0.0.1:8080")
+    );
+    assert_eq!(
+        Some(SocketAddress::new(Ip::LOCALHOST_V6, 8080)),
+        parse("[::1]:8080")
+    );
+    assert_eq!(
+        Some(SocketAddress::new(Ip::UNSPECIFIED_V6, 65535)),
+        parse("[::]:65535")
+    );
+    let link_local = "fe80::1".parse::<Ip>().unwrap();
+    assert_eq!(
+        Some(SocketAddress::new(link_local, 80).with_scope_id(2)),
+        parse("[fe80::1%2]:80")
+    );
+    for bad in [
+        "127.0.0.1",
+        "127.0.0.1:",
+        "127.0.0.1:65536",
+        "127.0.0.1:+80",
+        "::1:8080",
+        "[::1]",
+        "[::1]8080",
+        "[127.0.0.1]:80",
+        "[::1]:80x",
+        "[fe80::1%]:80",
+        "[fe80::1%eth0]:80",
+        "[fe80::1%4294967296]:80",
+    ] {
+        assert_eq!(None, parse(bad), "parsed {bad:?}");
+    }
+    assert!(matches!(
+        "nope".parse::<SocketAddress>(),
+        Err(crate::Error::Uncategorized(_))
+    ));
+}
+
+#[test]
+fn test_display_ip() {
+    for (input, canonical) in [
+        ("127.0.0.1", "127.0.0.1"),
+        ("::", "::"),
+        ("::1", "::1"),
+        ("1::", "1::"),
+        ("2001:0DB8:0000:0000:0000:0000:0000:0001", "2001:db8::1"),
+        // Only one group of zeroes isn't compressed
+        ("2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1"),
+        // The longest run is compressed
+        ("2001:0:0:1:0:0:0:1", "2001:0:0:1::1"),
+        // The first one on ties
+        ("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+        ("::ffff:7f00:1", "::ffff:127.0.0.1"),
+        ("fe80::1:2", "fe80::1:2"),
+    ] {
+        let ip = input.parse::<Ip>().unwrap();
+        let displayed = std::format!("{ip}");
+        assert_eq!(canonical, displayed);
+        assert_eq!(ip, displayed.parse::<Ip>().unwrap());
+    }
+    assert_eq!(
+        "127.0.0.1:80",
+        std::format!("{}", SocketAddress::new(Ip::LOCALHOST_V4, 80))
+    );
+    assert_eq!(
+        "[::1]:443",
+        std::format!("{}", SocketAddress::new(Ip::LOCALHOST_V6, 443))
+    );
+    assert_eq!(
+        "[fe80::1%3]:443",
+        std::format!(
+            "{}",
+            SocketAddress::new("fe80::1".parse().unwrap(), 443).with_scope_id(3)
+        )
+    );
+}
+
+#[test]
+fn test_classify_ip() {
+    let parse = |s: &str| s.parse::<Ip>().unwrap();
+    assert!(parse("127.1.2.3").is_loopback());
+    assert!(Ip::LOCALHOST_V6.is_loopback());
+    assert!(!parse("::2").is_loopback());
+    assert!(Ip::UNSPECIFIED_V4.is_unspecified());
+    assert!(Ip::UNSPECIFIED_V6.is_unspecified());
+    assert!(!Ip::LOCALHOST_V4.is_unspecified());
+    for private in [
+        "10.1.1.1",
+        "172.16.0.1",
+        "172.31.255.255",
+        "192.168.1.1",
+        "fd00::1",
+    ] {
+        assert!(parse(private).is_private(), "{private} should be private");
+    }
+    for public in [
+        "172.32.0.1",
+        "192.169.0.1",
+        "8.8.8.8",
+        "2001:db8::1",
+        "fe80::1",
+    ] {
+        assert!(
+            !parse(public).is_private(),
+            "{public} should not be private"
+        );
+    }
+    assert!(parse("224.0.0.1").is_multicast());
+    assert!(parse("239.255.255.255").is_multicast());
+    assert!(!parse("240.0.0.1").is_multicast());
+    assert!(parse("ff02::1").is_multicast());
+    assert!(!parse("fe02::1").is_multicast());
+    let mapped = parse("::ffff:10.0.0.1");
+    assert!(!mapped.is_private());
+    assert_eq!(parse("10.0.0.1"), mapped.to_canonical());
+    assert!(mapped.to_canonical().is_private());
+    assert_eq!(Ip::LOCALHOST_V6, Ip::LOCALHOST_V6.to_canonical());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_unix_pass_mem_file() {
+    use crate::fs::MemFile;
+    let sock_path = UnixStr::try_from_str("/tmp/test-sock/sock-memfile\0").unwrap();
+    let _ = crate::fs::remove_file(sock_path);
+    crate::fs::create_dir_all(UnixStr::try_from_str("/tmp/test-sock/\0").unwrap()).unwrap();
+    let mut listener = UnixListener::bind(sock_path).unwrap();
+    let mut client = UnixStream::connect(sock_path).unwrap();
+    let mut server = listener.accept().unwrap();
+    let mut mf = MemFile::create(UnixStr::try_from_str("shared\0").unwrap(), true).unwrap();
+    mf.write_all(b"Shared buffer").unwrap();
+    assert_eq!(4, client.send_with_fds(b"memf", &[mf.as_raw_fd()]).unwrap());
+    let mut buf = [0u8; 8];
+    let (read, fds) = server.recv_with_fds(&mut buf, 2).unwrap();
+    assert_eq!(b"memf", &buf[..read]);
+    assert_eq!(1, fds.len());
+    assert_ne!(mf.as_raw_fd(), fds[0]);
+    let received = unsafe { MemFile::from_raw_fd(fds[0]) };
+    let map = unsafe { received.map(false) }.unwrap();
+    assert_eq!(b"Shared buffer", &*map);
+    // Plain data still goes through without fds
+    assert_eq!(2, client.send_with_fds(b"hi", &[]).unwrap());
+    let (read, fds) = server.recv_with_fds(&mut buf, 1).unwrap();
+    assert_eq!(b"hi", &buf[..read]);
+    assert!(fds.is_empty());
+}
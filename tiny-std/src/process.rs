@@ -5,10 +5,16 @@ use alloc::vec;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::hint::unreachable_unchecked;
+use core::time::Duration;
 
 use rusl::error::Errno;
-use rusl::platform::{Fd, GidT, OpenFlags, PidT, UidT, WaitPidFlags};
+use rusl::platform::{
+    ChildState, Fd, GidT, OpenFlags, PidFdOpenFlags, PidT, PollEvents, PollFd, TimeSpec, UidT,
+    WaitFlags, WaitIdInfo, WaitIdType, WaitPidFlags,
+};
+pub use rusl::platform::{RLimit, Resource, SignalKind};
 use rusl::platform::{STDERR, STDIN, STDOUT};
+use rusl::process::PrctlOption;
 use rusl::string::unix_str::UnixStr;
 #[cfg(feature = "alloc")]
 use rusl::string::unix_str::UnixString;
@@ -16,6 +22,7 @@ use rusl::string::unix_str::UnixString;
 use crate::error::{Error, Result};
 use crate::fs::OpenOptions;
 use crate::io::{Read, Write};
+use crate::time::MonotonicInstant;
 use crate::unix::fd::{BorrowedFd, OwnedFd, RawFd};
 
 const DEV_NULL: &UnixStr = UnixStr::from_str_checked("/dev/null\0");
@@ -26,6 +33,40 @@ pub fn exit(code: i32) -> ! {
     rusl::process::exit(code)
 }
 
+/// Get the soft and hard limit of `resource` for this process
+/// # Errors
+/// Os errors getting the limit
+#[inline]
+pub fn get_rlimit(resource: Resource) -> Result<RLimit> {
+    Ok(rusl::process::prlimit64(0, resource, None)?)
+}
+
+/// Set the soft and hard limit of `resource` for this process, children inherit limits
+/// # Errors
+/// The soft limit is above the hard limit, or raising the hard limit without `CAP_SYS_RESOURCE`
+#[inline]
+pub fn set_rlimit(resource: Resource, limit: RLimit) -> Result<()> {
+    rusl::process::prlimit64(0, resource, Some(&limit))?;
+    Ok(())
+}
+
+/// Get the id of this process
+#[inline]
+#[must_use]
+#[expect(clippy::cast_sign_loss)]
+pub fn id() -> u32 {
+    rusl::process::get_pid() as u32
+}
+
+/// Get the id of this process' parent, if the parent has exited
+/// this is the id of whichever process it was reparented to
+#[inline]
+#[must_use]
+#[expect(clippy::cast_sign_loss)]
+pub fn parent_id() -> u32 {
+    rusl::process::get_ppid() as u32
+}
+
 #[cfg(feature = "alloc")]
 pub struct Command<'a> {
     bin: &'a UnixStr,
@@ -40,6 +81,8 @@ pub struct Command<'a> {
     stdout: Option<Stdio>,
     stderr: Option<Stdio>,
     pgroup: Option<PidT>,
+    pdeathsig: Option<SignalKind>,
+    rlimits: Vec<(Resource, RLimit)>,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -86,6 +129,8 @@ impl<'a> Command<'a> {
             stdout: None,
             stderr: None,
             pgroup: None,
+            pdeathsig: None,
+            rlimits: vec![],
         })
     }
 
@@ -177,6 +222,21 @@ impl<'a> Command<'a> {
         self
     }
 
+    /// Signal the child gets when this parent thread exits, useful to not leave
+    /// orphaned children behind if the parent dies unexpectedly.
+    /// It's tied to the thread that spawns the child, not the whole process,
+    /// and is cleared if the child executes a setuid binary.
+    pub fn pdeathsig(&mut self, signal: SignalKind) -> &mut Self {
+        self.pdeathsig = Some(signal);
+        self
+    }
+
+    /// Set a resource limit for the child, applied before exec
+    pub fn rlimit(&mut self, resource: Resource, limit: RLimit) -> &mut Self {
+        self.rlimits.push((resource, limit));
+        self
+    }
+
     pub fn stdin(&mut self, stdin: Stdio) -> &mut Self {
         self.stdin = Some(stdin);
         self
@@ -218,6 +278,8 @@ impl<'a> Command<'a> {
                 self.uid,
                 self.gid,
                 self.pgroup,
+                self.pdeathsig,
+                &self.rlimits,
             )
         }
     }
@@ -251,6 +313,61 @@ impl Child {
     pub fn get_pid(&self) -> i32 {
         self.handle.pid
     }
+
+    /// Get the pidfd of this Child, if one has been opened with `open_pidfd`.
+    /// It becomes readable when the child exits, so it can be registered with an `EpollDriver`.
+    #[inline]
+    #[must_use]
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.handle.pidfd.as_ref().map(|fd| BorrowedFd::new(fd.0))
+    }
+
+    /// Open a pidfd for this Child, if one isn't already open.
+    /// Once opened it's used for waiting and signalling.
+    /// This is race-free since the child's pid can't be reused until it's been waited for,
+    /// an already waited for child will produce an error.
+    /// # Errors
+    /// The child has already been waited for, or os errors opening the pidfd,
+    /// such as `ENOSYS` on kernels older than 5.3
+    pub fn open_pidfd(&mut self) -> Result<BorrowedFd<'_>> {
+        self.handle.open_pidfd()?;
+        // Safety: Just opened or already present
+        let fd = unsafe { self.handle.pidfd.as_ref().unwrap_unchecked() };
+        Ok(BorrowedFd::new(fd.0))
+    }
+
+    /// Send a signal to this child, using its pidfd, one is opened temporarily if
+    /// this child doesn't have one.
+    /// On kernels without pidfd support the signal is sent by pid, which is still safe
+    /// since the pid can't be reused before the child is waited for.
+    /// # Errors
+    /// The child has already been waited for, or os errors sending the signal
+    pub fn signal(&self, signal: SignalKind) -> Result<()> {
+        if let Some(fd) = &self.handle.pidfd {
+            rusl::process::pidfd_send_signal(fd.0, signal)?;
+            return Ok(());
+        }
+        match self.handle.pidfd_open() {
+            Ok(fd) => {
+                let fd = OwnedFd(fd);
+                rusl::process::pidfd_send_signal(fd.0, signal)?;
+            }
+            Err(e) if e.matches_errno(Errno::ENOSYS) => {
+                rusl::process::kill(self.handle.pid, signal)?;
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
+    /// Forcibly terminate this child with `SIGKILL`, it still has to be waited for
+    /// # Errors
+    /// The child has already been waited for, or os errors sending the signal
+    #[inline]
+    pub fn kill(&self) -> Result<()> {
+        self.signal(SignalKind::SIGKILL)
+    }
+
     /// Waits for this child process to finish retuning its exit code
     /// # Errors
     /// Os errors relating to waiting for process
@@ -268,11 +385,44 @@ impl Child {
     pub fn try_wait(&mut self) -> Result<Option<i32>> {
         self.handle.try_wait()
     }
+
+    /// Waits at most `timeout` for this child process to finish, returns Ok(None) if
+    /// the child still hasn't finished, otherwise returns the exit code.
+    /// Opens a pidfd for the child if it doesn't have one.
+    /// # Errors
+    /// Os errors relating to waiting for process, or opening the pidfd
+    pub fn wait_timeout(&mut self, timeout: Duration) -> Result<Option<i32>> {
+        if let Some(status) = self.handle.status {
+            return Ok(Some(status));
+        }
+        self.handle.open_pidfd()?;
+        let Some(fd) = &self.handle.pidfd else {
+            return Err(Error::no_code("Failed to open pidfd for child"));
+        };
+        let start = MonotonicInstant::now();
+        let mut remaining = timeout;
+        loop {
+            let ts = TimeSpec::try_from(remaining)?;
+            let mut poll_fds = [PollFd::new(fd.0, PollEvents::POLLIN)];
+            match rusl::select::ppoll(&mut poll_fds, Some(&ts), None) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return self.handle.try_wait(),
+                Err(e) if e.code == Some(Errno::EINTR) => {
+                    let Some(left) = timeout.checked_sub(start.elapsed()) else {
+                        return self.handle.try_wait();
+                    };
+                    remaining = left;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
 }
 
 pub struct Process {
     pid: i32,
     status: Option<i32>,
+    pidfd: Option<OwnedFd>,
 }
 
 impl Process {
@@ -280,6 +430,13 @@ impl Process {
         if let Some(status) = self.status {
             return Ok(status);
         }
+        if let Some(fd) = &self.pidfd {
+            let info = rusl::process::wait_id(WaitIdType::PidFd(fd.0), WaitFlags::WEXITED)?
+                .ok_or_else(|| Error::no_code("Waitid returned no child without `WNOHANG`"))?;
+            let status = wait_status(&info);
+            self.status = Some(status);
+            return Ok(status);
+        }
         let res = rusl::process::wait_pid(self.pid, WaitPidFlags::empty())?;
         self.status = Some(res.status);
         Ok(res.status)
@@ -289,6 +446,18 @@ impl Process {
         if let Some(status) = self.status {
             return Ok(Some(status));
         }
+        if let Some(fd) = &self.pidfd {
+            let Some(info) = rusl::process::wait_id(
+                WaitIdType::PidFd(fd.0),
+                WaitFlags::WEXITED | WaitFlags::WNOHANG,
+            )?
+            else {
+                return Ok(None);
+            };
+            let status = wait_status(&info);
+            self.status = Some(status);
+            return Ok(Some(status));
+        }
         let res = rusl::process::wait_pid(self.pid, WaitPidFlags::WNOHANG)?;
         if res.pid == 0 {
             Ok(None)
@@ -297,6 +466,36 @@ impl Process {
             Ok(Some(res.status))
         }
     }
+
+    fn open_pidfd(&mut self) -> Result<()> {
+        if self.pidfd.is_none() {
+            self.pidfd = Some(OwnedFd(self.pidfd_open()?));
+        }
+        Ok(())
+    }
+
+    fn pidfd_open(&self) -> Result<Fd> {
+        if self.status.is_some() {
+            return Err(Error::no_code(
+                "Can't open a pidfd to a child that has already been waited for",
+            ));
+        }
+        Ok(rusl::process::pidfd_open(
+            self.pid,
+            PidFdOpenFlags::empty(),
+        )?)
+    }
+}
+
+/// Encode the result of a `waitid` the same way `wait4` encodes its status,
+/// so that the status is the same regardless of how the child was waited for.
+#[inline]
+fn wait_status(info: &WaitIdInfo) -> i32 {
+    match info.state() {
+        Some(ChildState::Exited) => (info.status() & 0xff) << 8,
+        Some(ChildState::Dumped) => info.status() | 0x80,
+        _ => info.status(),
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -449,11 +648,14 @@ unsafe fn do_spawn<F: PreExec>(
     uid: Option<UidT>,
     gid: Option<GidT>,
     pgroup: Option<PidT>,
+    pdeathsig: Option<SignalKind>,
+    rlimits: &[(Resource, RLimit)],
 ) -> Result<Child> {
     const CLOEXEC_MSG_FOOTER: [u8; 4] = *b"NOEX";
     let (ours, theirs) = setup_io(default_stdio, needs_stdin, stdin, stdout, stderr)?;
     let sync_pipe = rusl::unistd::pipe2(OpenFlags::O_CLOEXEC)?;
     let (read_pipe, write_pipe) = (sync_pipe.in_pipe, sync_pipe.out_pipe);
+    let parent_pid = rusl::process::get_pid();
     let child_pid = rusl::process::fork()?;
     // From this point we're two processes
     if child_pid == 0 {
@@ -480,6 +682,16 @@ unsafe fn do_spawn<F: PreExec>(
         if let Some(pgroup) = pgroup {
             rusl::unistd::setpgid(0, pgroup)?;
         }
+        for (resource, limit) in rlimits {
+            rusl::process::prlimit64(0, *resource, Some(limit))?;
+        }
+        if let Some(signal) = pdeathsig {
+            rusl::process::prctl(PrctlOption::SetPdeathsig(Some(signal)))?;
+            // The parent may have exited before the signal was set up
+            if rusl::process::get_ppid() != parent_pid {
+                rusl::process::kill(rusl::process::get_pid(), signal)?;
+            }
+        }
         for closure in closures {
             closure.run()?;
         }
@@ -509,6 +721,7 @@ unsafe fn do_spawn<F: PreExec>(
     let mut process = Process {
         pid: child_pid,
         status: None,
+        pidfd: None,
     };
     let mut bytes = [0, 0, 0, 0, 0, 0, 0, 0];
     loop {
@@ -607,6 +820,8 @@ pub fn spawn<const N: usize, CL: PreExec>(
             uid,
             gid,
             pgroup,
+            None,
+            &[],
         )
     }
 }
@@ -683,3 +898,120 @@ fn setup_io(
     };
     Ok((ours, theirs))
 }
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use core::time::Duration;
+
+    use rusl::unix_lit;
+
+    use super::*;
+    use crate::linux::epoll::{EpollDriver, EpollEvent, EpollEventMask, EpollTimeout};
+    use crate::unix::fd::AsRawFd;
+
+    #[test]
+    fn kill_terminates_child() {
+        let mut child = Command::new(unix_lit!("/bin/sh"))
+            .unwrap()
+            .arg(unix_lit!("-c"))
+            .arg(unix_lit!("sleep 10"))
+            .spawn()
+            .unwrap();
+        child.kill().unwrap();
+        // Killed by signal, no exit code
+        assert_eq!(SignalKind::SIGKILL.value(), child.wait().unwrap() & 0x7f);
+        assert!(child.kill().is_err());
+    }
+
+    #[test]
+    fn pdeathsig_is_set_before_exec() {
+        let mut cmd = Command::new(unix_lit!("/bin/sh")).unwrap();
+        cmd.arg(unix_lit!("-c"))
+            .arg(unix_lit!("exit 0"))
+            .pdeathsig(SignalKind::SIGTERM);
+        // Runs in the child after the signal is set, exits with 3 if it isn't
+        unsafe {
+            cmd.pre_exec(|| {
+                let mut sig = 0;
+                rusl::process::prctl(PrctlOption::GetPdeathsig(&mut sig))?;
+                if sig != SignalKind::SIGTERM.value() {
+                    rusl::process::exit(3);
+                }
+                Ok(())
+            });
+        }
+        let mut child = cmd.spawn().unwrap();
+        assert_eq!(0, child.wait().unwrap());
+    }
+
+    #[test]
+    fn rlimit_applies_to_child_only() {
+        let before = get_rlimit(Resource::Nofile).unwrap();
+        let mut child = Command::new(unix_lit!("/bin/sh"))
+            .unwrap()
+            .arg(unix_lit!("-c"))
+            .arg(unix_lit!("test \"$(ulimit -n)\" = 64"))
+            .rlimit(Resource::Nofile, RLimit::new(64, before.max))
+            .spawn()
+            .unwrap();
+        assert_eq!(0, child.wait().unwrap());
+        assert_eq!(before, get_rlimit(Resource::Nofile).unwrap());
+        set_rlimit(Resource::Nofile, before).unwrap();
+        assert!(set_rlimit(Resource::Nofile, RLimit::new(2, 1)).is_err());
+    }
+
+    #[test]
+    fn ids_match_std() {
+        assert_eq!(std::process::id(), id());
+        assert_eq!(std::os::unix::process::parent_id(), parent_id());
+    }
+
+    #[test]
+    fn pidfd_wait_gives_same_status() {
+        let mut child = Command::new(unix_lit!("/bin/sh"))
+            .unwrap()
+            .arg(unix_lit!("-c"))
+            .arg(unix_lit!("exit 3"))
+            .spawn()
+            .unwrap();
+        child.open_pidfd().unwrap();
+        assert_eq!(3 << 8, child.wait().unwrap());
+        assert!(child.signal(SignalKind::SIGTERM).is_err());
+    }
+
+    #[test]
+    fn pidfd_readable_on_exit() {
+        let mut child = Command::new(unix_lit!("/bin/sh"))
+            .unwrap()
+            .arg(unix_lit!("-c"))
+            .arg(unix_lit!("exit 0"))
+            .spawn()
+            .unwrap();
+        let pidfd = child.open_pidfd().unwrap().as_raw_fd();
+        let driver = EpollDriver::create(true).unwrap();
+        driver.register(pidfd, 1, EpollEventMask::EPOLLIN).unwrap();
+        let mut events = [EpollEvent::new(0, EpollEventMask::empty())];
+        let ready = driver
+            .wait(&mut events, EpollTimeout::WaitMillis(5_000))
+            .unwrap();
+        assert_eq!(1, ready);
+        assert_eq!(Some(0), child.try_wait().unwrap());
+    }
+
+    #[test]
+    fn signal_and_wait_timeout() {
+        let mut child = Command::new(unix_lit!("/bin/sh"))
+            .unwrap()
+            .arg(unix_lit!("-c"))
+            .arg(unix_lit!("sleep 10"))
+            .spawn()
+            .unwrap();
+        assert!(child.pidfd().is_none());
+        assert_eq!(None, child.wait_timeout(Duration::from_millis(10)).unwrap());
+        assert!(child.pidfd().is_some());
+        child.signal(SignalKind::SIGKILL).unwrap();
+        let status = child.wait_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(Some(SignalKind::SIGKILL.value()), status);
+    }
+}
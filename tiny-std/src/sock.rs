@@ -5,15 +5,17 @@ use rusl::{
     platform::{NonNegativeI32, PollEvents, PollFd, TimeSpec},
 };
 
+use crate::time::MonotonicInstant;
+
 pub(crate) fn sock_nonblock_op_poll_if_not_ready<
     T,
-    F: Fn(NonNegativeI32) -> Result<T, rusl::Error>,
+    F: FnMut(NonNegativeI32) -> Result<T, rusl::Error>,
 >(
     sock: NonNegativeI32,
     block_errno: Errno,
     ready_event: PollEvents,
     timeout: Option<Duration>,
-    op: F,
+    mut op: F,
 ) -> Result<T, crate::Error> {
     let ts = if let Some(to) = timeout {
         Some(TimeSpec::try_from(to)?)
@@ -43,6 +45,44 @@ pub(crate) fn sock_nonblock_op_poll_if_not_ready<
     }
 }
 
+/// Like [`sock_nonblock_op_poll_if_not_ready`] but keeps retrying until `op` succeeds or the
+/// `timeout` passes. For ops where being polled ready doesn't guarantee that the retry won't block,
+/// such as a datagram being taken by another thread reading from the same socket.
+pub(crate) fn sock_nonblock_op_poll_until_ready<
+    T,
+    F: FnMut(NonNegativeI32) -> Result<T, rusl::Error>,
+>(
+    sock: NonNegativeI32,
+    block_errno: Errno,
+    ready_event: PollEvents,
+    timeout: Option<Duration>,
+    mut op: F,
+) -> Result<T, crate::Error> {
+    let start = MonotonicInstant::now();
+    let pollfd = PollFd::new(sock, ready_event);
+    loop {
+        match op(sock) {
+            Ok(o) => return Ok(o),
+            Err(e) if e.code == Some(block_errno) => {}
+            Err(e) => return Err(e.into()),
+        }
+        let ts = if let Some(to) = timeout {
+            let Some(remaining) = to.checked_sub(start.elapsed()) else {
+                return Err(crate::Error::Timeout);
+            };
+            Some(TimeSpec::try_from(remaining)?)
+        } else {
+            None
+        };
+        match rusl::select::ppoll(&mut [pollfd], ts.as_ref(), None) {
+            Ok(0) => return Err(crate::Error::Timeout),
+            Ok(_) => {}
+            Err(e) if e.code == Some(Errno::EINTR) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 pub(crate) fn blocking_read_nonblock_sock(
     sock: NonNegativeI32,
     buf: &mut [u8],
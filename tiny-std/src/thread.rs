@@ -3,9 +3,11 @@
 #[cfg(all(feature = "threaded", feature = "symbols"))]
 pub(crate) mod spawn;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use core::num::NonZeroUsize;
 use core::time::Duration;
 use rusl::error::Errno;
+pub use rusl::platform::CpuSet;
 #[cfg(all(feature = "threaded", feature = "symbols"))]
 pub use spawn::*;
 
@@ -23,11 +25,71 @@ pub fn sleep(duration: Duration) -> Result<()> {
     }
 }
 
+/// Give up the CPU to let other threads run
+#[inline]
+pub fn yield_now() {
+    rusl::process::sched_yield();
+}
+
+/// The number of CPUs the current thread is allowed to run on,
+/// which may be fewer than the number of CPUs on the machine
+/// # Errors
+/// The affinity can't be read, which shouldn't happen
+pub fn available_parallelism() -> Result<NonZeroUsize> {
+    let count = match rusl::process::sched_getaffinity(0) {
+        Ok(set) => set.count(),
+        // The kernel's mask doesn't fit in a `CpuSet`,
+        // the kernel can't be configured for more than 8192 CPUs
+        Err(e) if e.code == Some(Errno::EINVAL) => {
+            let mut mask = [0u64; 8192 / 64];
+            let written = rusl::process::sched_getaffinity_mask(0, &mut mask)?;
+            mask[..written / 8]
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum()
+        }
+        Err(e) => return Err(e.into()),
+    };
+    NonZeroUsize::new(count)
+        .ok_or_else(|| Error::no_code("Current thread has an empty CPU affinity"))
+}
+
+/// Get the CPUs that the current thread is allowed to run on
+/// # Errors
+/// The affinity can't be read, `EINVAL` on machines where the kernel is configured
+/// for more than [`CpuSet::MAX_CPUS`] CPUs
+#[inline]
+pub fn affinity() -> Result<CpuSet> {
+    Ok(rusl::process::sched_getaffinity(0)?)
+}
+
+/// Restrict the current thread to run on the CPUs in `set`.
+/// Threads spawned afterwards inherit the affinity.
+/// # Errors
+/// `set` contains no CPUs that are online and allowed by the thread's cpuset
+#[inline]
+pub fn set_affinity(set: &CpuSet) -> Result<()> {
+    rusl::process::sched_setaffinity(0, set)?;
+    Ok(())
+}
+
+/// Pin the current thread to a single CPU
+/// # Errors
+/// See [`set_affinity`]
+#[inline]
+pub fn pin_to_cpu(cpu: usize) -> Result<()> {
+    let mut set = CpuSet::new();
+    set.add(cpu);
+    set_affinity(&set)
+}
+
 #[cfg(test)]
 mod tests {
     use core::time::Duration;
 
-    use crate::thread::sleep;
+    use crate::thread::{
+        affinity, available_parallelism, pin_to_cpu, set_affinity, sleep, yield_now, CpuSet,
+    };
     use crate::time::MonotonicInstant;
 
     #[test]
@@ -38,4 +100,24 @@ mod tests {
         let elapsed = now.elapsed();
         assert!(elapsed > sleep_dur);
     }
+
+    #[test]
+    fn parallelism_matches_affinity() {
+        let set = affinity().unwrap();
+        assert_eq!(set.count(), available_parallelism().unwrap().get());
+    }
+
+    #[test]
+    fn pin_and_restore() {
+        let orig = affinity().unwrap();
+        let last = orig.iter().last().unwrap();
+        pin_to_cpu(last).unwrap();
+        yield_now();
+        let pinned = affinity().unwrap();
+        assert_eq!(1, pinned.count());
+        assert!(pinned.contains(last));
+        assert!(set_affinity(&CpuSet::new()).is_err());
+        set_affinity(&orig).unwrap();
+        assert_eq!(orig, affinity().unwrap());
+    }
 }
@@ -1,9 +1,12 @@
 use core::time::Duration;
 
-use rusl::platform::TimeSpec;
+pub use rusl::platform::{ClockId, TimeSpec};
+
+pub use timer::Timer;
 
 #[cfg(test)]
 mod test;
+mod timer;
 
 pub const UNIX_TIME: SystemTime = SystemTime(TimeSpec::new_zeroed());
 
@@ -150,6 +153,13 @@ impl From<TimeSpec> for SystemTime {
     }
 }
 
+impl From<SystemTime> for TimeSpec {
+    #[inline]
+    fn from(value: SystemTime) -> Self {
+        value.0
+    }
+}
+
 #[inline]
 fn checked_add_dur(timespec: TimeSpec, duration: Duration) -> Option<TimeSpec> {
     // tv_nsec are < `NANOS_A_SECOND`, this cannot overflow
@@ -0,0 +1,268 @@
+use core::time::Duration;
+
+use rusl::platform::{ClockId, ITimerSpec, TimeSpec, TimerFdFlags, TimerFdSetFlags};
+
+use crate::error::{Error, Result};
+use crate::time::{Instant, SystemTime};
+use crate::unix::fd::{AsRawFd, OwnedFd, RawFd};
+
+/// A timer backed by a file descriptor, it becomes readable when it expires, which means
+/// it can be registered with an `EpollDriver` alongside other fds.
+/// Starts disarmed.
+pub struct Timer {
+    fd: OwnedFd,
+    clock: ClockId,
+}
+
+impl Timer {
+    /// Create a new disarmed timer measuring time on `clock`.
+    /// If `nonblocking`, reading expirations from a timer that hasn't expired returns
+    /// an `EAGAIN` error instead of waiting for it to expire.
+    /// # Errors
+    /// Os errors creating the timer, such as an unsupported `clock`
+    pub fn new(clock: ClockId, nonblocking: bool) -> Result<Self> {
+        let flags = if nonblocking {
+            TimerFdFlags::TFD_CLOEXEC | TimerFdFlags::TFD_NONBLOCK
+        } else {
+            TimerFdFlags::TFD_CLOEXEC
+        };
+        let fd = rusl::time::timerfd_create(clock, flags)?;
+        Ok(Self {
+            fd: OwnedFd(fd),
+            clock,
+        })
+    }
+
+    /// The clock this timer measures time on
+    #[inline]
+    #[must_use]
+    pub fn clock(&self) -> ClockId {
+        self.clock
+    }
+
+    /// Arm the timer to expire once, after `after` has passed.
+    /// A zero `after` disarms the timer.
+    /// # Errors
+    /// Os errors arming the timer, or a `Duration` that doesn't fit into a `TimeSpec`
+    pub fn set_oneshot(&self, after: Duration) -> Result<()> {
+        self.set(
+            TimerFdSetFlags::empty(),
+            TimeSpec::try_from(after)?,
+            TimeSpec::new_zeroed(),
+        )
+    }
+
+    /// Arm the timer to expire every `interval`, starting one `interval` from now.
+    /// # Errors
+    /// Os errors arming the timer, or a `Duration` that doesn't fit into a `TimeSpec`
+    pub fn set_periodic(&self, interval: Duration) -> Result<()> {
+        let ts = TimeSpec::try_from(interval)?;
+        self.set(TimerFdSetFlags::empty(), ts, ts)
+    }
+
+    /// Arm the timer to expire at `deadline`, then every `interval` after that if one is provided.
+    /// The timer has to measure time on `CLOCK_MONOTONIC`, the clock of `Instant`.
+    /// A `deadline` that has already passed expires immediately.
+    /// # Errors
+    /// The timer measures time on another clock, os errors arming the timer,
+    /// or a `Duration` that doesn't fit into a `TimeSpec`
+    pub fn set_deadline(&self, deadline: Instant, interval: Option<Duration>) -> Result<()> {
+        self.set_absolute(&[ClockId::CLOCK_MONOTONIC], deadline.0, interval)
+    }
+
+    /// Arm the timer to expire at `deadline`, then every `interval` after that if one is provided.
+    /// The timer has to measure time on `CLOCK_REALTIME` or `CLOCK_REALTIME_ALARM`,
+    /// the clocks of `SystemTime`.
+    /// A `deadline` that has already passed expires immediately.
+    /// # Errors
+    /// The timer measures time on another clock, os errors arming the timer,
+    /// or a `Duration` that doesn't fit into a `TimeSpec`
+    pub fn set_system_deadline(
+        &self,
+        deadline: SystemTime,
+        interval: Option<Duration>,
+    ) -> Result<()> {
+        self.set_absolute(
+            &[ClockId::CLOCK_REALTIME, ClockId::CLOCK_REALTIME_ALARM],
+            deadline.into(),
+            interval,
+        )
+    }
+
+    /// Arm the timer to expire at the absolute `deadline` as measured by this timer's clock,
+    /// then every `interval` after that if one is provided.
+    /// For clocks that have no time type, such as `CLOCK_BOOTTIME`.
+    /// A `deadline` that has already passed expires immediately.
+    /// # Errors
+    /// Os errors arming the timer, or a `Duration` that doesn't fit into a `TimeSpec`
+    pub fn set_absolute_deadline(
+        &self,
+        deadline: TimeSpec,
+        interval: Option<Duration>,
+    ) -> Result<()> {
+        let interval = interval
+            .map(TimeSpec::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        self.set(TimerFdSetFlags::TFD_TIMER_ABSTIME, deadline, interval)
+    }
+
+    /// Disarm the timer, pending expirations that haven't been read are discarded.
+    /// # Errors
+    /// Os errors disarming the timer
+    pub fn disarm(&self) -> Result<()> {
+        self.set(
+            TimerFdSetFlags::empty(),
+            TimeSpec::new_zeroed(),
+            TimeSpec::new_zeroed(),
+        )
+    }
+
+    /// Get the time left until the next expiration, `None` if the timer is disarmed.
+    /// # Errors
+    /// Os errors getting the timer setting
+    pub fn remaining(&self) -> Result<Option<Duration>> {
+        let cur = rusl::time::timerfd_gettime(self.fd.0)?;
+        Ok(ts_to_duration(cur.value()))
+    }
+
+    /// Get the interval of the timer, `None` if it isn't periodic.
+    /// # Errors
+    /// Os errors getting the timer setting
+    pub fn interval(&self) -> Result<Option<Duration>> {
+        let cur = rusl::time::timerfd_gettime(self.fd.0)?;
+        Ok(ts_to_duration(cur.interval()))
+    }
+
+    /// Read the number of times the timer has expired since it was armed or last read,
+    /// resetting the count.
+    /// Waits for an expiration if the timer is blocking and there hasn't been one yet.
+    /// # Errors
+    /// Os errors reading, if nonblocking, `EAGAIN` if the timer hasn't expired.
+    pub fn read_expirations(&self) -> Result<u64> {
+        let mut buf = [0u8; 8];
+        let read = rusl::unistd::read(self.fd.0, &mut buf)?;
+        if read != buf.len() {
+            return Err(Error::no_code(
+                "Got a partial expiration count from a timerfd",
+            ));
+        }
+        Ok(u64::from_ne_bytes(buf))
+    }
+
+    fn set_absolute(
+        &self,
+        clocks: &[ClockId],
+        deadline: TimeSpec,
+        interval: Option<Duration>,
+    ) -> Result<()> {
+        if !clocks
+            .iter()
+            .any(|clock| clock.into_i32() == self.clock.into_i32())
+        {
+            return Err(Error::no_code(
+                "Tried to set a timer deadline measured on a different clock than the timer's",
+            ));
+        }
+        self.set_absolute_deadline(deadline, interval)
+    }
+
+    #[inline]
+    fn set(&self, flags: TimerFdSetFlags, value: TimeSpec, interval: TimeSpec) -> Result<()> {
+        rusl::time::timerfd_settime(self.fd.0, flags, &ITimerSpec::new(value, interval))?;
+        Ok(())
+    }
+}
+
+impl AsRawFd for Timer {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+#[inline]
+fn ts_to_duration(ts: TimeSpec) -> Option<Duration> {
+    if ts == TimeSpec::new_zeroed() {
+        return None;
+    }
+    Some(Duration::new(
+        u64::try_from(ts.seconds()).ok()?,
+        u32::try_from(ts.nanoseconds()).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use rusl::error::Errno;
+
+    use super::*;
+    use crate::linux::epoll::{EpollDriver, EpollEvent, EpollEventMask, EpollTimeout};
+
+    #[test]
+    fn oneshot_expires_once() {
+        let timer = Timer::new(ClockId::CLOCK_MONOTONIC, false).unwrap();
+        assert!(timer.remaining().unwrap().is_none());
+        timer.set_oneshot(Duration::from_millis(1)).unwrap();
+        assert!(timer.interval().unwrap().is_none());
+        assert_eq!(1, timer.read_expirations().unwrap());
+        assert!(timer.remaining().unwrap().is_none());
+    }
+
+    #[test]
+    fn periodic_counts_expirations() {
+        let timer = Timer::new(ClockId::CLOCK_MONOTONIC, true).unwrap();
+        let err = timer.read_expirations().unwrap_err();
+        assert!(err.matches_errno(Errno::EAGAIN));
+        timer.set_periodic(Duration::from_millis(1)).unwrap();
+        assert_eq!(Some(Duration::from_millis(1)), timer.interval().unwrap());
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(timer.read_expirations().unwrap() >= 2);
+        timer.disarm().unwrap();
+        assert!(timer.remaining().unwrap().is_none());
+    }
+
+    #[test]
+    fn deadline_wakes_epoll() {
+        let timer = Timer::new(ClockId::CLOCK_MONOTONIC, true).unwrap();
+        assert!(timer.set_system_deadline(SystemTime::now(), None).is_err());
+        timer.set_deadline(Instant::now(), None).unwrap();
+        let driver = EpollDriver::create(true).unwrap();
+        driver
+            .register(timer.as_raw_fd(), 3, EpollEventMask::EPOLLIN)
+            .unwrap();
+        let mut events = [EpollEvent::new(0, EpollEventMask::empty())];
+        let ready = driver
+            .wait(&mut events, EpollTimeout::WaitMillis(1_000))
+            .unwrap();
+        assert_eq!(1, ready);
+        assert_eq!(3, events[0].get_data());
+        assert_eq!(1, timer.read_expirations().unwrap());
+    }
+
+    #[test]
+    fn system_deadline_expires() {
+        let timer = Timer::new(ClockId::CLOCK_REALTIME, false).unwrap();
+        assert!(timer.set_deadline(Instant::now(), None).is_err());
+        let deadline = (SystemTime::now() + Duration::from_millis(5)).unwrap();
+        timer
+            .set_system_deadline(deadline, Some(Duration::from_secs(30)))
+            .unwrap();
+        assert_eq!(Some(Duration::from_secs(30)), timer.interval().unwrap());
+        assert_eq!(1, timer.read_expirations().unwrap());
+        assert!(SystemTime::now() >= deadline);
+    }
+
+    #[test]
+    fn boottime_absolute_deadline_expires() {
+        let timer = Timer::new(ClockId::CLOCK_BOOTTIME, false).unwrap();
+        assert!(timer.set_deadline(Instant::now(), None).is_err());
+        let now = rusl::time::clock_get_time(ClockId::CLOCK_BOOTTIME).unwrap();
+        let deadline =
+            TimeSpec::try_from(ts_to_duration(now).unwrap() + Duration::from_millis(5)).unwrap();
+        timer.set_absolute_deadline(deadline, None).unwrap();
+        assert!(timer.remaining().unwrap().unwrap() <= Duration::from_millis(5));
+        assert_eq!(1, timer.read_expirations().unwrap());
+        assert!(rusl::time::clock_get_time(ClockId::CLOCK_BOOTTIME).unwrap() >= deadline);
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "cli")]
 pub mod cli;
+pub mod credentials;
 pub mod fd;
 pub mod host_name;
 pub mod misc;
@@ -8,3 +9,7 @@ pub mod print;
 pub mod random;
 #[cfg(feature = "symbols")]
 mod symbols;
+
+#[cfg(feature = "alloc")]
+pub use credentials::groups;
+pub use credentials::{credentials, Credentials};
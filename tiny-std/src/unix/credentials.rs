@@ -0,0 +1,84 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use rusl::error::Errno;
+use rusl::platform::{GidT, UidT};
+
+use crate::error::Result;
+
+/// The real and effective user and group ids of this process
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Credentials {
+    pub uid: UidT,
+    pub euid: UidT,
+    pub gid: GidT,
+    pub egid: GidT,
+}
+
+/// Get the credentials of this process, unlike `elf::aux::get_uid` these reflect
+/// changes made after startup and include the effective ids
+/// # Errors
+/// Os errors getting the ids, these syscalls don't fail on Linux
+pub fn credentials() -> Result<Credentials> {
+    Ok(Credentials {
+        uid: rusl::unistd::get_uid()?,
+        euid: rusl::unistd::get_euid()?,
+        gid: rusl::unistd::get_gid()?,
+        egid: rusl::unistd::get_egid()?,
+    })
+}
+
+/// Get the supplementary group ids of this process
+/// # Errors
+/// Os errors getting the groups
+#[cfg(feature = "alloc")]
+pub fn groups() -> Result<Vec<GidT>> {
+    loop {
+        let num = rusl::unistd::getgroups(&mut [])?;
+        let mut groups = alloc::vec![0; num];
+        match rusl::unistd::getgroups(&mut groups) {
+            Ok(written) => {
+                groups.truncate(written);
+                return Ok(groups);
+            }
+            // Groups were added in between the calls
+            Err(e) if e.code == Some(Errno::EINVAL) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use rusl::unix_lit;
+
+    /// The ids on the line starting with `prefix` in `/proc/self/status`
+    fn proc_status_ids(prefix: &str) -> Vec<u32> {
+        let status = crate::fs::read_to_string(unix_lit!("/proc/self/status")).unwrap();
+        let line = status.lines().find(|l| l.starts_with(prefix)).unwrap();
+        line[prefix.len()..]
+            .split_whitespace()
+            .map(|id| id.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn credentials_match_proc_status() {
+        let creds = crate::unix::credentials().unwrap();
+        let uids = proc_status_ids("Uid:");
+        let gids = proc_status_ids("Gid:");
+        assert_eq!(uids[0], creds.uid);
+        assert_eq!(uids[1], creds.euid);
+        assert_eq!(gids[0], creds.gid);
+        assert_eq!(gids[1], creds.egid);
+    }
+
+    #[test]
+    fn groups_match_proc_status() {
+        let groups = crate::unix::groups().unwrap();
+        assert_eq!(proc_status_ids("Groups:"), groups);
+    }
+}
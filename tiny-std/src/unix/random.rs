@@ -1,50 +1,57 @@
+use core::ops::{Bound, RangeBounds};
+use core::sync::atomic::{AtomicU64, Ordering};
+
 use crate::error::Result;
-use crate::fs::File;
-use crate::io::Read;
 use rusl::error::Errno;
-use rusl::string::unix_str::UnixStr;
-
-const DEV_RANDOM: &UnixStr = UnixStr::from_str_checked("/dev/random\0");
+use rusl::platform::GetRandomFlags;
 
-/// Fills the provided buffer with random bytes from /dev/random.
+/// Fills the provided buffer with random bytes from the kernel's CSPRNG through `getrandom`.
+/// Blocks until the entropy pool is initialized, which only matters very early in boot.
 /// # Errors
-/// File permissions failure
+/// The syscall isn't available, which it is since Linux 3.17
 pub fn system_random(buf: &mut [u8]) -> Result<()> {
-    let mut file = File::open(DEV_RANDOM)?;
+    fill_with_getrandom(buf, GetRandomFlags::empty())
+}
+
+/// Like [`system_random`] but never blocks, if the entropy pool isn't initialized
+/// the bytes may be of lower quality.
+/// # Errors
+/// The syscall isn't available, which it is since Linux 3.17
+pub fn system_random_nonblocking(buf: &mut [u8]) -> Result<()> {
+    match fill_with_getrandom(buf, GetRandomFlags::GRND_INSECURE) {
+        // `GRND_INSECURE` was added in 5.6
+        Err(e) if e.matches_errno(Errno::EINVAL) => {
+            fill_with_getrandom(buf, GetRandomFlags::GRND_NONBLOCK)
+        }
+        res => res,
+    }
+}
+
+fn fill_with_getrandom(buf: &mut [u8], flags: GetRandomFlags) -> Result<()> {
     let mut offset = 0;
     while offset < buf.len() {
-        match file.read(&mut buf[offset..]) {
+        match rusl::unistd::getrandom(&mut buf[offset..], flags) {
             Ok(read) => {
                 offset += read;
             }
-            Err(e) => {
-                if e.matches_errno(Errno::EINTR) {
-                    continue;
-                }
-                return Err(e);
-            }
+            Err(ref e) if e.code == Some(Errno::EINTR) => {}
+            Err(e) => return Err(e.into()),
         }
     }
     Ok(())
 }
 
-/// A small, basic [PRNG](https://en.wikipedia.org/wiki/Pseudorandom_number_generator)
-/// implemented as an [LCG](https://en.wikipedia.org/wiki/Linear_congruential_generator).
-/// Should never be used for security-critical things, but can be used as a high-performance
-/// source of pseudo-randomness.
+/// A fast [PRNG](https://en.wikipedia.org/wiki/Pseudorandom_number_generator)
+/// implemented as [xoshiro256**](https://prng.di.unimi.it/).
+/// Should never be used for security-critical things, its output can be predicted after
+/// observing a few values, but can be used as a high-performance source of pseudo-randomness.
 /// For secure randoms [`system_random`] is more appropriate.
+#[derive(Debug, Clone)]
 pub struct Prng {
-    seed: u64,
+    state: [u64; 4],
 }
 
 impl Prng {
-    /// Choosing same constants as glibc here.
-    /// [LCG](https://en.wikipedia.org/wiki/Linear_congruential_generator)
-    /// Yanked my implementation from [tiny-bench, license here](https://github.com/EmbarkStudios/tiny-bench/blob/main/LICENSE-MIT)
-    const MOD: u128 = 2u128.pow(48);
-    const A: u128 = 25_214_903_917;
-    const C: u128 = 11;
-
     /// Create a new Prng-instance seeding with a [`crate::time::MonotonicInstant`], this is fine because
     /// you shouldn't need a secure seed anyway, because you should not use this for
     /// security-purposes at all.
@@ -55,24 +62,136 @@ impl Prng {
         let time_nanos_in_u64 = (time.0.seconds() as u64)
             .overflowing_add(time.0.nanoseconds() as u64)
             .0;
-        Prng {
-            seed: time_nanos_in_u64,
+        Self::new(time_nanos_in_u64)
+    }
+
+    /// Create a new Prng-instance with a seed that differs between instances and runs.
+    /// Uses the random bytes the kernel passes in the aux values when available, avoiding a syscall,
+    /// otherwise non-blocking `getrandom`, and the time as a last resort.
+    #[must_use]
+    #[cfg_attr(feature = "aux", expect(clippy::cast_possible_truncation))]
+    pub fn new_random_seeded() -> Self {
+        // The aux random value is the same for the whole run, mix in a counter so that
+        // instances are unique
+        static INSTANCES: AtomicU64 = AtomicU64::new(0);
+        let instance = INSTANCES.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "aux")]
+        if let Some(aux) = crate::elf::aux::get_random() {
+            let mut seed = (aux as u64) ^ ((aux >> 64) as u64);
+            let mut counter = instance;
+            seed ^= splitmix64(&mut counter);
+            return Self::new(seed);
         }
+        let mut buf = [0u8; 32];
+        if system_random_nonblocking(&mut buf).is_ok() {
+            let mut state = [0u64; 4];
+            for (word, chunk) in state.iter_mut().zip(buf.chunks_exact(8)) {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(chunk);
+                *word = u64::from_ne_bytes(bytes);
+            }
+            if state != [0; 4] {
+                return Prng { state };
+            }
+        }
+        let mut prng = Self::new_time_seeded();
+        prng.state[0] ^= instance;
+        prng
     }
 
-    /// Create a new prng-instance with the provided seed
+    /// Create a new prng-instance with the provided seed,
+    /// the same seed always produces the same sequence
     #[inline]
     #[must_use]
     pub fn new(seed: u64) -> Self {
+        // Expand the seed with splitmix64 as recommended by the xoshiro authors,
+        // this never produces the all-zero state which xoshiro can't escape
+        let mut sm = seed;
         Prng {
-            // And maybe check for overflows. Note: No we're good until about year 2554
-            seed,
+            state: [
+                splitmix64(&mut sm),
+                splitmix64(&mut sm),
+                splitmix64(&mut sm),
+                splitmix64(&mut sm),
+            ],
         }
     }
 
+    /// Get the next pseudo-random `u64`
+    #[inline]
     pub fn next_u64(&mut self) -> u64 {
-        self.seed = ((Self::A * u128::from(self.seed) + Self::C) % Self::MOD) as u64;
-        self.seed
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Get the next pseudo-random `u32`
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        // The high bits are the better ones
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Fill the provided buffer with pseudo-random bytes
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_ne_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Get a uniformly distributed value in `range`, without modulo bias
+    /// # Panics
+    /// `range` is empty
+    pub fn gen_range<R: RangeBounds<u64>>(&mut self, range: R) -> u64 {
+        let low = match range.start_bound() {
+            Bound::Included(low) => *low,
+            Bound::Excluded(low) => low.checked_add(1).expect("Empty range"),
+            Bound::Unbounded => 0,
+        };
+        let high = match range.end_bound() {
+            Bound::Included(high) => *high,
+            Bound::Excluded(high) => high.checked_sub(1).expect("Empty range"),
+            Bound::Unbounded => u64::MAX,
+        };
+        assert!(low <= high, "Empty range");
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.below(span),
+            // The full range of `u64`
+            None => self.next_u64(),
+        }
+    }
+
+    /// Shuffle `slice` in place, every permutation is equally likely
+    /// as long as the number of permutations is small compared to the period of the generator
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+
+    /// Lemire's nearly divisionless method, `bound` must be non-zero
+    #[expect(clippy::cast_possible_truncation)]
+    fn below(&mut self, bound: u64) -> u64 {
+        let mut m = u128::from(self.next_u64()) * u128::from(bound);
+        let mut low = m as u64;
+        if low < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while low < threshold {
+                m = u128::from(self.next_u64()) * u128::from(bound);
+                low = m as u64;
+            }
+        }
+        (m >> 64) as u64
     }
 }
 
@@ -85,6 +204,15 @@ impl Iterator for Prng {
     }
 }
 
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +230,9 @@ mod tests {
         }
         // Could calculate the actual probability for this.
         assert!(count_zero < 32, "After filling a buf with random bytes {count_zero} zeroes were found, should be around 16.");
+        let mut other = [0u8; 4096];
+        system_random_nonblocking(&mut other).unwrap();
+        assert_ne!(buf, other);
     }
 
     #[test]
@@ -115,12 +246,77 @@ mod tests {
         }
         // Sweet determinism
         assert_eq!(0, count_zero);
+        let mut a = Prng::new(55);
+        let mut b = Prng::new(55);
+        for _ in 0..64 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn matches_reference_output() {
+        // From the reference implementation, with the state set directly
+        let mut prng = Prng {
+            state: [1, 2, 3, 4],
+        };
+        assert_eq!(11520, prng.next_u64());
+        assert_eq!(0, prng.next_u64());
+        assert_eq!(1_509_978_240, prng.next_u64());
+        assert_eq!(1_215_971_899_390_074_240, prng.next_u64());
     }
 
     #[test]
     fn prng_seeded_not_same() {
-        let time_seeded1 = Prng::new_time_seeded();
-        let time_seeded2 = Prng::new_time_seeded();
-        assert_ne!(time_seeded1.seed, time_seeded2.seed);
+        let mut time_seeded1 = Prng::new_time_seeded();
+        let mut time_seeded2 = Prng::new_time_seeded();
+        assert_ne!(time_seeded1.next_u64(), time_seeded2.next_u64());
+        let mut random_seeded1 = Prng::new_random_seeded();
+        let mut random_seeded2 = Prng::new_random_seeded();
+        assert_ne!(random_seeded1.next_u64(), random_seeded2.next_u64());
+    }
+
+    #[test]
+    fn gen_range_bounds() {
+        let mut prng = Prng::new(7);
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            let val = prng.gen_range(10..20);
+            assert!((10..20).contains(&val));
+            seen[usize::try_from(val - 10).unwrap()] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+        for _ in 0..100 {
+            assert_eq!(5, prng.gen_range(5..=5));
+            assert!(prng.gen_range(u64::MAX - 1..) >= u64::MAX - 1);
+        }
+        // Full range doesn't overflow
+        prng.gen_range(..);
+    }
+
+    #[test]
+    #[should_panic(expected = "Empty range")]
+    fn gen_range_empty() {
+        Prng::new(7).gen_range(5..5);
+    }
+
+    #[test]
+    fn fill_and_shuffle() {
+        let mut prng = Prng::new(11);
+        // Not a multiple of 8
+        let mut buf = [0u8; 29];
+        prng.fill_bytes(&mut buf);
+        assert!(buf[24..].iter().any(|b| *b != 0));
+        let mut vals: [u32; 64] = core::array::from_fn(|i| u32::try_from(i).unwrap());
+        prng.shuffle(&mut vals);
+        assert_ne!(
+            core::array::from_fn::<u32, 64, _>(|i| u32::try_from(i).unwrap()),
+            vals
+        );
+        vals.sort_unstable();
+        assert_eq!(
+            core::array::from_fn::<u32, 64, _>(|i| u32::try_from(i).unwrap()),
+            vals
+        );
+        prng.shuffle::<u32>(&mut []);
     }
 }